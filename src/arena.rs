@@ -0,0 +1,197 @@
+// =============================================================================
+// ARÈNE (BORNÉE OU TORIQUE)
+// =============================================================================
+//
+// L'arène est le rectangle [0, size) où se joue la partie :
+// - Bounded : les entités qui sortent de l'écran disparaissent ; le
+//   vaisseau, lui, bute contre les bords
+// - Toroidal : tout passe d'un bord à l'autre. Distances et directions
+//   prennent le plus court chemin à travers les bords, et une entité à
+//   cheval sur un bord est dessinée des deux côtés (voir `images`)
+//
+// Les aides ci-dessous valent pour les deux modes : en arène bornée, elles
+// se réduisent aux calculs habituels du plan (les replays enregistrés avant
+// le mode torique restent donc valides).
+//
+// Le laser traverse les bords comme le reste : son rayon touche les copies
+// des ennemis de l'autre côté (voir WeaponSystem::update_laser).
+//
+// L'arène peut être plus grande que la fenêtre : `view` est la taille de la
+// portion visible, que la caméra (camera.rs) déplace avec le vaisseau.
+//
+// =============================================================================
+
+use macroquad::prelude::*;
+use serde::{Deserialize, Serialize};
+
+// -----------------------------------------------------------------------------
+// MODE DE L'ARÈNE
+// -----------------------------------------------------------------------------
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ArenaMode {
+    #[default]
+    Bounded,    // Les entités sortent de l'écran
+    Toroidal,   // Tout passe d'un bord à l'autre
+}
+
+impl ArenaMode {
+    pub fn label(self) -> &'static str {
+        match self {
+            ArenaMode::Bounded => "Bornée",
+            ArenaMode::Toroidal => "Torique",
+        }
+    }
+
+    /// Mode suivant (bascule dans le menu)
+    pub fn next(self) -> Self {
+        match self {
+            ArenaMode::Bounded => ArenaMode::Toroidal,
+            ArenaMode::Toroidal => ArenaMode::Bounded,
+        }
+    }
+}
+
+// -----------------------------------------------------------------------------
+// ARÈNE
+// -----------------------------------------------------------------------------
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Arena {
+    pub size: Vec2,         // Taille de l'aire de jeu (indépendante de la fenêtre)
+    pub mode: ArenaMode,
+    pub view: Vec2,         // Taille de la portion visible (la fenêtre), px
+}
+
+impl Arena {
+    /// Arène entièrement visible (vue de la taille de l'arène)
+    pub fn new(size: Vec2, mode: ArenaMode) -> Self {
+        Self { size, mode, view: size }
+    }
+
+    /// Même arène, vue à travers une fenêtre de taille `view`
+    pub fn with_view(mut self, view: Vec2) -> Self {
+        self.view = view;
+        self
+    }
+
+    /// Arène bornée de taille donnée
+    pub fn bounded(size: Vec2) -> Self {
+        Self::new(size, ArenaMode::Bounded)
+    }
+
+    /// Toutes les entités passent-elles d'un bord à l'autre ?
+    pub fn wraps(&self) -> bool {
+        self.mode == ArenaMode::Toroidal
+    }
+
+    pub fn center(&self) -> Vec2 {
+        self.size / 2.
+    }
+
+    /// Position ramenée dans l'arène (inchangée en arène bornée)
+    pub fn wrap(&self, pos: Vec2) -> Vec2 {
+        if self.wraps() {
+            pos.rem_euclid(self.size)
+        } else {
+            pos
+        }
+    }
+
+    /// Ramène `pos` dans l'arène et décale `prev` (position du tick
+    /// précédent) d'autant : le trajet du tick reste continu pour le
+    /// balayage des collisions et l'interpolation du rendu
+    pub fn wrap_motion(&self, pos: &mut Vec2, prev: &mut Vec2) {
+        let wrapped = self.wrap(*pos);
+        *prev += wrapped - *pos;
+        *pos = wrapped;
+    }
+
+    /// Plus court vecteur de `from` vers `to` (à travers les bords en
+    /// arène torique)
+    pub fn delta(&self, from: Vec2, to: Vec2) -> Vec2 {
+        let d = to - from;
+        if self.wraps() {
+            d - (d / self.size).round() * self.size
+        } else {
+            d
+        }
+    }
+
+    pub fn distance(&self, a: Vec2, b: Vec2) -> f32 {
+        self.delta(a, b).length()
+    }
+
+    pub fn distance_squared(&self, a: Vec2, b: Vec2) -> f32 {
+        self.delta(a, b).length_squared()
+    }
+
+    /// Direction unitaire de `from` vers `to` (nulle s'ils sont confondus)
+    pub fn direction(&self, from: Vec2, to: Vec2) -> Vec2 {
+        self.delta(from, to).normalize_or_zero()
+    }
+
+    /// Copie de `pos` la plus proche de `near` (éventuellement hors de
+    /// l'arène) : c'est elle que `near` voit, vise et touche
+    pub fn image_near(&self, pos: Vec2, near: Vec2) -> Vec2 {
+        if self.wraps() {
+            near + self.delta(near, pos)
+        } else {
+            pos
+        }
+    }
+
+    /// Est-ce que `pos` est dans l'arène, à `margin` près ?
+    /// Toujours vrai en arène torique
+    pub fn contains(&self, pos: Vec2, margin: f32) -> bool {
+        self.wraps()
+            || (pos.x > -margin
+                && pos.x < self.size.x + margin
+                && pos.y > -margin
+                && pos.y < self.size.y + margin)
+    }
+
+    /// Décalages des copies visibles d'un objet de rayon `reach` centré en
+    /// `pos` : Vec2::ZERO d'abord, puis une copie de l'autre côté de chaque
+    /// bord qu'il chevauche (jusqu'à quatre en coin)
+    pub fn images(&self, pos: Vec2, reach: f32) -> impl Iterator<Item = Vec2> {
+        let xs = [Some(0.0), self.axis_image(pos.x, self.size.x, reach)];
+        let ys = [Some(0.0), self.axis_image(pos.y, self.size.y, reach)];
+        xs.into_iter()
+            .flatten()
+            .flat_map(move |x| ys.into_iter().flatten().map(move |y| vec2(x, y)))
+    }
+
+    /// Décalages des copies d'un objet de rayon `reach` centré en `pos` qui
+    /// tombent dans le rectangle `view` (aucune si l'objet est hors de vue)
+    pub fn images_in(&self, pos: Vec2, reach: f32, view: Rect) -> impl Iterator<Item = Vec2> {
+        let bounds = Rect::new(
+            view.x - reach,
+            view.y - reach,
+            view.w + 2.0 * reach,
+            view.h + 2.0 * reach,
+        );
+        self.tiles().filter(move |&offset| bounds.contains(pos + offset))
+    }
+
+    /// Décalages des neuf copies de l'arène autour d'une position (la seule
+    /// position elle-même en arène bornée), pour les recherches de voisins
+    pub fn tiles(&self) -> impl Iterator<Item = Vec2> {
+        let steps: &[f32] = if self.wraps() { &[0.0, -1.0, 1.0] } else { &[0.0] };
+        let size = self.size;
+        steps
+            .iter()
+            .flat_map(move |&x| steps.iter().map(move |&y| vec2(x, y) * size))
+    }
+
+    /// Copie sur un axe : de l'autre côté du bord que l'objet chevauche
+    fn axis_image(&self, v: f32, size: f32, reach: f32) -> Option<f32> {
+        if !self.wraps() {
+            None
+        } else if v < reach {
+            Some(size)
+        } else if v > size - reach {
+            Some(-size)
+        } else {
+            None
+        }
+    }
+}
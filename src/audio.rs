@@ -0,0 +1,617 @@
+// =============================================================================
+// SYSTÈME AUDIO (ABONNÉ DU BUS D'ÉVÉNEMENTS)
+// =============================================================================
+//
+// Ce fichier joue les effets sonores en réaction aux événements du gameplay :
+// - SoundCue : sons du jeu, rangés par catégorie (armes, impacts...)
+// - Mixer : volume général et volume par catégorie (réglages du joueur)
+// - AudioSystem : choisit le son, le panoramique (position x de l'événement
+//   par rapport au centre de la vue) et le volume (atténué en slow-motion), et limite le nombre de voix
+// - AudioBackend : sortie réelle (macroquad, feature `audio`) ou muette
+//   (NullBackend : tests et builds sans son)
+// - Music (music.rs) : couches musicales pilotées par le niveau de menace,
+//   jingles de fin de vague
+//
+// macroquad ne sait pas placer un son dans l'espace stéréo : chaque son est
+// donc préparé en plusieurs versions stéréo (PAN_STEPS), de la gauche
+// vers la droite, et la plus proche de la position demandée est jouée.
+//
+// =============================================================================
+
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::f32::consts::FRAC_PI_2;
+use macroquad::math::{vec2, Vec2};
+use crate::arena::Arena;
+use crate::consts::{SCREEN_HEIGHT, SCREEN_WIDTH};
+use crate::events::{Event, EventListener};
+use crate::music::{Music, MusicLayer, Stinger};
+#[cfg(feature = "audio")]
+use crate::music::MusicBank;
+use crate::synth::{self, SfxConfig, SAMPLE_RATE};
+use crate::weapons::WeaponKind;
+
+/// Nombre maximal de sons joués simultanément
+pub const MAX_VOICES: usize = 16;
+
+/// Nombre maximal d'instances simultanées d'un même son
+pub const MAX_VOICES_PER_CUE: usize = 4;
+
+/// Volume minimal en slow-motion (au facteur de temps le plus bas)
+const SLOWMO_MIN_GAIN: f32 = 0.4;
+
+/// Nombre de positions stéréo préparées par son (gauche → droite)
+pub const PAN_STEPS: usize = 5;
+
+/// Écart de volume sous lequel une boucle de musique n'est pas mise à jour
+const MUSIC_VOLUME_EPSILON: f32 = 0.01;
+
+// -----------------------------------------------------------------------------
+// SONS ET CATÉGORIES
+// -----------------------------------------------------------------------------
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum SoundCue {
+    Shot,           // Tir normal
+    Missile,        // Lancement de missile
+    Laser,          // Tir laser
+    Hit,            // Ennemi touché
+    Explosion,      // Ennemi détruit
+    ShieldBlock,    // Coup absorbé par le bouclier
+    Pickup,         // Power-up ramassé
+    PlayerHit,      // Vie perdue
+    WaveComplete,   // Fin de vague
+    GameOver,       // Fin de partie
+}
+
+impl SoundCue {
+    /// Tous les sons, dans l'ordre de chargement
+    pub const ALL: [SoundCue; 10] = [
+        SoundCue::Shot,
+        SoundCue::Missile,
+        SoundCue::Laser,
+        SoundCue::Hit,
+        SoundCue::Explosion,
+        SoundCue::ShieldBlock,
+        SoundCue::Pickup,
+        SoundCue::PlayerHit,
+        SoundCue::WaveComplete,
+        SoundCue::GameOver,
+    ];
+
+    /// Catégorie de mixage du son
+    pub fn category(self) -> SoundCategory {
+        match self {
+            SoundCue::Shot | SoundCue::Missile | SoundCue::Laser => SoundCategory::Weapons,
+            SoundCue::Hit | SoundCue::Explosion | SoundCue::ShieldBlock => SoundCategory::Impacts,
+            SoundCue::Pickup => SoundCategory::Pickups,
+            SoundCue::PlayerHit | SoundCue::WaveComplete | SoundCue::GameOver => SoundCategory::Alerts,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SoundCategory {
+    Weapons,    // Tirs du joueur
+    Impacts,    // Touches, explosions, bouclier
+    Pickups,    // Power-ups
+    Alerts,     // Vie perdue, vague, fin de partie
+}
+
+// -----------------------------------------------------------------------------
+// MIXEUR (RÉGLAGES DU JOUEUR)
+// -----------------------------------------------------------------------------
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Mixer {
+    pub master: f32,
+    pub weapons: f32,
+    pub impacts: f32,
+    pub pickups: f32,
+    pub alerts: f32,
+    pub music: f32,
+}
+
+impl Mixer {
+    pub fn new() -> Self {
+        Self {
+            master: 0.8,
+            weapons: 0.5,
+            impacts: 0.8,
+            pickups: 1.0,
+            alerts: 1.0,
+            music: 0.6,
+        }
+    }
+
+    /// Volume effectif d'une catégorie (volume général compris)
+    pub fn gain(&self, category: SoundCategory) -> f32 {
+        let volume = match category {
+            SoundCategory::Weapons => self.weapons,
+            SoundCategory::Impacts => self.impacts,
+            SoundCategory::Pickups => self.pickups,
+            SoundCategory::Alerts => self.alerts,
+        };
+        (self.master * volume).clamp(0.0, 1.0)
+    }
+
+    /// Volume effectif de la musique (volume général compris)
+    pub fn music_gain(&self) -> f32 {
+        (self.master * self.music).clamp(0.0, 1.0)
+    }
+}
+
+impl Default for Mixer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// -----------------------------------------------------------------------------
+// SORTIE AUDIO
+// -----------------------------------------------------------------------------
+pub trait AudioBackend {
+    /// Durée d'un son en secondes (suivi des voix actives)
+    fn duration(&self, cue: SoundCue) -> f32;
+
+    /// Joue un son (`volume` de 0 à 1, `pan` de -1 gauche à 1 droite)
+    fn play(&mut self, cue: SoundCue, volume: f32, pan: f32);
+
+    /// Règle le volume d'une boucle de musique (`slowed` : version ralentie)
+    /// Les boucles tournent en permanence, muettes tant que le volume est nul
+    fn set_music_volume(&mut self, layer: MusicLayer, slowed: bool, volume: f32);
+
+    /// Joue un jingle musical
+    fn play_stinger(&mut self, stinger: Stinger, volume: f32);
+}
+
+/// Sortie muette : compte les sons sans rien jouer
+pub struct NullBackend {
+    pub played: usize,
+    pub stingers: usize,
+}
+
+impl NullBackend {
+    pub fn new() -> Self {
+        Self { played: 0, stingers: 0 }
+    }
+}
+
+impl Default for NullBackend {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl AudioBackend for NullBackend {
+    fn duration(&self, _cue: SoundCue) -> f32 {
+        0.2
+    }
+
+    fn play(&mut self, _cue: SoundCue, _volume: f32, _pan: f32) {
+        self.played += 1;
+    }
+
+    fn set_music_volume(&mut self, _layer: MusicLayer, _slowed: bool, _volume: f32) {}
+
+    fn play_stinger(&mut self, _stinger: Stinger, _volume: f32) {
+        self.stingers += 1;
+    }
+}
+
+/// Sortie macroquad : chaque son est chargé en PAN_STEPS versions stéréo
+#[cfg(feature = "audio")]
+pub struct MacroquadBackend {
+    sounds: BTreeMap<SoundCue, (f32, Vec<macroquad::audio::Sound>)>,
+    music: BTreeMap<(MusicLayer, bool), macroquad::audio::Sound>,
+    stingers: BTreeMap<Stinger, macroquad::audio::Sound>,
+}
+
+#[cfg(feature = "audio")]
+impl MacroquadBackend {
+    /// Charge tous les sons et la musique dans le mixeur de macroquad,
+    /// puis lance les boucles de musique (muettes)
+    pub async fn load(bank: &SoundBank, music_bank: &MusicBank) -> Self {
+        let mut sounds = BTreeMap::new();
+        for (&cue, samples) in &bank.sounds {
+            let mut variants = Vec::with_capacity(PAN_STEPS);
+            for step in 0..PAN_STEPS {
+                let wav = encode_wav(&pan_stereo(samples, pan_of_step(step)), 2, bank.sample_rate);
+                match macroquad::audio::load_sound_from_bytes(&wav).await {
+                    Ok(sound) => variants.push(sound),
+                    Err(e) => eprintln!("Son {cue:?} illisible : {e}"),
+                }
+            }
+            if variants.len() == PAN_STEPS {
+                sounds.insert(cue, (bank.duration(cue), variants));
+            }
+        }
+
+        let mut music = BTreeMap::new();
+        for (&key, samples) in &music_bank.loops {
+            let wav = encode_wav(&pan_stereo(samples, 0.0), 2, music_bank.sample_rate);
+            match macroquad::audio::load_sound_from_bytes(&wav).await {
+                Ok(sound) => {
+                    music.insert(key, sound);
+                }
+                Err(e) => eprintln!("Musique {key:?} illisible : {e}"),
+            }
+        }
+        // Lancées ensemble pour rester calées les unes sur les autres
+        for sound in music.values() {
+            macroquad::audio::play_sound(
+                sound,
+                macroquad::audio::PlaySoundParams { looped: true, volume: 0.0 },
+            );
+        }
+
+        let mut stingers = BTreeMap::new();
+        for (&stinger, samples) in &music_bank.stingers {
+            let wav = encode_wav(&pan_stereo(samples, 0.0), 2, music_bank.sample_rate);
+            match macroquad::audio::load_sound_from_bytes(&wav).await {
+                Ok(sound) => {
+                    stingers.insert(stinger, sound);
+                }
+                Err(e) => eprintln!("Jingle {stinger:?} illisible : {e}"),
+            }
+        }
+        Self { sounds, music, stingers }
+    }
+}
+
+#[cfg(feature = "audio")]
+impl AudioBackend for MacroquadBackend {
+    fn duration(&self, cue: SoundCue) -> f32 {
+        self.sounds.get(&cue).map_or(0.0, |(duration, _)| *duration)
+    }
+
+    fn play(&mut self, cue: SoundCue, volume: f32, pan: f32) {
+        if let Some((_, variants)) = self.sounds.get(&cue) {
+            macroquad::audio::play_sound(
+                &variants[step_of_pan(pan)],
+                macroquad::audio::PlaySoundParams { looped: false, volume },
+            );
+        }
+    }
+
+    fn set_music_volume(&mut self, layer: MusicLayer, slowed: bool, volume: f32) {
+        if let Some(sound) = self.music.get(&(layer, slowed)) {
+            macroquad::audio::set_sound_volume(sound, volume);
+        }
+    }
+
+    fn play_stinger(&mut self, stinger: Stinger, volume: f32) {
+        if let Some(sound) = self.stingers.get(&stinger) {
+            macroquad::audio::play_sound(
+                sound,
+                macroquad::audio::PlaySoundParams { looped: false, volume },
+            );
+        }
+    }
+}
+
+// -----------------------------------------------------------------------------
+// SYSTÈME AUDIO
+// -----------------------------------------------------------------------------
+
+/// Son en cours de lecture (pour la limite de voix)
+struct Voice {
+    cue: SoundCue,
+    remaining: f32,
+}
+
+pub struct AudioSystem<B: AudioBackend> {
+    pub backend: B,
+    pub mixer: Mixer,
+    pub music: Music,
+    voices: Vec<Voice>,
+    music_volumes: [[f32; 2]; 3],   // Dernier volume envoyé par boucle (normale, ralentie)
+    arena: Arena,       // Arène de la partie (panoramique)
+    listener: Vec2,     // Centre de la vue : les sons s'y entendent au centre
+    time_scale: f32,    // Facteur de slow-motion (1 = vitesse normale)
+}
+
+impl<B: AudioBackend> AudioSystem<B> {
+    pub fn new(backend: B, mixer: Mixer) -> Self {
+        Self {
+            backend,
+            mixer,
+            music: Music::new(),
+            voices: Vec::new(),
+            music_volumes: [[0.0; 2]; 3],
+            arena: Arena::bounded(vec2(SCREEN_WIDTH, SCREEN_HEIGHT)),  // Remplacée au début de chaque partie
+            listener: vec2(SCREEN_WIDTH, SCREEN_HEIGHT) / 2.,
+            time_scale: 1.0,
+        }
+    }
+
+    /// Fait avancer les voix actives (temps réel de la frame)
+    pub fn update(&mut self, dt: f32) {
+        for voice in self.voices.iter_mut() {
+            voice.remaining -= dt;
+        }
+        self.voices.retain(|v| v.remaining > 0.0);
+
+        self.music.update(dt);
+        let gain = self.mixer.music_gain();
+        for (layer, sent) in MusicLayer::ALL.into_iter().zip(self.music_volumes.iter_mut()) {
+            for (slowed, sent) in [false, true].into_iter().zip(sent.iter_mut()) {
+                let volume = self.music.volume(layer, slowed) * gain;
+                if (volume - *sent).abs() > MUSIC_VOLUME_EPSILON || (volume == 0.0 && *sent != 0.0) {
+                    self.backend.set_music_volume(layer, slowed, volume);
+                    *sent = volume;
+                }
+            }
+        }
+    }
+
+    /// Active ou coupe la musique (en fondu) selon la scène affichée
+    pub fn set_music_active(&mut self, active: bool) {
+        self.music.active = active;
+    }
+
+    /// Joue un jingle et atténue les boucles le temps qu'il passe
+    pub fn play_stinger(&mut self, stinger: Stinger) {
+        let volume = self.mixer.music_gain();
+        if self.music.active && volume > 0.0 {
+            self.backend.play_stinger(stinger, volume);
+            self.music.duck();
+        }
+    }
+
+    /// Nombre de sons en cours de lecture
+    pub fn active_voices(&self) -> usize {
+        self.voices.len()
+    }
+
+    /// Joue un son si une voix est libre
+    /// `x` : position horizontale de la source (None = centré)
+    pub fn play(&mut self, cue: SoundCue, x: Option<f32>) {
+        let same_cue = self.voices.iter().filter(|v| v.cue == cue).count();
+        if self.voices.len() >= MAX_VOICES || same_cue >= MAX_VOICES_PER_CUE {
+            return;
+        }
+
+        let volume = self.mixer.gain(cue.category()) * self.slowmo_gain();
+        if volume <= 0.0 {
+            return;
+        }
+        let half_view = (self.arena.view.x / 2.0).max(1.0);
+        let pan = x.map_or(0.0, |x| {
+            let dx = self.arena.delta(self.listener, vec2(x, self.listener.y)).x;
+            (dx / half_view).clamp(-1.0, 1.0)
+        });
+
+        self.backend.play(cue, volume, pan);
+        self.voices.push(Voice {
+            cue,
+            remaining: self.backend.duration(cue),
+        });
+    }
+
+    /// Atténuation en slow-motion (plus le temps ralentit, plus c'est étouffé)
+    fn slowmo_gain(&self) -> f32 {
+        SLOWMO_MIN_GAIN + (1.0 - SLOWMO_MIN_GAIN) * self.time_scale.clamp(0.0, 1.0)
+    }
+}
+
+impl<B: AudioBackend> EventListener for AudioSystem<B> {
+    fn on_event(&mut self, event: &Event) {
+        match *event {
+            Event::RunStarted { arena } => {
+                self.arena = arena;
+                self.listener = arena.center();
+                self.time_scale = 1.0;
+                self.music.reset();
+            }
+            Event::TimeScaleChanged { factor } => {
+                self.time_scale = factor;
+                self.music.set_time_scale(factor);
+            }
+            Event::ViewMoved { center } => self.listener = center,
+            Event::ThreatChanged { level } => self.music.set_threat(level),
+            Event::WeaponFired { weapon, pos, .. } => {
+                let cue = match weapon {
+                    WeaponKind::Cannon => SoundCue::Shot,
+                    WeaponKind::Missile => SoundCue::Missile,
+                    WeaponKind::Laser => SoundCue::Laser,
+                    WeaponKind::Shield | WeaponKind::Collision => return,
+                };
+                self.play(cue, Some(pos.x));
+            }
+            Event::EnemyDamaged { by_weapon, pos, .. } => {
+                // Le laser touche à chaque tick : pas de son par coup
+                if !matches!(by_weapon, WeaponKind::Collision | WeaponKind::Laser) {
+                    self.play(SoundCue::Hit, Some(pos.x));
+                }
+            }
+            Event::EnemyKilled { pos, .. } => self.play(SoundCue::Explosion, Some(pos.x)),
+            Event::PlayerHit { pos, .. } => self.play(SoundCue::PlayerHit, Some(pos.x)),
+            Event::ShieldBlocked { pos } => self.play(SoundCue::ShieldBlock, Some(pos.x)),
+            Event::PowerUpCollected { pos, .. } => self.play(SoundCue::Pickup, Some(pos.x)),
+            Event::WaveCompleted { .. } => {
+                self.play(SoundCue::WaveComplete, None);
+                self.play_stinger(Stinger::WaveComplete);
+            }
+            Event::BossArrived { .. } => self.play_stinger(Stinger::Boss),
+            Event::BossPhaseChanged { pos, .. } => self.play(SoundCue::Explosion, Some(pos.x)),
+            Event::GameOver => {
+                self.time_scale = 1.0;
+                self.music.set_time_scale(1.0);
+                self.play(SoundCue::GameOver, None);
+            }
+        }
+    }
+}
+
+// -----------------------------------------------------------------------------
+// BANQUE DE SONS (ÉCHANTILLONS MONO, SYNTHÉTISÉS AU DÉMARRAGE)
+// -----------------------------------------------------------------------------
+pub struct SoundBank {
+    pub sample_rate: u32,
+    pub sounds: BTreeMap<SoundCue, Vec<f32>>,   // Échantillons de -1 à 1
+}
+
+impl SoundBank {
+    /// Synthétise tous les sons à partir de leurs paramètres (voir synth.rs)
+    pub fn render(config: &SfxConfig) -> Self {
+        let sounds = SoundCue::ALL
+            .into_iter()
+            .map(|cue| (cue, synth::render(config.get(cue), SAMPLE_RATE)))
+            .collect();
+        Self {
+            sample_rate: SAMPLE_RATE,
+            sounds,
+        }
+    }
+
+    /// Durée d'un son en secondes
+    pub fn duration(&self, cue: SoundCue) -> f32 {
+        self.sounds
+            .get(&cue)
+            .map_or(0.0, |samples| samples.len() as f32 / self.sample_rate as f32)
+    }
+}
+
+// -----------------------------------------------------------------------------
+// STÉRÉO ET FORMAT WAV
+// -----------------------------------------------------------------------------
+
+/// Position stéréo de la version `step` (-1 gauche à 1 droite)
+pub fn pan_of_step(step: usize) -> f32 {
+    step as f32 / (PAN_STEPS - 1) as f32 * 2.0 - 1.0
+}
+
+/// Version stéréo la plus proche d'une position
+pub fn step_of_pan(pan: f32) -> usize {
+    (((pan.clamp(-1.0, 1.0) + 1.0) / 2.0) * (PAN_STEPS - 1) as f32).round() as usize
+}
+
+/// Place un son mono dans l'espace stéréo (loi à puissance constante)
+/// Retourne les échantillons entrelacés gauche / droite
+pub fn pan_stereo(samples: &[f32], pan: f32) -> Vec<f32> {
+    let angle = (pan.clamp(-1.0, 1.0) + 1.0) * 0.5 * FRAC_PI_2;
+    let (left, right) = (angle.cos(), angle.sin());
+    samples.iter().flat_map(|&s| [s * left, s * right]).collect()
+}
+
+/// Encode des échantillons entrelacés en WAV PCM 16 bits
+pub fn encode_wav(samples: &[f32], channels: u16, sample_rate: u32) -> Vec<u8> {
+    let data_len = (samples.len() * 2) as u32;
+    let block_align = channels * 2;
+    let mut out = Vec::with_capacity(44 + data_len as usize);
+    out.extend_from_slice(b"RIFF");
+    out.extend_from_slice(&(36 + data_len).to_le_bytes());
+    out.extend_from_slice(b"WAVE");
+    out.extend_from_slice(b"fmt ");
+    out.extend_from_slice(&16u32.to_le_bytes());
+    out.extend_from_slice(&1u16.to_le_bytes());   // PCM
+    out.extend_from_slice(&channels.to_le_bytes());
+    out.extend_from_slice(&sample_rate.to_le_bytes());
+    out.extend_from_slice(&(sample_rate * block_align as u32).to_le_bytes());
+    out.extend_from_slice(&block_align.to_le_bytes());
+    out.extend_from_slice(&16u16.to_le_bytes());  // Bits par échantillon
+    out.extend_from_slice(b"data");
+    out.extend_from_slice(&data_len.to_le_bytes());
+    for &s in samples {
+        out.extend_from_slice(&((s.clamp(-1.0, 1.0) * i16::MAX as f32) as i16).to_le_bytes());
+    }
+    out
+}
+
+// =============================================================================
+// TESTS
+// =============================================================================
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::arena::ArenaMode;
+    use crate::enemies::PowerUpType;
+
+    /// Sortie muette qui garde (son, volume, panoramique) de chaque son joué
+    #[derive(Default)]
+    struct Recorder {
+        played: Vec<(SoundCue, f32, f32)>,
+    }
+
+    impl AudioBackend for Recorder {
+        fn duration(&self, _cue: SoundCue) -> f32 {
+            0.2
+        }
+
+        fn play(&mut self, cue: SoundCue, volume: f32, pan: f32) {
+            self.played.push((cue, volume, pan));
+        }
+
+        fn set_music_volume(&mut self, _layer: MusicLayer, _slowed: bool, _volume: f32) {}
+
+        fn play_stinger(&mut self, _stinger: Stinger, _volume: f32) {}
+    }
+
+    fn shot(x: f32) -> Event {
+        Event::WeaponFired { weapon: WeaponKind::Cannon, pos: vec2(x, 300.), dir: Vec2::X }
+    }
+
+    #[test]
+    fn voices_saturate_at_caps() {
+        let mut audio = AudioSystem::new(NullBackend::new(), Mixer::new());
+        for _ in 0..10 {
+            audio.on_event(&shot(400.));
+        }
+        assert_eq!(audio.active_voices(), MAX_VOICES_PER_CUE);
+        assert_eq!(audio.backend.played, MAX_VOICES_PER_CUE);
+
+        // Quatre autres sons jusqu'à la limite globale, puis plus rien
+        let pos = vec2(400., 300.);
+        for _ in 0..10 {
+            audio.on_event(&Event::ShieldBlocked { pos });
+            audio.on_event(&Event::PlayerHit { pos, lives_left: 2, by: None });
+            audio.on_event(&Event::BossPhaseChanged { phase: 2, pos });
+            audio.on_event(&Event::PowerUpCollected { kind: PowerUpType::Shield, pos });
+            audio.on_event(&Event::GameOver);
+        }
+        assert_eq!(audio.active_voices(), MAX_VOICES);
+        assert_eq!(audio.backend.played, MAX_VOICES);
+
+        // Les voix se libèrent une fois leur son terminé
+        audio.update(0.25);
+        assert_eq!(audio.active_voices(), 0);
+        audio.on_event(&shot(400.));
+        assert_eq!(audio.active_voices(), 1);
+    }
+
+    #[test]
+    fn pan_follows_source_relative_to_view() {
+        let mut audio = AudioSystem::new(Recorder::default(), Mixer::new());
+        audio.on_event(&Event::RunStarted { arena: Arena::bounded(vec2(800., 600.)) });
+        for x in [0., 400., 600., 800., 1200.] {
+            audio.play(SoundCue::Hit, Some(x));
+            audio.update(1.0);
+        }
+        audio.play(SoundCue::Hit, None);
+        let pans: Vec<f32> = audio.backend.played.iter().map(|&(_, _, pan)| pan).collect();
+        assert_eq!(pans, [-1.0, 0.0, 0.5, 1.0, 1.0, 0.0]);
+
+        // Arène torique : la source juste après le bord droit s'entend à droite
+        let arena = Arena::new(vec2(800., 600.), ArenaMode::Toroidal);
+        audio.on_event(&Event::RunStarted { arena });
+        audio.on_event(&Event::ViewMoved { center: vec2(700., 300.) });
+        audio.play(SoundCue::Hit, Some(100.));
+        assert_eq!(audio.backend.played.last().unwrap().2, 0.5);
+    }
+
+    #[test]
+    fn slowmo_muffles_sounds() {
+        let mut audio = AudioSystem::new(Recorder::default(), Mixer::new());
+        audio.play(SoundCue::Hit, None);
+        audio.on_event(&Event::TimeScaleChanged { factor: 0.0 });
+        audio.play(SoundCue::Hit, None);
+        audio.on_event(&Event::TimeScaleChanged { factor: 0.5 });
+        audio.play(SoundCue::Hit, None);
+
+        let full = Mixer::new().gain(SoundCategory::Impacts);
+        let volumes: Vec<f32> = audio.backend.played.iter().map(|&(_, volume, _)| volume / full).collect();
+        assert_eq!(volumes[0], 1.0);
+        assert!((volumes[1] - SLOWMO_MIN_GAIN).abs() < 1e-6);
+        assert!((volumes[2] - (1.0 + SLOWMO_MIN_GAIN) / 2.0).abs() < 1e-6);
+    }
+}
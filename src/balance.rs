@@ -0,0 +1,741 @@
+// =============================================================================
+// ÉQUILIBRAGE DU GAMEPLAY (CONFIGURATION RECHARGEABLE)
+// =============================================================================
+//
+// Ce fichier regroupe tous les réglages de gameplay :
+// - BalanceConfig : vaisseau, armes, drones, astéroïdes, vagues, boss,
+//   power-ups
+// - Valeurs par défaut documentées champ par champ (celles du jeu d'origine)
+// - Chargement depuis un fichier RON (balance.ron) avec validation
+// - BalanceWatcher : rechargement à chaud quand le fichier change
+//
+// Toutes les sections et tous les champs sont optionnels dans le fichier :
+// un champ absent garde sa valeur par défaut, un champ inconnu est une erreur
+// (faute de frappe). Un fichier invalide ne remplace jamais la configuration
+// en cours.
+//
+// Les replays ne stockent pas l'équilibrage : un replay ne se rejoue à
+// l'identique qu'avec la configuration utilisée pendant la partie.
+//
+// =============================================================================
+
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+use crate::consts::{MAX_FRAME_TIME, SIM_DT};
+
+/// Emplacement du fichier d'équilibrage (builds natifs)
+pub const BALANCE_PATH: &str = "balance.ron";
+
+/// Intervalle entre deux vérifications du fichier (secondes)
+pub const BALANCE_POLL_INTERVAL: f32 = 0.5;
+
+// -----------------------------------------------------------------------------
+// CONFIGURATION COMPLÈTE
+// -----------------------------------------------------------------------------
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct BalanceConfig {
+    pub ship: ShipBalance,
+    pub weapons: WeaponBalance,
+    pub drones: DroneBalance,
+    pub asteroids: AsteroidBalance,
+    pub waves: WaveBalance,
+    pub boss: BossBalance,
+    pub powerups: PowerUpBalance,
+}
+
+// -----------------------------------------------------------------------------
+// VAISSEAU
+// -----------------------------------------------------------------------------
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ShipBalance {
+    pub lives: i32,                 // Vies initiales (3)
+    pub max_lives: i32,             // Vies max via power-up (5)
+    pub invincible_time: f32,       // Invincibilité après dégât, secondes (2.0)
+
+    pub turn_speed: f32,            // Vitesse de rotation, rad/s (2.0)
+    pub thrust: f32,                // Accélération avant, px/s² (500)
+    pub brake: f32,                 // Accélération arrière, px/s² (300)
+    pub reverse_boost: f32,         // Multiplicateur du recul boosté (2.5)
+    pub reverse_boost_time: f32,    // Durée de l'indicateur de boost, secondes (0.5)
+    pub friction: f32,              // Vitesse conservée à chaque tick (0.97)
+
+    pub shield_time: f32,           // Énergie max du bouclier, secondes (5.0)
+    pub shield_drain: f32,          // Consommation bouclier actif, par seconde (1.5)
+    pub shield_recharge: f32,       // Recharge bouclier inactif, par seconde (0.6)
+
+    pub slowmo_time: f32,           // Énergie max du slow-mo, secondes (3.0)
+    pub slowmo_drain: f32,          // Consommation slow-mo actif, par seconde (2.5)
+    pub slowmo_recharge: f32,       // Recharge slow-mo inactif, par seconde (0.4)
+    pub slowmo_ready: f32,          // Fraction rechargée avant réutilisation (0.9)
+    pub slowmo_factor: f32,         // Vitesse du temps en slow-mo (0.3)
+
+    pub energy_min: f32,            // Énergie minimale pour activer bouclier ou slow-mo (0.1)
+    pub rapid_fire_time: f32,       // Durée du power-up tir rapide, secondes (7.0)
+}
+
+impl Default for ShipBalance {
+    fn default() -> Self {
+        Self {
+            lives: 3,
+            max_lives: 5,
+            invincible_time: 2.0,
+            turn_speed: 2.0,
+            thrust: 500.0,
+            brake: 300.0,
+            reverse_boost: 2.5,
+            reverse_boost_time: 0.5,
+            friction: 0.97,
+            shield_time: 5.0,
+            shield_drain: 1.5,
+            shield_recharge: 0.6,
+            slowmo_time: 3.0,
+            slowmo_drain: 2.5,
+            slowmo_recharge: 0.4,
+            slowmo_ready: 0.9,
+            slowmo_factor: 0.3,
+            energy_min: 0.1,
+            rapid_fire_time: 7.0,
+        }
+    }
+}
+
+// -----------------------------------------------------------------------------
+// ARMES DU JOUEUR
+// -----------------------------------------------------------------------------
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct WeaponBalance {
+    // Tir normal
+    pub fire_rate: f32,             // Délai entre deux tirs, secondes (0.2)
+    pub rapid_fire_rate: f32,       // Délai sous power-up tir rapide (0.1)
+    pub bullet_speed: f32,          // Vitesse des balles, px/s (800)
+    pub bullet_life: f32,           // Durée de vie des balles, secondes (2.0)
+    pub bullet_recoil: f32,         // Recul par tir, px/s (30)
+
+    // Missiles téléguidés
+    pub missile_ammo_max: i32,      // Munitions max (5)
+    pub missile_cooldown: f32,      // Délai entre deux missiles, secondes (0.5)
+    pub missile_regen_time: f32,    // Temps de recharge d'une munition, secondes (1.0)
+    pub missile_life: f32,          // Durée de vie, secondes (3.0)
+    pub missile_speed: f32,         // Vitesse, px/s (400)
+    pub missile_turn_speed: f32,    // Agilité du virage (3.0)
+    pub missile_damage: i32,        // Dégâts par impact, points de vie (2)
+    pub missile_recoil: f32,        // Recul au lancement, px/s (50)
+    pub missile_lock_time: f32,     // Délai entre deux verrouillages, secondes (0.25)
+    pub missile_lock_range: f32,    // Portée du verrouillage, px (450)
+    pub missile_salvo_spread: f32,  // Écart entre les missiles d'une salve, radians (0.25)
+
+    // Laser perçant
+    pub laser_duration: f32,        // Durée du tir, secondes (1.0)
+    pub laser_cooldown: f32,        // Temps de recharge, secondes (5.0)
+    pub laser_damage: i32,          // Dégâts par impact, points de vie (2)
+    pub laser_max_penetration: i32, // Ennemis traversés max (5)
+    pub laser_range: f32,           // Portée, px (800)
+    pub laser_recoil: f32,          // Recul au tir, px/s (80)
+}
+
+impl Default for WeaponBalance {
+    fn default() -> Self {
+        Self {
+            fire_rate: 0.2,
+            rapid_fire_rate: 0.1,
+            bullet_speed: 800.0,
+            bullet_life: 2.0,
+            bullet_recoil: 30.0,
+            missile_ammo_max: 5,
+            missile_cooldown: 0.5,
+            missile_regen_time: 1.0,
+            missile_life: 3.0,
+            missile_speed: 400.0,
+            missile_turn_speed: 3.0,
+            missile_damage: 2,
+            missile_recoil: 50.0,
+            missile_lock_time: 0.25,
+            missile_lock_range: 450.0,
+            missile_salvo_spread: 0.25,
+            laser_duration: 1.0,
+            laser_cooldown: 5.0,
+            laser_damage: 2,
+            laser_max_penetration: 5,
+            laser_range: 800.0,
+            laser_recoil: 80.0,
+        }
+    }
+}
+
+// -----------------------------------------------------------------------------
+// DRONES ENNEMIS
+// -----------------------------------------------------------------------------
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct DroneBalance {
+    pub base_hp: i32,               // Points de vie en vague 1 (2)
+    pub waves_per_hp: i32,          // Vagues nécessaires pour +1 PV (3)
+
+    pub kamikaze_speed: f32,        // Vitesse de poursuite, px/s (40)
+    pub kamikaze_steering: f32,     // Lissage de la poursuite par tick (0.1)
+    pub sniper_speed: f32,          // Vitesse de descente, px/s (20)
+    pub bomber_speed_x: f32,        // Vitesse horizontale, px/s (80)
+    pub bomber_speed_y: f32,        // Vitesse de descente, px/s (30)
+
+    pub sniper_first_shot: f32,     // Délai avant le premier tir, divisé par la difficulté (2.0)
+    pub sniper_cooldown: f32,       // Délai entre deux tirs, secondes (2.5)
+    pub sniper_bullet_speed: f32,   // Vitesse des balles, px/s (400)
+    pub sniper_lead: f32,           // Anticipation du mouvement du joueur, secondes (0.3)
+    pub bomber_cooldown: f32,       // Délai entre deux bombes, secondes (3.0)
+    pub bomber_bullet_speed: f32,   // Vitesse de chute des bombes, px/s (150)
+    pub bullet_life: f32,           // Durée de vie des balles ennemies, secondes (3.0)
+}
+
+impl Default for DroneBalance {
+    fn default() -> Self {
+        Self {
+            base_hp: 2,
+            waves_per_hp: 3,
+            kamikaze_speed: 40.0,
+            kamikaze_steering: 0.1,
+            sniper_speed: 20.0,
+            bomber_speed_x: 80.0,
+            bomber_speed_y: 30.0,
+            sniper_first_shot: 2.0,
+            sniper_cooldown: 2.5,
+            sniper_bullet_speed: 400.0,
+            sniper_lead: 0.3,
+            bomber_cooldown: 3.0,
+            bomber_bullet_speed: 150.0,
+            bullet_life: 3.0,
+        }
+    }
+}
+
+// -----------------------------------------------------------------------------
+// ASTÉROÏDES
+// -----------------------------------------------------------------------------
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct AsteroidBalance {
+    pub large_chance: f32,          // Chance qu'un astéroïde apparaisse grand plutôt que moyen (0.4)
+    pub fragments_min: i32,         // Fragments min d'un astéroïde brisé (2)
+    pub fragments_max: i32,         // Fragments max d'un astéroïde brisé (3)
+    pub fragment_speed: f32,        // Vitesse d'éclatement des fragments, px/s (60)
+
+    pub large_score: i32,           // Points pour un grand astéroïde détruit (20)
+    pub medium_score: i32,          // Points pour un moyen astéroïde détruit (30)
+    pub small_score: i32,           // Points pour un petit astéroïde détruit (50)
+    pub large_drop_chance: f32,     // Chance de power-up sur un grand astéroïde (0.05)
+    pub medium_drop_chance: f32,    // Chance de power-up sur un moyen astéroïde (0.1)
+    pub small_drop_chance: f32,     // Chance de power-up sur un petit astéroïde (0.2)
+}
+
+impl Default for AsteroidBalance {
+    fn default() -> Self {
+        Self {
+            large_chance: 0.4,
+            fragments_min: 2,
+            fragments_max: 3,
+            fragment_speed: 60.0,
+            large_score: 20,
+            medium_score: 30,
+            small_score: 50,
+            large_drop_chance: 0.05,
+            medium_drop_chance: 0.1,
+            small_drop_chance: 0.2,
+        }
+    }
+}
+
+// -----------------------------------------------------------------------------
+// VAGUES ET APPARITIONS
+// -----------------------------------------------------------------------------
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct WaveBalance {
+    pub base_size: i32,             // Ennemis à détruire en vague 1 (5)
+    pub size_per_wave: i32,         // Ennemis en plus par vague : base + vague × n (3)
+    pub completion_bonus: i32,      // Bonus de fin de vague : vague × n points (100)
+
+    pub base_difficulty: f32,       // Difficulté de départ (1.5)
+    pub difficulty_per_wave: f32,   // Difficulté : base × (1 + vague × n) (0.5)
+    pub asteroid_spawn_rate: f32,   // 1 chance sur (n / difficulté) par tick (80)
+    pub drone_spawn_rate: f32,      // 1 chance sur (n / difficulté) par tick (200)
+    pub drone_cap: i32,             // Drones simultanés max : n + vague (5)
+    pub asteroid_cap: i32,          // Arène torique : plus de spawn au-delà de n astéroïdes (12)
+}
+
+impl Default for WaveBalance {
+    fn default() -> Self {
+        Self {
+            base_size: 5,
+            size_per_wave: 3,
+            completion_bonus: 100,
+            base_difficulty: 1.5,
+            difficulty_per_wave: 0.5,
+            asteroid_spawn_rate: 80.0,
+            drone_spawn_rate: 200.0,
+            drone_cap: 5,
+            asteroid_cap: 12,
+        }
+    }
+}
+
+// -----------------------------------------------------------------------------
+// BOSS (VAISSEAU-MÈRE, VOIR BOSS.RS)
+// -----------------------------------------------------------------------------
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct BossBalance {
+    pub every: i32,                 // Une vague sur n est remplacée par un boss (5)
+    pub core_hp: i32,               // Santé du noyau au premier boss (400)
+    pub core_hp_per_boss: i32,      // Santé du noyau en plus à chaque boss suivant (150)
+    pub turret_count: i32,          // Tourelles sur la coque (4)
+    pub turret_hp: i32,             // Santé d'une tourelle (20)
+
+    pub speed: f32,                 // Vitesse du vaisseau-mère, px/s (80)
+    pub steering: f32,              // Part de l'écart de vitesse rattrapée par tick (0.03)
+    pub hover_distance: f32,        // Distance tenue au-dessus du vaisseau, px (220)
+    pub spin: f32,                  // Rotation de la coque, rad/s (0.3)
+
+    pub phase2_health: f32,         // Santé (fraction) du passage en phase 2 (0.66)
+    pub phase3_health: f32,         // Santé (fraction) du passage en phase 3 (0.33)
+    pub phase3_speed: f32,          // Phase 3 : multiplicateur de vitesse (1.8)
+
+    pub fan_cooldown: f32,          // Phase 1 : délai entre deux éventails, secondes (1.6)
+    pub fan_bullets: i32,           // Phase 1 : balles par éventail (5)
+    pub fan_spread: f32,            // Phase 1 : écart entre deux balles, rad (0.2)
+    pub ring_cooldown: f32,         // Phase 2 : délai entre deux couronnes, secondes (1.8)
+    pub ring_bullets: i32,          // Phase 2 : balles par couronne (16)
+    pub spiral_cooldown: f32,       // Phase 3 : délai entre deux salves de spirale, secondes (0.15)
+    pub spiral_arms: i32,           // Phase 3 : bras de la spirale (3)
+    pub spiral_step: f32,           // Phase 3 : rotation de la spirale par salve, rad (0.25)
+    pub turret_cooldown: f32,       // Tir visé d'une tourelle, secondes (÷ phase) (1.5)
+    pub bullet_speed: f32,          // Vitesse des balles du boss, px/s (220)
+
+    pub hit_score: i32,             // Points par coup sur le boss (1)
+    pub turret_score: i32,          // Points par tourelle détruite (150)
+    pub kill_score: i32,            // Points pour le noyau détruit (1000)
+    pub reward_drops: i32,          // Power-ups garantis à la destruction, dont une vie (3)
+}
+
+impl Default for BossBalance {
+    fn default() -> Self {
+        Self {
+            every: 5,
+            core_hp: 400,
+            core_hp_per_boss: 150,
+            turret_count: 4,
+            turret_hp: 20,
+            speed: 80.0,
+            steering: 0.03,
+            hover_distance: 220.0,
+            spin: 0.3,
+            phase2_health: 0.66,
+            phase3_health: 0.33,
+            phase3_speed: 1.8,
+            fan_cooldown: 1.6,
+            fan_bullets: 5,
+            fan_spread: 0.2,
+            ring_cooldown: 1.8,
+            ring_bullets: 16,
+            spiral_cooldown: 0.15,
+            spiral_arms: 3,
+            spiral_step: 0.25,
+            turret_cooldown: 1.5,
+            bullet_speed: 220.0,
+            hit_score: 1,
+            turret_score: 150,
+            kill_score: 1000,
+            reward_drops: 3,
+        }
+    }
+}
+
+// -----------------------------------------------------------------------------
+// POWER-UPS
+// -----------------------------------------------------------------------------
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct PowerUpBalance {
+    pub spawn_chance: f32,          // Chance de drop sur un drone détruit (0.3)
+}
+
+impl Default for PowerUpBalance {
+    fn default() -> Self {
+        Self { spawn_chance: 0.3 }
+    }
+}
+
+// -----------------------------------------------------------------------------
+// ERREURS
+// -----------------------------------------------------------------------------
+#[derive(Debug)]
+pub enum BalanceError {
+    Io(io::Error),                      // Lecture impossible
+    Parse(ron::error::SpannedError),    // Syntaxe ou champ inconnu
+    Invalid {                           // Valeur hors limites
+        field: &'static str,
+        reason: &'static str,
+    },
+}
+
+impl fmt::Display for BalanceError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BalanceError::Io(e) => write!(f, "erreur d'accès à l'équilibrage : {e}"),
+            BalanceError::Parse(e) => write!(f, "équilibrage illisible : {e}"),
+            BalanceError::Invalid { field, reason } => {
+                write!(f, "équilibrage invalide : {field} {reason}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for BalanceError {}
+
+impl From<io::Error> for BalanceError {
+    fn from(e: io::Error) -> Self {
+        BalanceError::Io(e)
+    }
+}
+
+impl From<ron::error::SpannedError> for BalanceError {
+    fn from(e: ron::error::SpannedError) -> Self {
+        BalanceError::Parse(e)
+    }
+}
+
+// -----------------------------------------------------------------------------
+// VALIDATION
+// -----------------------------------------------------------------------------
+
+/// Vérifie qu'une valeur est strictement positive
+fn positive(field: &'static str, value: f32) -> Result<(), BalanceError> {
+    if value > 0.0 && value.is_finite() {
+        Ok(())
+    } else {
+        Err(BalanceError::Invalid { field, reason: "doit être strictement positif" })
+    }
+}
+
+/// Vérifie qu'une valeur est positive ou nulle
+fn non_negative(field: &'static str, value: f32) -> Result<(), BalanceError> {
+    if value >= 0.0 && value.is_finite() {
+        Ok(())
+    } else {
+        Err(BalanceError::Invalid { field, reason: "ne peut pas être négatif" })
+    }
+}
+
+/// Vérifie qu'une fraction est dans [0, 1]
+fn fraction(field: &'static str, value: f32) -> Result<(), BalanceError> {
+    if (0.0..=1.0).contains(&value) {
+        Ok(())
+    } else {
+        Err(BalanceError::Invalid { field, reason: "doit être compris entre 0 et 1" })
+    }
+}
+
+/// Vérifie qu'un entier vaut au moins `min`
+fn at_least(field: &'static str, value: i32, min: i32) -> Result<(), BalanceError> {
+    if value >= min {
+        Ok(())
+    } else if min == 0 {
+        Err(BalanceError::Invalid { field, reason: "ne peut pas être négatif" })
+    } else {
+        Err(BalanceError::Invalid { field, reason: "doit valoir au moins 1" })
+    }
+}
+
+impl BalanceConfig {
+    /// Vérifie la cohérence des valeurs (retourne la première erreur trouvée)
+    pub fn validate(&self) -> Result<(), BalanceError> {
+        let s = &self.ship;
+        at_least("ship.lives", s.lives, 1)?;
+        at_least("ship.max_lives", s.max_lives, 1)?;
+        if s.max_lives < s.lives {
+            return Err(BalanceError::Invalid {
+                field: "ship.max_lives",
+                reason: "doit être supérieur ou égal à ship.lives",
+            });
+        }
+        non_negative("ship.invincible_time", s.invincible_time)?;
+        non_negative("ship.turn_speed", s.turn_speed)?;
+        non_negative("ship.thrust", s.thrust)?;
+        non_negative("ship.brake", s.brake)?;
+        positive("ship.reverse_boost", s.reverse_boost)?;
+        positive("ship.reverse_boost_time", s.reverse_boost_time)?;
+        fraction("ship.friction", s.friction)?;
+        positive("ship.shield_time", s.shield_time)?;
+        non_negative("ship.shield_drain", s.shield_drain)?;
+        non_negative("ship.shield_recharge", s.shield_recharge)?;
+        positive("ship.slowmo_time", s.slowmo_time)?;
+        non_negative("ship.slowmo_drain", s.slowmo_drain)?;
+        non_negative("ship.slowmo_recharge", s.slowmo_recharge)?;
+        fraction("ship.slowmo_ready", s.slowmo_ready)?;
+        // Un tick coûte SIM_DT / slowmo_factor secondes réelles : au-delà de
+        // MAX_FRAME_TIME (le plafond de l'accumulateur), il n'aurait jamais
+        // lieu et le jeu resterait figé tant que le slow-mo est maintenu
+        if !(SIM_DT / MAX_FRAME_TIME..=1.0).contains(&s.slowmo_factor) {
+            return Err(BalanceError::Invalid {
+                field: "ship.slowmo_factor",
+                reason: "doit être compris entre SIM_DT / MAX_FRAME_TIME (~0.07) et 1",
+            });
+        }
+        non_negative("ship.energy_min", s.energy_min)?;
+        positive("ship.rapid_fire_time", s.rapid_fire_time)?;
+
+        let w = &self.weapons;
+        positive("weapons.fire_rate", w.fire_rate)?;
+        positive("weapons.rapid_fire_rate", w.rapid_fire_rate)?;
+        positive("weapons.bullet_speed", w.bullet_speed)?;
+        positive("weapons.bullet_life", w.bullet_life)?;
+        non_negative("weapons.bullet_recoil", w.bullet_recoil)?;
+        at_least("weapons.missile_ammo_max", w.missile_ammo_max, 0)?;
+        positive("weapons.missile_cooldown", w.missile_cooldown)?;
+        positive("weapons.missile_regen_time", w.missile_regen_time)?;
+        positive("weapons.missile_life", w.missile_life)?;
+        positive("weapons.missile_speed", w.missile_speed)?;
+        non_negative("weapons.missile_turn_speed", w.missile_turn_speed)?;
+        at_least("weapons.missile_damage", w.missile_damage, 1)?;
+        non_negative("weapons.missile_recoil", w.missile_recoil)?;
+        positive("weapons.missile_lock_time", w.missile_lock_time)?;
+        positive("weapons.missile_lock_range", w.missile_lock_range)?;
+        non_negative("weapons.missile_salvo_spread", w.missile_salvo_spread)?;
+        positive("weapons.laser_duration", w.laser_duration)?;
+        positive("weapons.laser_cooldown", w.laser_cooldown)?;
+        at_least("weapons.laser_damage", w.laser_damage, 1)?;
+        at_least("weapons.laser_max_penetration", w.laser_max_penetration, 1)?;
+        positive("weapons.laser_range", w.laser_range)?;
+        non_negative("weapons.laser_recoil", w.laser_recoil)?;
+
+        let d = &self.drones;
+        at_least("drones.base_hp", d.base_hp, 1)?;
+        at_least("drones.waves_per_hp", d.waves_per_hp, 1)?;
+        non_negative("drones.kamikaze_speed", d.kamikaze_speed)?;
+        fraction("drones.kamikaze_steering", d.kamikaze_steering)?;
+        non_negative("drones.sniper_speed", d.sniper_speed)?;
+        non_negative("drones.bomber_speed_x", d.bomber_speed_x)?;
+        non_negative("drones.bomber_speed_y", d.bomber_speed_y)?;
+        non_negative("drones.sniper_first_shot", d.sniper_first_shot)?;
+        positive("drones.sniper_cooldown", d.sniper_cooldown)?;
+        positive("drones.sniper_bullet_speed", d.sniper_bullet_speed)?;
+        non_negative("drones.sniper_lead", d.sniper_lead)?;
+        positive("drones.bomber_cooldown", d.bomber_cooldown)?;
+        positive("drones.bomber_bullet_speed", d.bomber_bullet_speed)?;
+        positive("drones.bullet_life", d.bullet_life)?;
+
+        let a = &self.asteroids;
+        fraction("asteroids.large_chance", a.large_chance)?;
+        at_least("asteroids.fragments_min", a.fragments_min, 1)?;
+        if a.fragments_max < a.fragments_min {
+            return Err(BalanceError::Invalid {
+                field: "asteroids.fragments_max",
+                reason: "doit valoir au moins fragments_min",
+            });
+        }
+        non_negative("asteroids.fragment_speed", a.fragment_speed)?;
+        at_least("asteroids.large_score", a.large_score, 0)?;
+        at_least("asteroids.medium_score", a.medium_score, 0)?;
+        at_least("asteroids.small_score", a.small_score, 0)?;
+        fraction("asteroids.large_drop_chance", a.large_drop_chance)?;
+        fraction("asteroids.medium_drop_chance", a.medium_drop_chance)?;
+        fraction("asteroids.small_drop_chance", a.small_drop_chance)?;
+
+        let v = &self.waves;
+        at_least("waves.base_size", v.base_size, 1)?;
+        at_least("waves.size_per_wave", v.size_per_wave, 0)?;
+        at_least("waves.completion_bonus", v.completion_bonus, 0)?;
+        positive("waves.base_difficulty", v.base_difficulty)?;
+        non_negative("waves.difficulty_per_wave", v.difficulty_per_wave)?;
+        positive("waves.asteroid_spawn_rate", v.asteroid_spawn_rate)?;
+        positive("waves.drone_spawn_rate", v.drone_spawn_rate)?;
+        at_least("waves.drone_cap", v.drone_cap, 0)?;
+        at_least("waves.asteroid_cap", v.asteroid_cap, 0)?;
+
+        let b = &self.boss;
+        if b.every < 2 {
+            return Err(BalanceError::Invalid {
+                field: "boss.every",
+                reason: "doit valoir au moins 2 (la première vague n'est jamais un boss)",
+            });
+        }
+        at_least("boss.core_hp", b.core_hp, 1)?;
+        at_least("boss.core_hp_per_boss", b.core_hp_per_boss, 0)?;
+        at_least("boss.turret_count", b.turret_count, 0)?;
+        at_least("boss.turret_hp", b.turret_hp, 1)?;
+        non_negative("boss.speed", b.speed)?;
+        fraction("boss.steering", b.steering)?;
+        non_negative("boss.hover_distance", b.hover_distance)?;
+        fraction("boss.phase2_health", b.phase2_health)?;
+        fraction("boss.phase3_health", b.phase3_health)?;
+        if b.phase3_health > b.phase2_health {
+            return Err(BalanceError::Invalid {
+                field: "boss.phase3_health",
+                reason: "doit être inférieur ou égal à phase2_health",
+            });
+        }
+        non_negative("boss.phase3_speed", b.phase3_speed)?;
+        positive("boss.fan_cooldown", b.fan_cooldown)?;
+        at_least("boss.fan_bullets", b.fan_bullets, 1)?;
+        non_negative("boss.fan_spread", b.fan_spread)?;
+        positive("boss.ring_cooldown", b.ring_cooldown)?;
+        at_least("boss.ring_bullets", b.ring_bullets, 1)?;
+        positive("boss.spiral_cooldown", b.spiral_cooldown)?;
+        at_least("boss.spiral_arms", b.spiral_arms, 1)?;
+        non_negative("boss.spiral_step", b.spiral_step)?;
+        positive("boss.turret_cooldown", b.turret_cooldown)?;
+        positive("boss.bullet_speed", b.bullet_speed)?;
+        at_least("boss.hit_score", b.hit_score, 0)?;
+        at_least("boss.turret_score", b.turret_score, 0)?;
+        at_least("boss.kill_score", b.kill_score, 0)?;
+        at_least("boss.reward_drops", b.reward_drops, 1)?;
+
+        fraction("powerups.spawn_chance", self.powerups.spawn_chance)?;
+        Ok(())
+    }
+
+    // -------------------------------------------------------------------------
+    // FORMAT TEXTE
+    // -------------------------------------------------------------------------
+
+    /// Lit et valide une configuration RON
+    pub fn from_ron(text: &str) -> Result<Self, BalanceError> {
+        let config: BalanceConfig = ron::from_str(text)?;
+        config.validate()?;
+        Ok(config)
+    }
+
+    /// Lit et valide le fichier d'équilibrage
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, BalanceError> {
+        Self::from_ron(&fs::read_to_string(path)?)
+    }
+
+    /// Sérialise la configuration en RON (ex : exporter les valeurs par défaut)
+    pub fn to_ron(&self) -> Result<String, ron::Error> {
+        ron::ser::to_string_pretty(self, ron::ser::PrettyConfig::default())
+    }
+}
+
+// -----------------------------------------------------------------------------
+// RECHARGEMENT À CHAUD
+// -----------------------------------------------------------------------------
+pub struct BalanceWatcher {
+    path: PathBuf,
+    modified: Option<SystemTime>,   // Date de modification au dernier chargement
+    elapsed: f32,                   // Temps depuis la dernière vérification
+}
+
+impl BalanceWatcher {
+    /// Surveille le fichier `path` (le premier `poll` le charge s'il existe)
+    pub fn new(path: impl AsRef<Path>) -> Self {
+        Self {
+            path: path.as_ref().to_path_buf(),
+            modified: None,
+            elapsed: BALANCE_POLL_INTERVAL,
+        }
+    }
+
+    /// Chemin du fichier surveillé
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Vérifie périodiquement le fichier ; retourne la nouvelle configuration
+    /// (ou l'erreur de chargement) seulement si le fichier a changé
+    pub fn poll(&mut self, dt: f32) -> Option<Result<BalanceConfig, BalanceError>> {
+        self.elapsed += dt;
+        if self.elapsed < BALANCE_POLL_INTERVAL {
+            return None;
+        }
+        self.elapsed = 0.0;
+
+        // Fichier absent : on garde la configuration en cours
+        let modified = fs::metadata(&self.path).and_then(|m| m.modified()).ok()?;
+        if self.modified == Some(modified) {
+            return None;
+        }
+        self.modified = Some(modified);
+        Some(BalanceConfig::load(&self.path))
+    }
+
+    /// Force le rechargement au prochain `poll`
+    pub fn invalidate(&mut self) {
+        self.modified = None;
+        self.elapsed = BALANCE_POLL_INTERVAL;
+    }
+}
+
+// =============================================================================
+// TESTS
+// =============================================================================
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Champ rejeté par la validation (None si la configuration est valide)
+    fn rejected(config: &BalanceConfig) -> Option<&'static str> {
+        match config.validate() {
+            Ok(()) => None,
+            Err(BalanceError::Invalid { field, .. }) => Some(field),
+            Err(e) => panic!("erreur inattendue : {e}"),
+        }
+    }
+
+    #[test]
+    fn defaults_are_valid() {
+        assert_eq!(rejected(&BalanceConfig::default()), None);
+    }
+
+    /// Retouche d'une configuration par défaut
+    type Tweak = fn(&mut BalanceConfig);
+
+    #[test]
+    fn out_of_range_values_are_rejected() {
+        let cases: [(&str, Tweak); 8] = [
+            ("ship.lives", |c| c.ship.lives = 0),
+            ("ship.max_lives", |c| c.ship.max_lives = c.ship.lives - 1),
+            ("ship.friction", |c| c.ship.friction = 1.5),
+            ("weapons.fire_rate", |c| c.weapons.fire_rate = 0.0),
+            ("asteroids.fragments_max", |c| c.asteroids.fragments_max = c.asteroids.fragments_min - 1),
+            ("waves.asteroid_cap", |c| c.waves.asteroid_cap = -1),
+            ("boss.every", |c| c.boss.every = 1),
+            ("boss.phase3_health", |c| {
+                c.boss.phase2_health = 0.3;
+                c.boss.phase3_health = 0.5;
+            }),
+        ];
+        for (field, tweak) in cases {
+            let mut config = BalanceConfig::default();
+            tweak(&mut config);
+            assert_eq!(rejected(&config), Some(field));
+        }
+    }
+
+    #[test]
+    fn slowmo_factor_keeps_ticks_within_frame_cap() {
+        let mut config = BalanceConfig::default();
+        for (factor, valid) in [(0.0, false), (0.05, false), (0.07, true), (1.0, true), (1.1, false)] {
+            config.ship.slowmo_factor = factor;
+            assert_eq!(rejected(&config).is_none(), valid, "slowmo_factor = {factor}");
+        }
+        // Le facteur le plus lent accepté laisse passer au moins un tick
+        config.ship.slowmo_factor = SIM_DT / MAX_FRAME_TIME;
+        assert_eq!(rejected(&config), None);
+        assert!(SIM_DT / config.ship.slowmo_factor <= MAX_FRAME_TIME);
+    }
+
+    #[test]
+    fn parse_errors_are_reported() {
+        assert!(matches!(BalanceConfig::from_ron("(ship: (lifes: 3))"), Err(BalanceError::Parse(_))));
+        assert!(matches!(
+            BalanceConfig::from_ron("(ship: (lives: 0))"),
+            Err(BalanceError::Invalid { field: "ship.lives", .. })
+        ));
+        assert!(BalanceConfig::from_ron("()").is_ok());
+    }
+}
@@ -0,0 +1,66 @@
+// =============================================================================
+// EXPORT DES EFFETS SONORES EN WAV
+// =============================================================================
+//
+// Petit outil pour écouter les sons hors du jeu : synthétise chaque son
+// décrit dans sounds.ron et l'écrit en WAV mono dans un dossier.
+//
+// Usage : cargo run --bin sfx_export -- [fichier des sons] [dossier de sortie]
+// (par défaut : sounds.ron et sfx/)
+//
+// =============================================================================
+
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+use std::process::ExitCode;
+use void_survivor::audio::{encode_wav, SoundCue};
+use void_survivor::synth::{self, SfxConfig, SAMPLE_RATE};
+
+/// Dossier de sortie par défaut
+const OUTPUT_DIR: &str = "sfx";
+
+/// Nom de fichier d'un son (ex : ShieldBlock → shield_block.wav)
+fn file_name(cue: SoundCue) -> String {
+    let mut name = String::new();
+    for (i, c) in format!("{cue:?}").chars().enumerate() {
+        if c.is_uppercase() && i > 0 {
+            name.push('_');
+        }
+        name.push(c.to_ascii_lowercase());
+    }
+    name + ".wav"
+}
+
+fn main() -> ExitCode {
+    let mut args = env::args().skip(1);
+    let config_path = args.next().unwrap_or_else(|| synth::SOUNDS_PATH.to_string());
+    let output_dir = PathBuf::from(args.next().unwrap_or_else(|| OUTPUT_DIR.to_string()));
+
+    let config = match SfxConfig::load(&config_path) {
+        Ok(config) => config,
+        Err(e) => {
+            eprintln!("{config_path} : {e}");
+            return ExitCode::FAILURE;
+        }
+    };
+    if let Err(e) = fs::create_dir_all(&output_dir) {
+        eprintln!("Impossible de créer {} : {e}", output_dir.display());
+        return ExitCode::FAILURE;
+    }
+
+    for cue in SoundCue::ALL {
+        let samples = synth::render(config.get(cue), SAMPLE_RATE);
+        let path = output_dir.join(file_name(cue));
+        if let Err(e) = fs::write(&path, encode_wav(&samples, 1, SAMPLE_RATE)) {
+            eprintln!("Impossible d'écrire {} : {e}", path.display());
+            return ExitCode::FAILURE;
+        }
+        println!(
+            "{} ({:.2} s)",
+            path.display(),
+            samples.len() as f32 / SAMPLE_RATE as f32
+        );
+    }
+    ExitCode::SUCCESS
+}
@@ -0,0 +1,363 @@
+// =============================================================================
+// ASSOCIATION ACTIONS → TOUCHES (RECONFIGURABLE)
+// =============================================================================
+//
+// Ce fichier remplace les touches figées à la compilation pour le pilotage :
+// - Action : actions du joueur reconfigurables (mouvements, armes, pause)
+// - KeyBindings : une ou plusieurs touches par action
+// - Préréglages QWERTY et AZERTY
+// - Détection des conflits (une touche pour deux actions, touche réservée)
+// - Noms de touches lisibles (fichier de réglages et affichage)
+//
+// Les touches de navigation des menus (Entrée, Échap, Tab...) restent fixes
+// dans consts.rs : on ne peut pas se retrouver bloqué dans un menu.
+//
+// =============================================================================
+
+use macroquad::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt;
+use crate::consts::*;
+
+/// Nombre maximal de touches pour une même action
+pub const MAX_KEYS_PER_ACTION: usize = 3;
+
+/// Touches qui ne peuvent pas être associées à une action
+pub const RESERVED_KEYS: [KeyCode; 3] = [KEY_MENU, KEY_DEBUG_HITBOXES, KEY_RELOAD_BALANCE];
+
+// -----------------------------------------------------------------------------
+// ACTIONS RECONFIGURABLES
+// -----------------------------------------------------------------------------
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum Action {
+    Thrust,     // Avancer
+    Brake,      // Reculer
+    TurnLeft,   // Tourner à gauche
+    TurnRight,  // Tourner à droite
+    Shoot,      // Tir normal
+    Shield,     // Bouclier
+    Boost,      // Recul boosté
+    Slowmo,     // Slow-motion
+    Missile,    // Missile téléguidé
+    Laser,      // Laser perçant
+    Pause,      // Mettre en pause
+}
+
+impl Action {
+    /// Toutes les actions, dans l'ordre d'affichage
+    pub const ALL: [Action; 11] = [
+        Action::Thrust,
+        Action::Brake,
+        Action::TurnLeft,
+        Action::TurnRight,
+        Action::Shoot,
+        Action::Shield,
+        Action::Boost,
+        Action::Slowmo,
+        Action::Missile,
+        Action::Laser,
+        Action::Pause,
+    ];
+
+    /// Libellé affiché dans les menus
+    pub fn label(self) -> &'static str {
+        match self {
+            Action::Thrust => "Avancer",
+            Action::Brake => "Reculer",
+            Action::TurnLeft => "Tourner à gauche",
+            Action::TurnRight => "Tourner à droite",
+            Action::Shoot => "Tirer",
+            Action::Shield => "Bouclier",
+            Action::Boost => "Recul rapide",
+            Action::Slowmo => "Slow-motion",
+            Action::Missile => "Missile téléguidé",
+            Action::Laser => "Laser perçant",
+            Action::Pause => "Pause",
+        }
+    }
+}
+
+// -----------------------------------------------------------------------------
+// PRÉRÉGLAGES DE CLAVIER
+// -----------------------------------------------------------------------------
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum KeyboardLayout {
+    Qwerty,
+    Azerty,
+}
+
+impl KeyboardLayout {
+    pub fn label(self) -> &'static str {
+        match self {
+            KeyboardLayout::Qwerty => "QWERTY",
+            KeyboardLayout::Azerty => "AZERTY",
+        }
+    }
+}
+
+// -----------------------------------------------------------------------------
+// SCHÉMAS DE CONTRÔLE
+// -----------------------------------------------------------------------------
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ControlScheme {
+    #[default]
+    Tank,       // Rotation + poussée dans la direction du vaisseau
+    TwinStick,  // Visée à la souris, déplacement dans les directions de l'écran
+}
+
+impl ControlScheme {
+    pub fn label(self) -> &'static str {
+        match self {
+            ControlScheme::Tank => "Clavier (rotation)",
+            ControlScheme::TwinStick => "Souris + clavier (twin-stick)",
+        }
+    }
+
+    /// Schéma suivant (bascule dans l'écran des touches)
+    pub fn next(self) -> Self {
+        match self {
+            ControlScheme::Tank => ControlScheme::TwinStick,
+            ControlScheme::TwinStick => ControlScheme::Tank,
+        }
+    }
+}
+
+// -----------------------------------------------------------------------------
+// CONFLITS
+// -----------------------------------------------------------------------------
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BindingConflict {
+    Shared(KeyCode, Action, Action),    // Même touche pour deux actions
+    Reserved(KeyCode, Action),          // Touche réservée aux menus
+}
+
+impl BindingConflict {
+    /// Vérifie si le conflit concerne cette action
+    pub fn involves(&self, action: Action) -> bool {
+        match *self {
+            BindingConflict::Shared(_, a, b) => a == action || b == action,
+            BindingConflict::Reserved(_, a) => a == action,
+        }
+    }
+}
+
+impl fmt::Display for BindingConflict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BindingConflict::Shared(key, a, b) => write!(
+                f,
+                "{} utilisée par « {} » et « {} »",
+                key_name(*key),
+                a.label(),
+                b.label()
+            ),
+            BindingConflict::Reserved(key, action) => write!(
+                f,
+                "{} est réservée aux menus (« {} »)",
+                key_name(*key),
+                action.label()
+            ),
+        }
+    }
+}
+
+// -----------------------------------------------------------------------------
+// TABLE DES TOUCHES
+// -----------------------------------------------------------------------------
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "BTreeMap<Action, Vec<String>>", into = "BTreeMap<Action, Vec<String>>")]
+pub struct KeyBindings {
+    keys: BTreeMap<Action, Vec<KeyCode>>,
+}
+
+impl KeyBindings {
+    /// Préréglage complet pour une disposition de clavier
+    /// Les flèches et Espace sont communs à toutes les dispositions
+    pub fn preset(layout: KeyboardLayout) -> Self {
+        use KeyCode::*;
+        let (up, left, down, right) = match layout {
+            KeyboardLayout::Qwerty => (W, A, S, D),
+            KeyboardLayout::Azerty => (Z, Q, S, D),
+        };
+        let table = [
+            (Action::Thrust, vec![up, Up]),
+            (Action::Brake, vec![down, Down]),
+            (Action::TurnLeft, vec![left, Left]),
+            (Action::TurnRight, vec![right, Right]),
+            (Action::Shoot, vec![I, Space]),
+            (Action::Shield, vec![U]),
+            (Action::Boost, vec![K]),
+            (Action::Slowmo, vec![H]),
+            (Action::Missile, vec![J]),
+            (Action::Laser, vec![L]),
+            (Action::Pause, vec![P]),
+        ];
+        Self {
+            keys: table.into_iter().collect(),
+        }
+    }
+
+    /// Touches associées à une action
+    pub fn keys(&self, action: Action) -> &[KeyCode] {
+        self.keys.get(&action).map_or(&[], Vec::as_slice)
+    }
+
+    /// Action utilisant déjà cette touche (s'il y en a une)
+    pub fn action_for(&self, key: KeyCode) -> Option<Action> {
+        Action::ALL
+            .into_iter()
+            .find(|&action| self.keys(action).contains(&key))
+    }
+
+    /// Ajoute une touche à une action
+    /// Refusée si la touche est réservée ou déjà prise par une autre action
+    pub fn bind(&mut self, action: Action, key: KeyCode) -> Result<(), BindingConflict> {
+        if RESERVED_KEYS.contains(&key) {
+            return Err(BindingConflict::Reserved(key, action));
+        }
+        match self.action_for(key) {
+            Some(other) if other == action => return Ok(()),
+            Some(other) => return Err(BindingConflict::Shared(key, other, action)),
+            None => {}
+        }
+
+        let keys = self.keys.entry(action).or_default();
+        if keys.len() >= MAX_KEYS_PER_ACTION {
+            keys.remove(0);  // La plus ancienne touche laisse sa place
+        }
+        keys.push(key);
+        Ok(())
+    }
+
+    /// Retire toutes les touches d'une action (l'action reste sans touche)
+    pub fn clear(&mut self, action: Action) {
+        self.keys.insert(action, Vec::new());
+    }
+
+    /// Vérifie si l'action figure dans la table (même sans touche)
+    pub fn is_configured(&self, action: Action) -> bool {
+        self.keys.contains_key(&action)
+    }
+
+    /// Liste tous les conflits (fichier de réglages édité à la main)
+    pub fn conflicts(&self) -> Vec<BindingConflict> {
+        let mut conflicts = Vec::new();
+        let mut owners: BTreeMap<u32, Action> = BTreeMap::new();
+        for action in Action::ALL {
+            for &key in self.keys(action) {
+                if RESERVED_KEYS.contains(&key) {
+                    conflicts.push(BindingConflict::Reserved(key, action));
+                } else if let Some(&other) = owners.get(&(key as u32)) {
+                    if other != action {
+                        conflicts.push(BindingConflict::Shared(key, other, action));
+                    }
+                } else {
+                    owners.insert(key as u32, action);
+                }
+            }
+        }
+        conflicts
+    }
+
+    /// Vérifie si une des touches de l'action est maintenue
+    pub fn down(&self, action: Action) -> bool {
+        self.keys(action).iter().any(|&k| is_key_down(k))
+    }
+
+    /// Vérifie si une des touches de l'action vient d'être enfoncée
+    pub fn pressed(&self, action: Action) -> bool {
+        self.keys(action).iter().any(|&k| is_key_pressed(k))
+    }
+
+    /// Touches de l'action pour l'affichage (ex : "I/Space", "-" si aucune)
+    pub fn describe(&self, action: Action) -> String {
+        let keys = self.keys(action);
+        if keys.is_empty() {
+            return "-".to_string();
+        }
+        keys.iter().map(|&k| key_name(k)).collect::<Vec<_>>().join("/")
+    }
+}
+
+impl Default for KeyBindings {
+    fn default() -> Self {
+        Self::preset(KeyboardLayout::Qwerty)
+    }
+}
+
+// Format du fichier : { Shoot: ["I", "Space"], ... }
+impl TryFrom<BTreeMap<Action, Vec<String>>> for KeyBindings {
+    type Error = String;
+
+    fn try_from(map: BTreeMap<Action, Vec<String>>) -> Result<Self, Self::Error> {
+        let mut keys = BTreeMap::new();
+        for (action, names) in map {
+            let codes = names
+                .iter()
+                .map(|name| key_from_name(name).ok_or(format!("touche inconnue « {name} »")))
+                .collect::<Result<Vec<_>, _>>()?;
+            keys.insert(action, codes);
+        }
+        Ok(Self { keys })
+    }
+}
+
+impl From<KeyBindings> for BTreeMap<Action, Vec<String>> {
+    fn from(bindings: KeyBindings) -> Self {
+        bindings
+            .keys
+            .into_iter()
+            .map(|(action, codes)| {
+                (action, codes.into_iter().map(|k| key_name(k).to_string()).collect())
+            })
+            .collect()
+    }
+}
+
+// -----------------------------------------------------------------------------
+// NOMS DES TOUCHES
+// -----------------------------------------------------------------------------
+
+// Même nom que la variante KeyCode (identique à l'affichage {:?})
+macro_rules! key_names {
+    ($($key:ident),* $(,)?) => {
+        const KEY_NAMES: &[(KeyCode, &str)] = &[$((KeyCode::$key, stringify!($key))),*];
+    };
+}
+
+key_names!(
+    A, B, C, D, E, F, G, H, I, J, K, L, M, N, O, P, Q, R, S, T, U, V, W, X, Y, Z,
+    Key0, Key1, Key2, Key3, Key4, Key5, Key6, Key7, Key8, Key9,
+    Space, Apostrophe, Comma, Minus, Period, Slash, Semicolon, Equal,
+    LeftBracket, Backslash, RightBracket, GraveAccent,
+    Escape, Enter, Tab, Backspace, Insert, Delete,
+    Right, Left, Down, Up, PageUp, PageDown, Home, End,
+    CapsLock, ScrollLock, NumLock, PrintScreen, Pause,
+    F1, F2, F3, F4, F5, F6, F7, F8, F9, F10, F11, F12,
+    Kp0, Kp1, Kp2, Kp3, Kp4, Kp5, Kp6, Kp7, Kp8, Kp9,
+    KpDecimal, KpDivide, KpMultiply, KpSubtract, KpAdd, KpEnter, KpEqual,
+    LeftShift, LeftControl, LeftAlt, LeftSuper,
+    RightShift, RightControl, RightAlt, RightSuper,
+);
+
+/// Nom lisible d'une touche ("?" si elle n'est pas dans la table)
+pub fn key_name(key: KeyCode) -> &'static str {
+    KEY_NAMES
+        .iter()
+        .find(|(k, _)| *k == key)
+        .map_or("?", |(_, name)| name)
+}
+
+/// Touche correspondant à un nom (insensible à la casse)
+pub fn key_from_name(name: &str) -> Option<KeyCode> {
+    KEY_NAMES
+        .iter()
+        .find(|(_, n)| n.eq_ignore_ascii_case(name))
+        .map(|(k, _)| *k)
+}
+
+/// Vérifie si une touche peut être associée à une action
+pub fn is_bindable(key: KeyCode) -> bool {
+    key_name(key) != "?"
+}
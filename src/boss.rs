@@ -0,0 +1,307 @@
+// =============================================================================
+// BOSS (VAISSEAU-MÈRE)
+// =============================================================================
+//
+// Toutes les `boss.every` vagues, les apparitions aléatoires laissent la
+// place à un vaisseau-mère (voir Game::handle_waves) :
+// - un noyau (BossPartKind::Core), octogone qui se tient au-dessus du
+//   vaisseau et tire selon sa phase
+// - des tourelles (BossPartKind::Turret) fixées sur sa coque, qui visent le
+//   joueur et se détruisent une à une
+//
+// Chaque partie est une entité du monde (World::boss_parts), avec sa forme
+// de collision et sa santé (Damageable, voir damage.rs) : balles, missiles,
+// laser, bouclier et chocs la touchent par les mêmes chemins que les
+// drones, et ses dégâts passent par DamageContext.
+//
+// Phases du noyau, selon sa santé restante (BossBalance::phase2_health et
+// phase3_health) :
+// - phase 1 : éventail de balles visé sur le joueur
+// - phase 2 : couronnes de balles qui tournent d'une salve à l'autre
+// - phase 3 : spirale continue, déplacement plus rapide
+// Les tourelles tirent d'autant plus vite que la phase avance.
+//
+// La destruction du noyau emporte les tourelles restantes, termine la vague
+// et lâche des power-ups garantis, dont une vie (Enemy::loot).
+//
+// =============================================================================
+
+use macroquad::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::f32::consts::TAU;
+use crate::arena::Arena;
+use crate::balance::{BalanceConfig, BossBalance};
+use crate::collider::Collider;
+use crate::consts::*;
+use crate::damage::{DamageContext, DamageInfo, Damageable};
+use crate::enemies::Bullet;
+use crate::events::{Event, EventBus};
+use crate::game::Cooldown;
+use crate::slotmap::{EntityId, SlotMap};
+use crate::weapons::WeaponKind;
+
+// -----------------------------------------------------------------------------
+// PARTIE DU VAISSEAU-MÈRE
+// -----------------------------------------------------------------------------
+#[derive(PartialEq, Eq, Clone, Copy, Debug, Serialize, Deserialize)]
+pub enum BossPartKind {
+    Core,       // Noyau : porte la santé du boss et ses phases
+    Turret,     // Tourelle destructible fixée sur la coque
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct BossPart {
+    pub kind: BossPartKind,
+    pub pos: Vec2,
+    pub prev_pos: Vec2,     // Position au tick précédent (interpolation du rendu)
+    pub vel: Vec2,
+    pub hp: i32,
+    pub max_hp: i32,
+    pub cooldown: Cooldown, // Délai avant le prochain tir
+    pub rotation: f32,      // Orientation de la coque (tourelle : celle du noyau)
+    pub mount: Vec2,        // Tourelle : position sur la coque, coque non tournée
+    pub phase: i32,         // Noyau : phase en cours (1 à 3)
+    pub pattern_angle: f32, // Noyau : angle des couronnes et de la spirale
+}
+
+impl BossPart {
+    /// Vaisseau-mère complet en `pos` : le noyau d'abord, puis ses tourelles
+    /// réparties sur la coque. `rank` : nombre de boss déjà apparus
+    pub fn mothership(pos: Vec2, rank: i32, balance: &BossBalance) -> Vec<BossPart> {
+        let core_hp = balance.core_hp + rank * balance.core_hp_per_boss;
+        let core = Self::new(BossPartKind::Core, pos, Vec2::ZERO, core_hp, balance.fan_cooldown);
+
+        let count = balance.turret_count;
+        let turrets = (0..count).map(|i| {
+            // Décalage d'un demi-pas : pas de tourelle droit sous le noyau
+            let angle = (i as f32 + 0.5) * TAU / count as f32;
+            let mount = Vec2::from_angle(angle) * BOSS_CORE_RADIUS;
+            // Premiers tirs décalés d'une tourelle à l'autre
+            let first_shot = balance.turret_cooldown * (1.0 + i as f32 / count as f32);
+            Self::new(BossPartKind::Turret, pos + mount, mount, balance.turret_hp, first_shot)
+        });
+        std::iter::once(core).chain(turrets).collect()
+    }
+
+    fn new(kind: BossPartKind, pos: Vec2, mount: Vec2, hp: i32, first_shot: f32) -> Self {
+        Self {
+            kind,
+            pos,
+            prev_pos: pos,
+            vel: Vec2::ZERO,
+            hp,
+            max_hp: hp,
+            cooldown: Cooldown::full(first_shot),
+            rotation: 0.0,
+            mount,
+            phase: 1,
+            pattern_angle: 0.0,
+        }
+    }
+
+    /// Phase correspondant à la santé restante
+    pub fn phase_for_health(&self, balance: &BossBalance) -> i32 {
+        let health = self.health_percent();
+        if health <= balance.phase3_health {
+            3
+        } else if health <= balance.phase2_health {
+            2
+        } else {
+            1
+        }
+    }
+
+    /// Noyau : se place à `hover_distance` au-dessus du vaisseau (`ship_pos`)
+    fn steer(&mut self, dt: f32, ship_pos: Vec2, balance: &BossBalance) {
+        self.cooldown.tick(dt);
+        let speed = if self.phase >= 3 { balance.speed * balance.phase3_speed } else { balance.speed };
+        let target = ship_pos - vec2(0.0, balance.hover_distance);
+        let desired = (target - self.pos).clamp_length_max(speed);
+        self.vel = self.vel.lerp(desired, balance.steering);
+        self.pos += self.vel * dt;
+        self.rotation += balance.spin * dt;
+    }
+
+    /// Tourelle : suit la coque du noyau (position et orientation)
+    fn follow(&mut self, dt: f32, core: &BossPart) {
+        self.cooldown.tick(dt);
+        self.rotation = core.rotation;
+        self.pos = core.pos + Vec2::from_angle(core.rotation).rotate(self.mount);
+        self.vel = core.vel;
+    }
+
+    /// Tire selon le type et la phase (aucune balle si le délai court encore)
+    /// `id` : identifiant de la partie, propriétaire des balles
+    fn fire(&mut self, id: EntityId, ship_pos: Vec2, phase: i32, balance: &BalanceConfig) -> Vec<Bullet> {
+        if !self.cooldown.ready() {
+            return Vec::new();
+        }
+        let boss = &balance.boss;
+        let aim = (ship_pos - self.pos).normalize_or(Vec2::Y);
+        let aim_angle = aim.y.atan2(aim.x);
+        let (angles, cooldown): (Vec<f32>, f32) = match (self.kind, phase) {
+            (BossPartKind::Turret, _) => (vec![aim_angle], boss.turret_cooldown / phase as f32),
+            (BossPartKind::Core, 1) => {
+                let half = (boss.fan_bullets - 1) as f32 * 0.5;
+                let fan = (0..boss.fan_bullets)
+                    .map(|i| aim_angle + (i as f32 - half) * boss.fan_spread)
+                    .collect();
+                (fan, boss.fan_cooldown)
+            }
+            (BossPartKind::Core, 2) => {
+                let step = TAU / boss.ring_bullets as f32;
+                self.pattern_angle += step * 0.5;
+                let ring = (0..boss.ring_bullets)
+                    .map(|i| self.pattern_angle + i as f32 * step)
+                    .collect();
+                (ring, boss.ring_cooldown)
+            }
+            (BossPartKind::Core, _) => {
+                let step = TAU / boss.spiral_arms as f32;
+                self.pattern_angle += boss.spiral_step;
+                let arms = (0..boss.spiral_arms)
+                    .map(|i| self.pattern_angle + i as f32 * step)
+                    .collect();
+                (arms, boss.spiral_cooldown)
+            }
+        };
+        self.cooldown.time = cooldown;
+
+        let size = match self.kind {
+            BossPartKind::Core => 6.0,
+            BossPartKind::Turret => 4.0,
+        };
+        angles
+            .into_iter()
+            .map(|angle| {
+                let dir = Vec2::from_angle(angle);
+                let muzzle = self.pos + dir * self.radius();
+                Bullet {
+                    pos: muzzle,
+                    prev_pos: muzzle,
+                    vel: dir * boss.bullet_speed,
+                    enemy: true,
+                    owner: Some(id),
+                    size,
+                    life: balance.drones.bullet_life,
+                }
+            })
+            .collect()
+    }
+
+    /// Rayon de la forme (octogone du noyau, hexagone d'une tourelle)
+    pub fn radius(&self) -> f32 {
+        match self.kind {
+            BossPartKind::Core => BOSS_CORE_RADIUS,
+            BossPartKind::Turret => BOSS_TURRET_RADIUS,
+        }
+    }
+
+    /// Toujours actif tant qu'il n'est pas détruit : le vaisseau-mère ne
+    /// quitte pas la partie
+    pub fn is_alive(&self) -> bool {
+        !self.is_destroyed()
+    }
+
+    /// Forme à une position (collisions et rendu)
+    pub fn hull_at(&self, pos: Vec2) -> Collider {
+        match self.kind {
+            BossPartKind::Core => Collider::regular_polygon(pos, 8, BOSS_CORE_RADIUS, self.rotation),
+            BossPartKind::Turret => Collider::regular_polygon(pos, 6, BOSS_TURRET_RADIUS, self.rotation),
+        }
+    }
+
+    /// Forme de collision
+    pub fn collider(&self) -> Collider {
+        self.hull_at(self.pos)
+    }
+
+    /// Forme de collision de la copie la plus proche de `near`
+    pub fn collider_near(&self, arena: &Arena, near: Vec2) -> Collider {
+        self.hull_at(arena.image_near(self.pos, near))
+    }
+
+    /// Masse (aire du disque) pour les chocs élastiques
+    pub fn mass(&self) -> f32 {
+        std::f32::consts::PI * self.radius() * self.radius()
+    }
+
+    /// Pourcentage de vie restant (barre de vie)
+    pub fn health_percent(&self) -> f32 {
+        self.hp as f32 / self.max_hp.max(1) as f32
+    }
+}
+
+// -----------------------------------------------------------------------------
+// SIMULATION DU VAISSEAU-MÈRE
+// -----------------------------------------------------------------------------
+
+/// Noyau vivant du vaisseau-mère (None hors des vagues de boss)
+pub fn core(parts: &SlotMap<BossPart>) -> Option<&BossPart> {
+    parts.iter().find(|p| p.kind == BossPartKind::Core && !p.is_destroyed())
+}
+
+/// Déplace le vaisseau-mère (le noyau, puis les tourelles sur sa coque) et
+/// ajoute ses tirs à `bullets` ; signale les changements de phase
+/// Le noyau poursuit et vise la copie du vaisseau la plus proche
+pub fn update_boss(
+    parts: &mut SlotMap<BossPart>,
+    bullets: &mut SlotMap<Bullet>,
+    dt: f32,
+    ship_pos: Vec2,
+    arena: &Arena,
+    events: &mut EventBus,
+    balance: &BalanceConfig,
+) {
+    let mut hull = None;
+    for (id, part) in parts.iter_mut_with_ids() {
+        if part.kind != BossPartKind::Core || part.is_destroyed() {
+            continue;
+        }
+        let phase = part.phase_for_health(&balance.boss);
+        if phase != part.phase {
+            part.phase = phase;
+            part.cooldown.set(0.0);     // Nouveau motif sans attendre
+            events.emit(Event::BossPhaseChanged { phase, pos: part.pos });
+        }
+        part.steer(dt, arena.image_near(ship_pos, part.pos), &balance.boss);
+        arena.wrap_motion(&mut part.pos, &mut part.prev_pos);
+        for bullet in part.fire(id, arena.image_near(ship_pos, part.pos), phase, balance) {
+            bullets.push(bullet);
+        }
+        hull = Some(part.clone());
+    }
+
+    // Tourelles (immobiles si le noyau vient d'être détruit : voir scuttle)
+    let Some(core) = hull else {
+        return;
+    };
+    for (id, part) in parts.iter_mut_with_ids() {
+        if part.kind != BossPartKind::Turret || part.is_destroyed() {
+            continue;
+        }
+        part.follow(dt, &core);
+        arena.wrap_motion(&mut part.pos, &mut part.prev_pos);
+        for bullet in part.fire(id, arena.image_near(ship_pos, part.pos), core.phase, balance) {
+            bullets.push(bullet);
+        }
+    }
+}
+
+/// Noyau détruit : les tourelles restantes sautent avec lui (sans points ;
+/// la destruction du noyau a déjà tout rapporté)
+pub fn scuttle(parts: &mut SlotMap<BossPart>, ctx: &mut DamageContext) {
+    let core_destroyed = parts
+        .iter()
+        .any(|p| p.kind == BossPartKind::Core && p.is_destroyed());
+    if !core_destroyed {
+        return;
+    }
+    for (id, part) in parts.iter_mut_with_ids() {
+        if part.kind == BossPartKind::Turret && !part.is_destroyed() {
+            let wreck = DamageInfo::new(part.health(), WeaponKind::Collision, part.pos);
+            ctx.damage_enemy(id, part, wreck);
+        }
+    }
+    ctx.shake.trigger(1.0);
+}
@@ -0,0 +1,99 @@
+// =============================================================================
+// CAMÉRA (MONDE PLUS GRAND QUE L'ÉCRAN)
+// =============================================================================
+//
+// Le monde (Arena::size) fait plusieurs écrans ; la vue (Arena::view) en
+// montre une portion de la taille de la fenêtre :
+// - centrée sur le vaisseau, avec une avance dans le sens du déplacement
+//   (lissée d'un tick à l'autre pour que la vue ne saute pas)
+// - arrêtée aux bords du monde en arène bornée ; en arène torique, elle suit
+//   le vaisseau à travers les bords (le rendu dessine alors les copies des
+//   entités qui tombent dans la vue, voir Arena::images_in)
+//
+// La caméra fait partie de la simulation : les ennemis apparaissent autour
+// de la vue (voir Game::handle_waves). Elle est donc sauvegardée avec la
+// partie et rejouée à l'identique par les replays.
+//
+// =============================================================================
+
+use macroquad::prelude::*;
+use serde::{Deserialize, Serialize};
+use crate::arena::Arena;
+use crate::consts::*;
+
+#[derive(Clone, Copy, Debug, Serialize, Deserialize)]
+pub struct Camera {
+    pub lead: Vec2,         // Avance de la vue sur le vaisseau, px
+    pub prev_lead: Vec2,    // Avance au tick précédent (interpolation du rendu)
+}
+
+impl Default for Camera {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Camera {
+    pub fn new() -> Self {
+        Self {
+            lead: Vec2::ZERO,
+            prev_lead: Vec2::ZERO,
+        }
+    }
+
+    /// Avance d'un tick : l'avance rejoint peu à peu celle que donne la
+    /// vitesse du vaisseau
+    pub fn update(&mut self, ship_vel: Vec2) {
+        self.prev_lead = self.lead;
+        let target = (ship_vel * CAMERA_LEAD_TIME).clamp_length_max(CAMERA_MAX_LEAD);
+        self.lead = self.lead.lerp(target, CAMERA_SMOOTHING);
+    }
+
+    /// Centre de la vue pour un vaisseau en `ship_pos`
+    pub fn center(&self, ship_pos: Vec2, arena: &Arena) -> Vec2 {
+        Self::frame(ship_pos + self.lead, arena)
+    }
+
+    /// Centre de la vue interpolé entre les deux derniers ticks (rendu) ;
+    /// `ship_pos` est la position interpolée du vaisseau
+    pub fn render_center(&self, ship_pos: Vec2, arena: &Arena, alpha: f32) -> Vec2 {
+        Self::frame(ship_pos + self.prev_lead.lerp(self.lead, alpha), arena)
+    }
+
+    /// Portion du monde visible autour de `center`
+    pub fn view_rect(center: Vec2, arena: &Arena) -> Rect {
+        let origin = center - arena.view / 2.;
+        Rect::new(origin.x, origin.y, arena.view.x, arena.view.y)
+    }
+
+    /// Caméra macroquad qui affiche la vue centrée en `center` dans la
+    /// fenêtre, y vers le bas comme à l'écran (Camera2D::from_display_rect
+    /// retourne l'image quand on dessine directement à l'écran)
+    pub fn camera2d(center: Vec2, arena: &Arena) -> Camera2D {
+        Camera2D {
+            target: center,
+            zoom: vec2(2. / arena.view.x, 2. / arena.view.y),
+            ..Default::default()
+        }
+    }
+
+    /// Arrête la vue aux bords du monde en arène bornée (centrée si le monde
+    /// est plus petit que la vue)
+    fn frame(center: Vec2, arena: &Arena) -> Vec2 {
+        if arena.wraps() {
+            return center;
+        }
+        let half = arena.view / 2.;
+        let axis = |c: f32, half: f32, size: f32| {
+            if size > half * 2. {
+                c.clamp(half, size - half)
+            } else {
+                size / 2.
+            }
+        };
+        vec2(
+            axis(center.x, half.x, arena.size.x),
+            axis(center.y, half.y, arena.size.y),
+        )
+    }
+}
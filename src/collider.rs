@@ -0,0 +1,534 @@
+// =============================================================================
+// FORMES DE COLLISION
+// =============================================================================
+//
+// Ce fichier décrit la forme exacte de chaque entité, partagée par :
+// - les collisions (collisions.rs, missiles, laser)
+// - le rendu (vaisseau et drones sont dessinés à partir de leur forme)
+// - l'affichage de debug des hitboxes (touche KEY_DEBUG_HITBOXES)
+//
+// Formes disponibles, toujours en coordonnées du monde :
+// - Circle : balles, missiles, power-ups, bouclier
+// - Polygon : polygone convexe (vaisseau triangulaire, drones carrés)
+// - Star : polygone étoilé, concave possible (contour des astéroïdes) ;
+//   découpé en triangles (centre, sommet, sommet suivant) pour les tests
+// - Segment : segment épaissi (capsule), comme le rayon laser
+//
+// Les projectiles rapides (balles, missiles) sont testés par balayage
+// (`sweep`) : leur cercle est déplacé le long du trajet du tick, ce qui
+// évite qu'ils traversent une cible fine entre deux positions.
+//
+// =============================================================================
+
+use macroquad::prelude::*;
+use std::f32::consts::TAU;
+
+/// Nombre maximal de sommets d'un polygone de collision
+pub const MAX_POLYGON_POINTS: usize = 12;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Collider {
+    Circle {
+        center: Vec2,
+        radius: f32,
+    },
+    Polygon(ConvexPolygon),
+    Star(StarPolygon),
+    Segment {
+        a: Vec2,
+        b: Vec2,
+        radius: f32,    // Demi-épaisseur (0 = segment fin)
+    },
+}
+
+// -----------------------------------------------------------------------------
+// POLYGONE CONVEXE
+// -----------------------------------------------------------------------------
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ConvexPolygon {
+    points: [Vec2; MAX_POLYGON_POINTS],
+    len: usize,
+}
+
+impl ConvexPolygon {
+    /// Polygone à partir de ses sommets (convexe, dans l'ordre du contour)
+    /// Les sommets au-delà de MAX_POLYGON_POINTS sont ignorés
+    pub fn new(points: &[Vec2]) -> Self {
+        let len = points.len().min(MAX_POLYGON_POINTS);
+        let mut array = [Vec2::ZERO; MAX_POLYGON_POINTS];
+        array[..len].copy_from_slice(&points[..len]);
+        Self { points: array, len }
+    }
+
+    /// Sommets du polygone
+    pub fn points(&self) -> &[Vec2] {
+        &self.points[..self.len]
+    }
+
+    /// Côtés du polygone (sommet, sommet suivant)
+    pub fn edges(&self) -> impl Iterator<Item = (Vec2, Vec2)> + '_ {
+        let points = self.points();
+        (0..points.len()).map(move |i| (points[i], points[(i + 1) % points.len()]))
+    }
+
+    /// Le point est-il à l'intérieur (bords compris) ?
+    pub fn contains(&self, p: Vec2) -> bool {
+        let mut sign = 0.0;
+        for (a, b) in self.edges() {
+            let cross = (b - a).perp_dot(p - a);
+            if cross != 0.0 {
+                if sign != 0.0 && cross.signum() != sign {
+                    return false;
+                }
+                sign = cross.signum();
+            }
+        }
+        true
+    }
+
+    /// Plus petite distance d'un point au contour
+    pub fn edge_distance(&self, p: Vec2) -> f32 {
+        self.edges()
+            .map(|(a, b)| segment_distance(p, a, b))
+            .fold(f32::INFINITY, f32::min)
+    }
+
+    /// Intervalle de projection sur un axe (test des axes séparateurs)
+    fn project(&self, axis: Vec2) -> (f32, f32) {
+        self.points()
+            .iter()
+            .map(|p| p.dot(axis))
+            .fold((f32::INFINITY, f32::NEG_INFINITY), |(lo, hi), d| (lo.min(d), hi.max(d)))
+    }
+}
+
+// -----------------------------------------------------------------------------
+// POLYGONE ÉTOILÉ
+// -----------------------------------------------------------------------------
+/// Polygone dont tout le contour est visible depuis `center` : il peut être
+/// concave (cratères), mais chaque triangle (centre, sommet, sommet suivant)
+/// est convexe
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct StarPolygon {
+    center: Vec2,
+    outline: ConvexPolygon,     // Sommets du contour (pas forcément convexe)
+}
+
+impl StarPolygon {
+    /// Polygone à partir de son centre et de ses sommets, dans l'ordre
+    /// angulaire autour du centre
+    pub fn new(center: Vec2, points: &[Vec2]) -> Self {
+        Self { center, outline: ConvexPolygon::new(points) }
+    }
+
+    /// Sommets du contour
+    pub fn points(&self) -> &[Vec2] {
+        self.outline.points()
+    }
+
+    /// Côtés du contour
+    pub fn edges(&self) -> impl Iterator<Item = (Vec2, Vec2)> + '_ {
+        self.outline.edges()
+    }
+
+    /// Découpage en triangles convexes autour du centre
+    pub fn triangles(&self) -> impl Iterator<Item = ConvexPolygon> + '_ {
+        self.edges().map(|(a, b)| ConvexPolygon::new(&[self.center, a, b]))
+    }
+
+    /// Le point est-il à l'intérieur (bords compris) ?
+    pub fn contains(&self, p: Vec2) -> bool {
+        self.triangles().any(|t| t.contains(p))
+    }
+}
+
+// -----------------------------------------------------------------------------
+// CONSTRUCTION
+// -----------------------------------------------------------------------------
+impl Collider {
+    pub fn circle(center: Vec2, radius: f32) -> Self {
+        Collider::Circle { center, radius }
+    }
+
+    pub fn segment(a: Vec2, b: Vec2, radius: f32) -> Self {
+        Collider::Segment { a, b, radius }
+    }
+
+    pub fn polygon(points: &[Vec2]) -> Self {
+        Collider::Polygon(ConvexPolygon::new(points))
+    }
+
+    pub fn star(center: Vec2, points: &[Vec2]) -> Self {
+        Collider::Star(StarPolygon::new(center, points))
+    }
+
+    /// Polygone régulier, mêmes sommets que draw_poly de macroquad
+    /// (`rotation` en radians, premier sommet dans cette direction)
+    pub fn regular_polygon(center: Vec2, sides: usize, radius: f32, rotation: f32) -> Self {
+        let sides = sides.clamp(3, MAX_POLYGON_POINTS);
+        let mut points = [Vec2::ZERO; MAX_POLYGON_POINTS];
+        for (i, point) in points.iter_mut().take(sides).enumerate() {
+            *point = center + Vec2::from_angle(i as f32 / sides as f32 * TAU + rotation) * radius;
+        }
+        Collider::polygon(&points[..sides])
+    }
+
+    /// Rectangle aligné sur les axes, centré sur `center`
+    pub fn rect(center: Vec2, half_size: Vec2) -> Self {
+        Collider::polygon(&[
+            center + vec2(-half_size.x, -half_size.y),
+            center + vec2(half_size.x, -half_size.y),
+            center + vec2(half_size.x, half_size.y),
+            center + vec2(-half_size.x, half_size.y),
+        ])
+    }
+
+    // -------------------------------------------------------------------------
+    // GÉOMÉTRIE
+    // -------------------------------------------------------------------------
+
+    /// Centre de la forme (milieu du segment, moyenne des sommets, centre
+    /// d'un polygone étoilé)
+    pub fn center(&self) -> Vec2 {
+        match self {
+            Collider::Circle { center, .. } => *center,
+            Collider::Star(star) => star.center,
+            Collider::Polygon(poly) => {
+                poly.points().iter().copied().sum::<Vec2>() / poly.points().len().max(1) as f32
+            }
+            Collider::Segment { a, b, .. } => (*a + *b) * 0.5,
+        }
+    }
+
+    /// Rayon du cercle englobant, centré sur center() (broad-phase)
+    pub fn bounding_radius(&self) -> f32 {
+        let center = self.center();
+        match self {
+            Collider::Circle { radius, .. } => *radius,
+            Collider::Polygon(poly) => poly
+                .points()
+                .iter()
+                .map(|p| p.distance(center))
+                .fold(0.0, f32::max),
+            Collider::Star(star) => star
+                .points()
+                .iter()
+                .map(|p| p.distance(center))
+                .fold(0.0, f32::max),
+            Collider::Segment { a, b, radius } => a.distance(*b) * 0.5 + radius,
+        }
+    }
+
+    /// Les deux formes se touchent-elles ?
+    pub fn intersects(&self, other: &Collider) -> bool {
+        use Collider::*;
+        match (self, other) {
+            // Polygone étoilé : l'un de ses triangles touche l'autre forme
+            (Star(star), shape) | (shape, Star(star)) => {
+                star.triangles().any(|t| Polygon(t).intersects(shape))
+            }
+            (Circle { center: c1, radius: r1 }, Circle { center: c2, radius: r2 }) => {
+                c1.distance_squared(*c2) < (r1 + r2) * (r1 + r2)
+            }
+            (Circle { center, radius }, Segment { a, b, radius: r })
+            | (Segment { a, b, radius: r }, Circle { center, radius }) => {
+                segment_distance(*center, *a, *b) < radius + r
+            }
+            (Circle { center, radius }, Polygon(poly))
+            | (Polygon(poly), Circle { center, radius }) => {
+                poly.contains(*center) || poly.edge_distance(*center) < *radius
+            }
+            (Segment { a, b, radius }, Polygon(poly))
+            | (Polygon(poly), Segment { a, b, radius }) => {
+                poly.contains(*a)
+                    || poly.contains(*b)
+                    || poly.edges().any(|(p, q)| segments_distance(*a, *b, p, q) <= *radius)
+            }
+            (Segment { a: a1, b: b1, radius: r1 }, Segment { a: a2, b: b2, radius: r2 }) => {
+                // Bords compris : deux segments fins qui se croisent se touchent
+                segments_distance(*a1, *b1, *a2, *b2) <= r1 + r2
+            }
+            (Polygon(p1), Polygon(p2)) => polygons_intersect(p1, p2),
+        }
+    }
+
+    /// Premier contact d'un cercle de rayon `radius` déplacé de `from` à `to`
+    /// avec la forme : instant entre 0 (départ) et 1 (arrivée), None s'il
+    /// ne la touche pas sur ce trajet
+    pub fn sweep(&self, from: Vec2, to: Vec2, radius: f32) -> Option<f32> {
+        match self {
+            Collider::Circle { center, radius: r } => ray_circle(from, to, *center, r + radius),
+            Collider::Segment { a, b, radius: r } => ray_capsule(from, to, *a, *b, r + radius),
+            Collider::Polygon(poly) => {
+                if poly.contains(from) {
+                    return Some(0.0);
+                }
+                // Polygone grossi de `radius` = côtés en capsules
+                poly.edges()
+                    .filter_map(|(a, b)| ray_capsule(from, to, a, b, radius))
+                    .reduce(f32::min)
+            }
+            Collider::Star(star) => {
+                if star.contains(from) {
+                    return Some(0.0);
+                }
+                // Le contour suffit, creux compris
+                star.edges()
+                    .filter_map(|(a, b)| ray_capsule(from, to, a, b, radius))
+                    .reduce(f32::min)
+            }
+        }
+    }
+
+    // -------------------------------------------------------------------------
+    // DESSIN
+    // -------------------------------------------------------------------------
+
+    /// Dessine la forme pleine
+    pub fn draw(&self, color: Color) {
+        match self {
+            Collider::Circle { center, radius } => draw_circle(center.x, center.y, *radius, color),
+            Collider::Polygon(poly) => {
+                let points = poly.points();
+                for i in 1..points.len().saturating_sub(1) {
+                    draw_triangle(points[0], points[i], points[i + 1], color);
+                }
+            }
+            Collider::Star(star) => {
+                for (a, b) in star.edges() {
+                    draw_triangle(star.center, a, b, color);
+                }
+            }
+            Collider::Segment { a, b, radius } => {
+                draw_line(a.x, a.y, b.x, b.y, (radius * 2.0).max(1.0), color)
+            }
+        }
+    }
+
+    /// Dessine le contour de la forme
+    pub fn draw_lines(&self, thickness: f32, color: Color) {
+        match self {
+            Collider::Circle { center, radius } => {
+                draw_circle_lines(center.x, center.y, *radius, thickness, color)
+            }
+            Collider::Polygon(poly) => {
+                for (a, b) in poly.edges() {
+                    draw_line(a.x, a.y, b.x, b.y, thickness, color);
+                }
+            }
+            Collider::Star(star) => {
+                for (a, b) in star.edges() {
+                    draw_line(a.x, a.y, b.x, b.y, thickness, color);
+                }
+            }
+            Collider::Segment { a, b, radius } => {
+                let side = (*b - *a).normalize_or_zero().perp() * *radius;
+                for offset in [side, -side] {
+                    draw_line(a.x + offset.x, a.y + offset.y, b.x + offset.x, b.y + offset.y, thickness, color);
+                }
+                draw_circle_lines(a.x, a.y, *radius, thickness, color);
+                draw_circle_lines(b.x, b.y, *radius, thickness, color);
+            }
+        }
+    }
+}
+
+// -----------------------------------------------------------------------------
+// OUTILS GÉOMÉTRIQUES
+// -----------------------------------------------------------------------------
+
+/// Distance d'un point au segment [a, b]
+pub fn segment_distance(p: Vec2, a: Vec2, b: Vec2) -> f32 {
+    let ab = b - a;
+    let t = if ab.length_squared() > 0.0 {
+        ((p - a).dot(ab) / ab.length_squared()).clamp(0.0, 1.0)
+    } else {
+        0.0
+    };
+    p.distance(a + ab * t)
+}
+
+/// Distance entre les segments [a1, b1] et [a2, b2] (0 s'ils se croisent)
+pub fn segments_distance(a1: Vec2, b1: Vec2, a2: Vec2, b2: Vec2) -> f32 {
+    let d1 = b1 - a1;
+    let d2 = b2 - a2;
+    let denom = d1.perp_dot(d2);
+    if denom != 0.0 {
+        let t = (a2 - a1).perp_dot(d2) / denom;
+        let u = (a2 - a1).perp_dot(d1) / denom;
+        if (0.0..=1.0).contains(&t) && (0.0..=1.0).contains(&u) {
+            return 0.0;
+        }
+    }
+    segment_distance(a1, a2, b2)
+        .min(segment_distance(b1, a2, b2))
+        .min(segment_distance(a2, a1, b1))
+        .min(segment_distance(b2, a1, b1))
+}
+
+/// Premier instant (0 à 1) où le point parcourant [from, to] entre dans
+/// le cercle (center, radius)
+fn ray_circle(from: Vec2, to: Vec2, center: Vec2, radius: f32) -> Option<f32> {
+    let d = to - from;
+    let f = from - center;
+    let c = f.length_squared() - radius * radius;
+    if c < 0.0 {
+        return Some(0.0);   // Déjà dedans au départ
+    }
+    let a = d.length_squared();
+    if a == 0.0 {
+        return None;
+    }
+    let b = f.dot(d);
+    let disc = b * b - a * c;
+    if disc < 0.0 {
+        return None;
+    }
+    let t = (-b - disc.sqrt()) / a;
+    (0.0..=1.0).contains(&t).then_some(t)
+}
+
+/// Premier instant (0 à 1) où le point parcourant [from, to] entre dans
+/// la capsule autour de [a, b] (bouts arrondis compris)
+fn ray_capsule(from: Vec2, to: Vec2, a: Vec2, b: Vec2, radius: f32) -> Option<f32> {
+    if segment_distance(from, a, b) < radius {
+        return Some(0.0);
+    }
+    let side = (b - a).normalize_or_zero().perp() * radius;
+    [
+        ray_circle(from, to, a, radius),
+        ray_circle(from, to, b, radius),
+        ray_segment(from, to, a + side, b + side),
+        ray_segment(from, to, a - side, b - side),
+    ]
+    .into_iter()
+    .flatten()
+    .reduce(f32::min)
+}
+
+/// Instant (0 à 1) où le point parcourant [from, to] croise le segment [a, b]
+fn ray_segment(from: Vec2, to: Vec2, a: Vec2, b: Vec2) -> Option<f32> {
+    let d = to - from;
+    let e = b - a;
+    let denom = d.perp_dot(e);
+    if denom == 0.0 {
+        return None;    // Parallèles : les bouts arrondis prennent le relais
+    }
+    let t = (a - from).perp_dot(e) / denom;
+    let u = (a - from).perp_dot(d) / denom;
+    ((0.0..=1.0).contains(&t) && (0.0..=1.0).contains(&u)).then_some(t)
+}
+
+/// Deux polygones convexes se chevauchent-ils ? (axes séparateurs)
+fn polygons_intersect(p1: &ConvexPolygon, p2: &ConvexPolygon) -> bool {
+    for (a, b) in p1.edges().chain(p2.edges()) {
+        let axis = (b - a).perp();
+        let (min1, max1) = p1.project(axis);
+        let (min2, max2) = p2.project(axis);
+        if max1 < min2 || max2 < min1 {
+            return false;
+        }
+    }
+    true
+}
+
+// =============================================================================
+// TESTS
+// =============================================================================
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Octogone de rayon 40 centré à l'origine ; `crater` enfonce le sommet
+    /// du bas à droite jusqu'à (5, -5)
+    fn octagon_points(crater: bool) -> Vec<Vec2> {
+        (0..8)
+            .map(|i| {
+                if crater && i == 7 {
+                    vec2(5., -5.)
+                } else {
+                    Vec2::from_angle(i as f32 * std::f32::consts::FRAC_PI_4) * 40.
+                }
+            })
+            .collect()
+    }
+
+    #[test]
+    fn intersects_table() {
+        let square = Collider::rect(Vec2::ZERO, Vec2::splat(10.));
+        let star = Collider::star(Vec2::ZERO, &octagon_points(true));
+        let cases = [
+            ("cercle / cercle", Collider::circle(Vec2::ZERO, 5.), Collider::circle(vec2(9., 0.), 5.), true),
+            ("cercles éloignés", Collider::circle(Vec2::ZERO, 5.), Collider::circle(vec2(11., 0.), 5.), false),
+            ("cercle / segment", Collider::circle(vec2(5., 4.), 3.), Collider::segment(Vec2::ZERO, vec2(10., 0.), 2.), true),
+            ("cercle loin du segment", Collider::circle(vec2(5., 8.), 3.), Collider::segment(Vec2::ZERO, vec2(10., 0.), 2.), false),
+            ("cercle / polygone (bord)", Collider::circle(vec2(13., 0.), 4.), square, true),
+            ("cercle / polygone (coin)", Collider::circle(vec2(14., 14.), 4.), square, false),
+            ("cercle dans le polygone", Collider::circle(Vec2::ZERO, 1.), square, true),
+            ("segment traversant", Collider::segment(vec2(-20., 0.), vec2(20., 0.), 0.), square, true),
+            ("segment à côté", Collider::segment(vec2(-20., 12.), vec2(20., 12.), 1.), square, false),
+            ("segment épais", Collider::segment(vec2(-20., 12.), vec2(20., 12.), 3.), square, true),
+            ("segments croisés", Collider::segment(vec2(-5., -5.), vec2(5., 5.), 0.), Collider::segment(vec2(-5., 5.), vec2(5., -5.), 0.), true),
+            ("polygones disjoints", Collider::rect(vec2(21., 0.), Vec2::splat(10.)), square, false),
+            ("polygones superposés", Collider::rect(vec2(19., 0.), Vec2::splat(10.)), square, true),
+            ("étoile / cercle plein", Collider::circle(vec2(-20., 0.), 3.), star, true),
+            ("cercle dans le cratère", Collider::circle(vec2(15., -15.), 3.), star, false),
+            ("étoile / polygone", square, star, true),
+        ];
+        for (name, a, b, expected) in cases {
+            assert_eq!(a.intersects(&b), expected, "{name}");
+            assert_eq!(b.intersects(&a), expected, "{name} (inversé)");
+        }
+
+        // Sans cratère, le même cercle touche la coque
+        let hull = Collider::polygon(&octagon_points(false));
+        assert!(Collider::circle(vec2(15., -15.), 3.).intersects(&hull));
+    }
+
+    #[test]
+    fn sweep_finds_thin_target_in_one_tick() {
+        // Balle de rayon 2 qui parcourt 200 px en un tick : le drone fin
+        // (4 px d'épaisseur) au milieu du trajet n'est jamais touché par
+        // les positions de début et de fin
+        let drone = Collider::rect(vec2(100., 0.), vec2(2., 20.));
+        let bullet = |pos: Vec2| Collider::circle(pos, 2.);
+        assert!(!drone.intersects(&bullet(Vec2::ZERO)));
+        assert!(!drone.intersects(&bullet(vec2(200., 0.))));
+
+        let t = drone.sweep(Vec2::ZERO, vec2(200., 0.), 2.).expect("drone traversé");
+        assert!((t - 96. / 200.).abs() < 1e-4, "t = {t}");
+
+        // Deux cibles sur le trajet : chacune rend son instant de contact
+        let far = Collider::rect(vec2(150., 0.), vec2(2., 20.));
+        let t_far = far.sweep(Vec2::ZERO, vec2(200., 0.), 2.).unwrap();
+        assert!(t < t_far);
+
+        // Hors du trajet
+        assert_eq!(drone.sweep(vec2(0., 40.), vec2(200., 40.), 2.), None);
+    }
+
+    #[test]
+    fn sweep_other_shapes() {
+        let circle = Collider::circle(vec2(50., 0.), 10.);
+        let t = circle.sweep(Vec2::ZERO, vec2(100., 0.), 0.).unwrap();
+        assert!((t - 0.4).abs() < 1e-4, "t = {t}");
+
+        let capsule = Collider::segment(vec2(50., -30.), vec2(50., 30.), 5.);
+        let t = capsule.sweep(Vec2::ZERO, vec2(100., 0.), 5.).unwrap();
+        assert!((t - 0.4).abs() < 1e-4, "t = {t}");
+
+        // Le contour de l'étoile suffit : le trajet entre par le cratère
+        let star = Collider::star(Vec2::ZERO, &octagon_points(true));
+        let t = star.sweep(vec2(30., -30.), Vec2::ZERO, 0.).unwrap();
+        assert!(t > 0.5, "t = {t}");
+    }
+
+    #[test]
+    fn sweep_starting_inside_hits_at_zero() {
+        let square = Collider::rect(Vec2::ZERO, Vec2::splat(10.));
+        assert_eq!(square.sweep(vec2(2., 2.), vec2(50., 50.), 1.), Some(0.0));
+
+        let star = Collider::star(Vec2::ZERO, &octagon_points(true));
+        assert_eq!(star.sweep(vec2(-10., 0.), vec2(-100., 0.), 1.), Some(0.0));
+    }
+}
@@ -7,32 +7,95 @@
 // - Balles ennemies vs joueur
 // - Vaisseau vs astéroïdes
 // - Vaisseau vs drones
+// - Vaisseau vs vaisseau-mère (noyau et tourelles, voir boss.rs)
+// - Chocs entre ennemis (astéroïdes, drones, vaisseau-mère)
 // - Power-ups vs joueur
 //
+// Les conséquences visibles (explosions, sons...) ne sont pas produites ici :
+// chaque règle émet un événement sur le bus (voir events.rs). Les dégâts,
+// le score et les power-ups passent par DamageContext (voir damage.rs).
+//
+// Les ennemis candidats viennent des grilles spatiales du monde (voir
+// spatial.rs), reconstruites avant ces tests : seuls les ennemis proches
+// sont testés, dans l'ordre de leurs index. Le test exact compare les
+// formes de collision (voir collider.rs), celles-là mêmes qui sont
+// dessinées ; le vaisseau est représenté par `ship_collider` (sa coque, ou
+// le cercle du bouclier quand il est actif).
+//
+// Balles et missiles sont balayés sur tout leur trajet du tick (prev_pos
+// vers pos) : même lors d'un long tick, ils ne traversent pas une cible
+// fine, et la cible touchée la première l'emporte.
+//
+// En arène torique, chaque test se fait contre la copie de l'autre entité
+// la plus proche (Arena::image_near) : les chocs ont lieu à travers les
+// bords comme au milieu de l'écran.
+//
 // =============================================================================
 
 use macroquad::prelude::*;
+use crate::arena::Arena;
+use crate::balance::ShipBalance;
+use crate::boss::BossPart;
+use crate::collider::Collider;
 use crate::consts::*;
+use crate::damage::{DamageContext, DamageInfo, Damageable};
 use crate::game::*;
 use crate::enemies::*;
-use crate::effects::EffectSystem;
-use crate::events::EventQueue;
+use crate::events::{Event, EventBus};
+use crate::ship::Ship;
+use crate::slotmap::SlotMap;
+use crate::spatial::SpatialGrid;
+use crate::weapons::WeaponKind;
+
+/// Premier ennemi d'une grille touché par un cercle de rayon `radius` qui
+/// se déplace de `from` à `to` : (index, instant de contact de 0 à 1)
+/// `shape` donne la forme d'un candidat (None = ignoré) ; à instant égal,
+/// l'index le plus petit l'emporte
+pub fn first_contact(
+    grid: &SpatialGrid,
+    from: Vec2,
+    to: Vec2,
+    radius: f32,
+    candidates: &mut Vec<usize>,
+    shape: impl Fn(usize) -> Option<Collider>,
+) -> Option<(usize, f32)> {
+    grid.query_segment(from, to, radius, candidates);
+    let mut first: Option<(usize, f32)> = None;
+    for &index in candidates.iter() {
+        let t = match shape(index).and_then(|s| s.sweep(from, to, radius)) {
+            Some(t) => t,
+            None => continue,
+        };
+        if first.is_none_or(|(_, min)| t < min) {
+            first = Some((index, t));
+        }
+    }
+    first
+}
+
+/// Cible d'un projectile
+#[derive(Clone, Copy)]
+pub(crate) enum Target {
+    Asteroid(usize),
+    Drone(usize),
+    Boss(usize),
+}
+
+/// Contact le plus tôt parmi ceux de chaque collection (à instant égal,
+/// le premier de la liste l'emporte)
+pub(crate) fn earliest(hits: [Option<(Target, f32)>; 3]) -> Option<(Target, f32)> {
+    hits.into_iter().flatten().fold(None, |first, (target, t)| match first {
+        Some((_, min)) if min <= t => first,
+        _ => Some((target, t)),
+    })
+}
 
 // -----------------------------------------------------------------------------
 // COLLISIONS BALLES AMIES VS ENNEMIS
 // -----------------------------------------------------------------------------
-pub fn handle_friendly_bullet_collisions(
-    bullets: &mut Vec<Bullet>,
-    asteroids: &mut Vec<Asteroid>,
-    drones: &mut Vec<Drone>,
-    score: &mut i32,
-    combo: &mut i32,
-    combo_timer: &mut Cooldown,
-    enemies_killed: &mut i32,
-    effects: &mut EffectSystem,
-    powerups: &mut Vec<PowerUp>,
-    _event_queue: &mut EventQueue,  // Non utilisé actuellement
-) {
+pub fn handle_friendly_bullet_collisions(world: &mut World, arena: &Arena, ctx: &mut DamageContext) {
+    let World { bullets, asteroids, drones, boss_parts, asteroid_grid, drone_grid, boss_grid, .. } = world;
+    let mut candidates = Vec::new();
     let mut i = 0;
     while i < bullets.len() {
         let bullet = &bullets[i];
@@ -43,59 +106,36 @@ pub fn handle_friendly_bullet_collisions(
             continue;
         }
 
-        let mut bullet_hit = false;
+        // Premier ennemi vivant touché sur le trajet (astéroïde, puis drone,
+        // puis vaisseau-mère en cas d'égalité)
+        let (from, to) = (bullet.prev_pos, bullet.pos);
+        let asteroid_hit = first_contact(asteroid_grid, from, to, bullet.size, &mut candidates, |a| {
+            (!asteroids[a].is_destroyed()).then(|| asteroids[a].collider_near(arena, to))
+        });
+        let drone_hit = first_contact(drone_grid, from, to, bullet.size, &mut candidates, |d| {
+            (!drones[d].is_destroyed()).then(|| drones[d].collider_near(arena, to))
+        });
+        let boss_hit = first_contact(boss_grid, from, to, bullet.size, &mut candidates, |p| {
+            (!boss_parts[p].is_destroyed()).then(|| boss_parts[p].collider_near(arena, to))
+        });
+        let target = earliest([
+            asteroid_hit.map(|(a, t)| (Target::Asteroid(a), t)),
+            drone_hit.map(|(d, t)| (Target::Drone(d), t)),
+            boss_hit.map(|(p, t)| (Target::Boss(p), t)),
+        ]);
 
-        // Collision avec les astéroïdes
-        for asteroid in asteroids.iter_mut() {
-            if bullet.pos.distance(asteroid.pos) < asteroid.radius {
-                asteroid.radius -= 10.0;
-                *score += 10;
-                *combo += 1;
-                combo_timer.reset();
-                
-                effects.create_explosion(asteroid.pos, WHITE, 10);
-                
-                // Chance de spawn power-up
-                if asteroid.radius <= 10.0 
-                   && rand::gen_range(0.0, 1.0) < POWERUP_SPAWN_CHANCE {
-                    powerups.push(PowerUp::new(asteroid.pos));
-                }
-                
-                bullet_hit = true;
-                break;
-            }
-        }
-
-        // Collision avec les drones (si pas déjà touché)
-        if !bullet_hit {
-            for drone in drones.iter_mut() {
-                if bullet.pos.distance(drone.pos) < 15.0 {
-                    drone.hp -= 1;
-                    *score += 50;
-                    *combo += 1;
-                    combo_timer.reset();
-                    
-                    effects.create_explosion(drone.pos, RED, 8);
-                    
-                    if drone.hp <= 0 {
-                        *enemies_killed += 1;
-                        *score += 50;
-                        
-                        effects.create_explosion(drone.pos, ORANGE, 15);
-                        
-                        if rand::gen_range(0.0, 1.0) < POWERUP_SPAWN_CHANCE {
-                            powerups.push(PowerUp::new(drone.pos));
-                        }
-                    }
-                    
-                    bullet_hit = true;
-                    break;
-                }
-            }
+        if let Some((target, t)) = target {
+            let hit = DamageInfo::new(BULLET_DAMAGE, WeaponKind::Cannon, from.lerp(to, t))
+                .with_knockback(bullet.vel, KNOCKBACK_BULLET);
+            match target {
+                Target::Asteroid(a) => ctx.damage_enemy(asteroids.id_at(a), &mut asteroids[a], hit),
+                Target::Drone(d) => ctx.damage_enemy(drones.id_at(d), &mut drones[d], hit),
+                Target::Boss(p) => ctx.damage_enemy(boss_parts.id_at(p), &mut boss_parts[p], hit),
+            };
         }
 
         // Suppression de la balle si elle a touché quelque chose
-        if bullet_hit {
+        if target.is_some() {
             bullets.swap_remove(i);
         } else {
             i += 1;
@@ -107,16 +147,10 @@ pub fn handle_friendly_bullet_collisions(
 // COLLISIONS BALLES ENNEMIES VS VAISSEAU
 // -----------------------------------------------------------------------------
 pub fn handle_enemy_bullet_collisions(
-    bullets: &mut Vec<Bullet>,
-    ship_pos: &Vec2,
-    invincible_time: f32,
-    shield_active: bool,
-    lives: &mut i32,
-    ship_invincible: &mut Cooldown,
-    shake: &mut ScreenShake,
-    effects: &mut EffectSystem,
-    state: &mut GameState,
-    event_queue: &mut EventQueue,
+    bullets: &mut SlotMap<Bullet>,
+    ship: &mut Ship,
+    arena: &Arena,
+    ctx: &mut DamageContext,
 ) {
     let mut i = 0;
     while i < bullets.len() {
@@ -128,22 +162,23 @@ pub fn handle_enemy_bullet_collisions(
             continue;
         }
 
-        // Collision avec le vaisseau
-        if bullet.pos.distance(*ship_pos) < 12.0 && invincible_time <= 0.0 {
-            if !shield_active {
-                *lives -= 1;
-                ship_invincible.reset();
-                shake.trigger(0.3);
-                
-                effects.create_explosion(*ship_pos, RED, 10);
-                
-                if *lives <= 0 {
-                    *state = GameState::GameOver;
-                    event_queue.push(crate::events::Event::GameOver);
-                }
+        // Collision avec le vaisseau (trajet de la copie la plus proche)
+        let offset = arena.image_near(bullet.pos, ship.pos) - bullet.pos;
+        let (from, to) = (bullet.prev_pos + offset, bullet.pos + offset);
+        let contact = if ship.is_invincible() {
+            None
+        } else {
+            ship.collider().sweep(from, to, bullet.size)
+        };
+        if let Some(t) = contact {
+            if !ship.shield_active {
+                let mut hit = DamageInfo::new(BULLET_DAMAGE, WeaponKind::Cannon, from.lerp(to, t))
+                    .with_knockback(bullet.vel, KNOCKBACK_BULLET);
+                hit.source = bullet.owner;
+                ctx.damage_ship(ship, hit);
             } else {
                 // Bouclier : la balle est détruite sans dégât
-                effects.create_explosion(bullet.pos, SKYBLUE, 6);
+                ctx.events.emit(Event::ShieldBlocked { pos: bullet.pos });
             }
             
             bullets.swap_remove(i);
@@ -157,39 +192,34 @@ pub fn handle_enemy_bullet_collisions(
 // COLLISIONS VAISSEAU VS ASTÉROÏDES
 // -----------------------------------------------------------------------------
 pub fn handle_ship_asteroid_collisions(
-    asteroids: &mut Vec<Asteroid>,
-    ship_pos: &Vec2,
-    invincible_time: f32,
-    shield_active: bool,
-    lives: &mut i32,
-    ship_invincible: &mut Cooldown,
-    shake: &mut ScreenShake,
-    score: &mut i32,
-    effects: &mut EffectSystem,
-    state: &mut GameState,
-    event_queue: &mut EventQueue,
+    asteroids: &mut SlotMap<Asteroid>,
+    asteroid_grid: &SpatialGrid,
+    ship: &mut Ship,
+    arena: &Arena,
+    ctx: &mut DamageContext,
 ) {
-    for asteroid in asteroids.iter_mut() {
-        if ship_pos.distance(asteroid.pos) < asteroid.radius + 8.0 
-           && invincible_time <= 0.0 {
+    let ship_collider = ship.collider();
+    let mut candidates = Vec::new();
+    asteroid_grid.query(ship_collider.center(), ship_collider.bounding_radius(), &mut candidates);
+    for &a in &candidates {
+        let id = asteroids.id_at(a);
+        let asteroid = &mut asteroids[a];
+        if !ship.is_invincible() && ship_collider.intersects(&asteroid.collider_near(arena, ship.pos)) {
             
-            if shield_active {
-                // Rebond sur le bouclier
-                asteroid.vel = (asteroid.pos - *ship_pos).normalize() * 400.0;
-                shake.trigger(0.3);
-                *score += 5;
+            if ship.shield_active {
+                // Rebond sur le bouclier : l'astéroïde renvoyé écrase
+                // désormais les ennemis pour le compte du joueur
+                asteroid.vel = arena.delta(ship.pos, asteroid.pos).normalize_or(Vec2::Y) * 400.0;
+                asteroid.thrown_timer = SHIELD_THROW_TIME;
+                ctx.shake.trigger(0.3);
+                *ctx.score += 5;
+                ctx.events.emit(Event::ShieldBlocked { pos: asteroid.pos });
             } else {
                 // Dégâts
-                *lives -= 1;
-                ship_invincible.reset();
-                shake.trigger(0.5);
-                
-                effects.create_explosion(asteroid.pos, WHITE, 12);
-                
-                if *lives <= 0 {
-                    *state = GameState::GameOver;
-                    event_queue.push(crate::events::Event::GameOver);
-                }
+                let hit = DamageInfo::new(RAM_DAMAGE, WeaponKind::Collision, ship.pos)
+                    .with_knockback(arena.delta(asteroid.pos, ship.pos), KNOCKBACK_RAM)
+                    .from_source(id);
+                ctx.damage_ship(ship, hit);
             }
         }
     }
@@ -199,105 +229,257 @@ pub fn handle_ship_asteroid_collisions(
 // COLLISIONS VAISSEAU VS DRONES
 // -----------------------------------------------------------------------------
 pub fn handle_ship_drone_collisions(
-    drones: &mut Vec<Drone>,
-    asteroids: &mut Vec<Asteroid>,
-    ship_pos: &Vec2,
-    invincible_time: f32,
-    shield_active: bool,
-    lives: &mut i32,
-    ship_invincible: &mut Cooldown,
-    shake: &mut ScreenShake,
-    effects: &mut EffectSystem,
-    state: &mut GameState,
-    score: &mut i32,
-    enemies_killed: &mut i32,
-    event_queue: &mut EventQueue,
+    drones: &mut SlotMap<Drone>,
+    drone_grid: &SpatialGrid,
+    ship: &mut Ship,
+    arena: &Arena,
+    ctx: &mut DamageContext,
+) {
+    // Seuls les drones proches du vaisseau peuvent le toucher
+    let ship_collider = ship.collider();
+    let mut candidates = Vec::new();
+    drone_grid.query(ship_collider.center(), ship_collider.bounding_radius(), &mut candidates);
+    for &d in &candidates {
+        let id = drones.id_at(d);
+        let drone = &mut drones[d];
+        if drone.is_destroyed()
+            || ship.is_invincible()
+            || !drone.collider_near(arena, ship.pos).intersects(&ship_collider)
+        {
+            continue;
+        }
+        if !ship.shield_active {
+            let hit = DamageInfo::new(RAM_DAMAGE, WeaponKind::Collision, ship.pos)
+                .with_knockback(arena.delta(drone.pos, ship.pos), KNOCKBACK_RAM)
+                .from_source(id);
+            ctx.damage_ship(ship, hit);
+            let crash = DamageInfo::new(drone.health(), WeaponKind::Collision, drone.pos);
+            ctx.damage_enemy(id, drone, crash);
+        } else {
+            // Rebond sur le bouclier
+            let bounce = DamageInfo::new(SHIELD_DAMAGE, WeaponKind::Shield, drone.pos)
+                .with_knockback(arena.delta(ship.pos, drone.pos), KNOCKBACK_SHIELD);
+            ctx.damage_enemy(id, drone, bounce);
+        }
+    }
+}
+
+// -----------------------------------------------------------------------------
+// COLLISIONS VAISSEAU VS VAISSEAU-MÈRE
+// -----------------------------------------------------------------------------
+// La coque ne cède pas : le vaisseau perd une vie et rebondit, ou est
+// repoussé par son bouclier (la partie percutée encaisse SHIELD_DAMAGE)
+pub fn handle_ship_boss_collisions(
+    boss_parts: &mut SlotMap<BossPart>,
+    boss_grid: &SpatialGrid,
+    ship: &mut Ship,
+    arena: &Arena,
+    ctx: &mut DamageContext,
 ) {
-    for drone in drones.iter_mut() {
-        // Collision drone - astéroïde (dommages collatéraux)
-        for asteroid in asteroids.iter_mut() {
-            if drone.pos.distance(asteroid.pos) < 20.0 + asteroid.radius {
-                drone.hp = 0;
-                asteroid.radius -= 15.0;
-                
-                effects.create_explosion(drone.pos, ORANGE, 15);
-                shake.trigger(0.2);
-                
-                if asteroid.radius <= 10.0 {
-                    effects.create_explosion(asteroid.pos, WHITE, 12);
-                }
-                
-                *enemies_killed += 1;
-                *score += 50;
+    let ship_collider = ship.collider();
+    let mut candidates = Vec::new();
+    boss_grid.query(ship_collider.center(), ship_collider.bounding_radius(), &mut candidates);
+    for &p in &candidates {
+        let id = boss_parts.id_at(p);
+        let part = &mut boss_parts[p];
+        if part.is_destroyed()
+            || ship.is_invincible()
+            || !ship_collider.intersects(&part.collider_near(arena, ship.pos))
+        {
+            continue;
+        }
+        let away = arena.delta(part.pos, ship.pos).normalize_or(Vec2::Y);
+        if ship.shield_active {
+            ship.vel = away * KNOCKBACK_SHIELD;
+            let bounce = DamageInfo::new(SHIELD_DAMAGE, WeaponKind::Shield, ship.pos);
+            ctx.damage_enemy(id, part, bounce);
+            ctx.events.emit(Event::ShieldBlocked { pos: ship.pos });
+        } else {
+            let hit = DamageInfo::new(RAM_DAMAGE, WeaponKind::Collision, ship.pos)
+                .with_knockback(away, KNOCKBACK_RAM)
+                .from_source(id);
+            ctx.damage_ship(ship, hit);
+        }
+    }
+}
+
+// -----------------------------------------------------------------------------
+// CHOCS ENTRE ENNEMIS (ASTÉROÏDES ENTRE EUX, VS DRONES, VS VAISSEAU-MÈRE)
+// -----------------------------------------------------------------------------
+// Chocs élastiques selon les masses (aires) ; chaque corps subit un point
+// de dégât par IMPACT_SPEED_PER_DAMAGE px/s de vitesse d'impact. Un
+// astéroïde renvoyé par le bouclier (thrown_timer) inflige ses dégâts au
+// nom du bouclier : points, combo et power-ups pour le joueur.
+// Le vaisseau-mère, bien plus lourd, écarte les astéroïdes sans dévier.
+// Les fragments apparus pendant ce tick n'entrent dans la grille qu'au
+// tick suivant.
+pub fn handle_enemy_collisions(
+    asteroids: &mut SlotMap<Asteroid>,
+    drones: &mut SlotMap<Drone>,
+    boss_parts: &mut SlotMap<BossPart>,
+    asteroid_grid: &SpatialGrid,
+    arena: &Arena,
+    ctx: &mut DamageContext,
+) {
+    let mut candidates = Vec::new();
+
+    // Astéroïdes entre eux (chaque paire une fois, a < b)
+    for a in 0..asteroids.len() {
+        if asteroids[a].is_destroyed() {
+            continue;
+        }
+        let shape = asteroids[a].collider();
+        asteroid_grid.query(shape.center(), shape.bounding_radius(), &mut candidates);
+        for &b in candidates.iter().filter(|&&b| b > a) {
+            let (id_a, id_b) = (asteroids.id_at(a), asteroids.id_at(b));
+            let Some((first, second)) = asteroids.get2_mut(id_a, id_b) else {
+                continue;
+            };
+            if first.is_destroyed()
+                || second.is_destroyed()
+                || !shape.intersects(&second.collider_near(arena, first.pos))
+            {
+                continue;
+            }
+            let second_pos = arena.image_near(second.pos, first.pos);
+            let speed = elastic_collision(
+                first.pos, first.mass(), &mut first.vel,
+                second_pos, second.mass(), &mut second.vel,
+            );
+            let damage = impact_damage(speed);
+            if damage > 0 {
+                let pos = arena.wrap((first.pos + second_pos) * 0.5);
+                let (by_first, by_second) = (impact_weapon(first), impact_weapon(second));
+                ctx.damage_enemy(id_b, second, DamageInfo::new(damage, by_first, pos));
+                ctx.damage_enemy(id_a, first, DamageInfo::new(damage, by_second, pos));
+                ctx.shake.trigger(0.2);
             }
         }
+    }
 
-        // Collision drone - vaisseau
-        if ship_pos.distance(drone.pos) < 20.0 && invincible_time <= 0.0 {
-            if !shield_active {
-                *lives -= 1;
-                ship_invincible.reset();
-                shake.trigger(0.5);
-                drone.hp = 0;
-                
-                effects.create_explosion(drone.pos, RED, 15);
-                
-                if *lives <= 0 {
-                    *state = GameState::GameOver;
-                    event_queue.push(crate::events::Event::GameOver);
-                }
-            } else if shield_active {
-                // Rebond sur le bouclier
-                drone.vel = (drone.pos - *ship_pos).normalize() * 300.0;
-                drone.hp -= 1;
-                
-                effects.create_explosion(drone.pos, SKYBLUE, 8);
+    // Drones contre astéroïdes
+    for (id, drone) in drones.iter_mut_with_ids() {
+        if drone.is_destroyed() {
+            continue;
+        }
+        let shape = drone.collider();
+        asteroid_grid.query(shape.center(), shape.bounding_radius(), &mut candidates);
+        for &a in &candidates {
+            let asteroid_id = asteroids.id_at(a);
+            let asteroid = &mut asteroids[a];
+            if asteroid.is_destroyed() || !shape.intersects(&asteroid.collider_near(arena, drone.pos)) {
+                continue;
+            }
+            let asteroid_pos = arena.image_near(asteroid.pos, drone.pos);
+            let speed = elastic_collision(
+                drone.pos, drone.mass(), &mut drone.vel,
+                asteroid_pos, asteroid.mass(), &mut asteroid.vel,
+            );
+            let damage = impact_damage(speed);
+            if damage > 0 {
+                let pos = arena.wrap((drone.pos + asteroid_pos) * 0.5);
+                ctx.damage_enemy(id, drone, DamageInfo::new(damage, impact_weapon(asteroid), pos));
+                ctx.damage_enemy(asteroid_id, asteroid, DamageInfo::new(damage, WeaponKind::Collision, pos));
+                ctx.shake.trigger(0.2);
+            }
+            if drone.is_destroyed() {
+                break;
+            }
+        }
+    }
+
+    // Vaisseau-mère contre astéroïdes (sa coque garde sa vitesse)
+    for (id, part) in boss_parts.iter_mut_with_ids() {
+        if part.is_destroyed() {
+            continue;
+        }
+        let shape = part.collider();
+        asteroid_grid.query(shape.center(), shape.bounding_radius(), &mut candidates);
+        for &a in &candidates {
+            let asteroid_id = asteroids.id_at(a);
+            let asteroid = &mut asteroids[a];
+            if asteroid.is_destroyed() || !shape.intersects(&asteroid.collider_near(arena, part.pos)) {
+                continue;
+            }
+            let asteroid_pos = arena.image_near(asteroid.pos, part.pos);
+            let mut hull_vel = part.vel;
+            let speed = elastic_collision(
+                part.pos, part.mass(), &mut hull_vel,
+                asteroid_pos, asteroid.mass(), &mut asteroid.vel,
+            );
+            let damage = impact_damage(speed);
+            if damage > 0 {
+                let pos = arena.wrap((part.pos + asteroid_pos) * 0.5);
+                ctx.damage_enemy(id, part, DamageInfo::new(damage, impact_weapon(asteroid), pos));
+                ctx.damage_enemy(asteroid_id, asteroid, DamageInfo::new(damage, WeaponKind::Collision, pos));
+                ctx.shake.trigger(0.2);
+            }
+            if part.is_destroyed() {
+                break;
             }
         }
     }
 }
 
+/// Choc élastique entre deux corps (a en `pos_a`, b en `pos_b`) : les
+/// vitesses sont modifiées selon les masses, la normale du choc joint les
+/// centres. Retourne la vitesse d'impact (0 s'ils s'éloignent déjà)
+pub fn elastic_collision(
+    pos_a: Vec2,
+    mass_a: f32,
+    vel_a: &mut Vec2,
+    pos_b: Vec2,
+    mass_b: f32,
+    vel_b: &mut Vec2,
+) -> f32 {
+    let normal = match (pos_b - pos_a).try_normalize() {
+        Some(normal) => normal,
+        None => Vec2::X,
+    };
+    let speed = (*vel_a - *vel_b).dot(normal);
+    if speed <= 0.0 {
+        return 0.0;
+    }
+    let impulse = 2.0 * speed / (1.0 / mass_a + 1.0 / mass_b);
+    *vel_a -= normal * (impulse / mass_a);
+    *vel_b += normal * (impulse / mass_b);
+    speed
+}
+
+/// Dégâts d'un choc selon sa vitesse d'impact
+fn impact_damage(speed: f32) -> i32 {
+    (speed / IMPACT_SPEED_PER_DAMAGE) as i32
+}
+
+/// Arme créditée pour les dégâts infligés par un astéroïde
+fn impact_weapon(asteroid: &Asteroid) -> WeaponKind {
+    if asteroid.thrown_timer > 0.0 {
+        WeaponKind::Shield
+    } else {
+        WeaponKind::Collision
+    }
+}
+
 // -----------------------------------------------------------------------------
 // COLLISIONS VAISSEAU VS POWER-UPS
 // -----------------------------------------------------------------------------
 pub fn handle_powerup_collisions(
-    powerups: &mut Vec<PowerUp>,
-    ship_pos: &Vec2,
-    shield_energy: &mut Cooldown,
-    lives: &mut i32,
-    slowmo_energy: &mut Cooldown,
-    rapid_fire_timer: &mut Cooldown,
+    powerups: &mut SlotMap<PowerUp>,
+    ship: &mut Ship,
+    arena: &Arena,
     shake: &mut ScreenShake,
-    effects: &mut EffectSystem,
-    event_queue: &mut EventQueue,
+    events: &mut EventBus,
+    balance: &ShipBalance,
 ) {
+    let ship_collider = ship.collider();
     let mut i = 0;
     while i < powerups.len() {
-        if ship_pos.distance(powerups[i].pos) < 20.0 {
+        if powerups[i].collider_near(arena, ship_collider.center()).intersects(&ship_collider) {
             let powerup = &powerups[i];
-            
-            // Application de l'effet selon le type
-            match powerup.kind {
-                PowerUpType::Shield => {
-                    shield_energy.time = shield_energy.max;
-                    effects.create_text_effect(powerup.pos, SKYBLUE);
-                }
-                PowerUpType::Life => {
-                    *lives = (*lives + 1).min(5);
-                    effects.create_text_effect(powerup.pos, GREEN);
-                }
-                PowerUpType::SlowTime => {
-                    slowmo_energy.time = slowmo_energy.max;
-                    effects.create_text_effect(powerup.pos, PURPLE);
-                }
-                PowerUpType::RapidFire => {
-                    rapid_fire_timer.time = rapid_fire_timer.max;
-                    effects.create_text_effect(powerup.pos, ORANGE);
-                }
-            }
+            powerup.apply(ship, balance);
             
             shake.trigger(0.2);
-            event_queue.push(crate::events::Event::PowerUpSpawn { pos: powerup.pos });
+            events.emit(Event::PowerUpCollected { kind: powerup.kind, pos: powerup.pos });
             powerups.swap_remove(i);
         } else {
             i += 1;
@@ -5,59 +5,103 @@ use macroquad::prelude::*;
 // =============================================================================
 
 // -----------------------------------------------------------------------------
-// CONTRÔLES (TOUCHES) - MODIFIABLES ICI
-// -----------------------------------------------------------------------------
-pub const KEY_SHOOT: KeyCode = KeyCode::I;           // Tirer
-pub const KEY_SHIELD: KeyCode = KeyCode::U;             // Activer bouclier
-pub const KEY_REVERSE_BOOST: KeyCode = KeyCode::K;      // Recul boosté
-pub const KEY_SLOWMO: KeyCode = KeyCode::H;             // Slow-motion
-pub const KEY_MISSILE: KeyCode = KeyCode::J;            // Missile téléguidé
-pub const KEY_LASER: KeyCode = KeyCode::L;              // Laser perçant
-pub const KEY_PAUSE: KeyCode = KeyCode::P;              // Mettre en pause
+// TOUCHES FIXES DES MENUS
+// -----------------------------------------------------------------------------
+// Les touches de pilotage (mouvements, armes, pause) sont reconfigurables :
+// voir KeyBindings (bindings.rs) et l'écran des touches du menu.
 pub const KEY_MENU: KeyCode = KeyCode::Escape;          // Retour au menu
-pub const KEY_UP: KeyCode = KeyCode::W;                // Avancer
-pub const KEY_DOWN: KeyCode = KeyCode::S;            // Reculer
-pub const KEY_LEFT: KeyCode = KeyCode::A;            // Tourner gauche
-pub const KEY_RIGHT: KeyCode = KeyCode::D;          // Tourner droite
 pub const KEY_START: KeyCode = KeyCode::Enter;          // Commencer la partie
+pub const KEY_CONTINUE: KeyCode = KeyCode::C;           // Reprendre la partie sauvegardée
+pub const KEY_REPLAY: KeyCode = KeyCode::R;             // Revoir la dernière partie
+pub const KEY_SCORES: KeyCode = KeyCode::Tab;           // Menu : afficher le classement
+pub const KEY_BINDINGS: KeyCode = KeyCode::T;           // Menu : configurer les touches
+pub const KEY_ARENA_MODE: KeyCode = KeyCode::M;         // Menu : arène bornée / torique
+pub const KEY_MENU_UP: KeyCode = KeyCode::Up;           // Liste : élément précédent
+pub const KEY_MENU_DOWN: KeyCode = KeyCode::Down;       // Liste : élément suivant
+pub const KEY_CLEAR_BINDING: KeyCode = KeyCode::Backspace;  // Touches : vider l'action
+pub const KEY_PRESET_QWERTY: KeyCode = KeyCode::F1;     // Touches : préréglage QWERTY
+pub const KEY_PRESET_AZERTY: KeyCode = KeyCode::F2;     // Touches : préréglage AZERTY
+pub const KEY_TOGGLE_SCHEME: KeyCode = KeyCode::F3;     // Touches : schéma clavier / souris
+pub const KEY_REPLAY_STEP: KeyCode = KeyCode::N;        // Replay : avancer d'un tick
+pub const KEY_REPLAY_1X: KeyCode = KeyCode::Key1;       // Replay : vitesse normale
+pub const KEY_REPLAY_2X: KeyCode = KeyCode::Key2;       // Replay : vitesse x2
+pub const KEY_REPLAY_4X: KeyCode = KeyCode::Key4;       // Replay : vitesse x4
+pub const KEY_DEBUG_HITBOXES: KeyCode = KeyCode::F4;    // Debug : afficher les hitboxes
+pub const KEY_RELOAD_BALANCE: KeyCode = KeyCode::F5;    // Recharger balance.ron
 
 // -----------------------------------------------------------------------------
-// CONSTANTES DE GAMEPLAY - ÉQUILIBRAGE
-// -----------------------------------------------------------------------------
-pub const BULLET_SPEED: f32 = 800.0;                    // Vitesse des tirs
-pub const MAX_SHIELD_TIME: f32 = 5.0;                   // Durée max bouclier (secondes)
-pub const MAX_SLOWMO_TIME: f32 = 3.0;                   // Durée max slow-mo (secondes)
-pub const PLAYER_LIVES: i32 = 3;                        // Vies initiales
-pub const PLAYER_INVINCIBLE_TIME: f32 = 2.0;            // Invincibilité après dégât
-pub const POWERUP_SPAWN_CHANCE: f32 = 0.3;              // Chance de spawn power-up (30%)
-
-// -----------------------------------------------------------------------------
-// ARMES - MISSILES TÉLÉGUIDÉS
+// BOUCLE DE SIMULATION (PAS DE TEMPS FIXE)
 // -----------------------------------------------------------------------------
-pub const MISSILE_AMMO_MAX: i32 = 5;                    // Munitions max
-pub const MISSILE_COOLDOWN_MAX: f32 = 0.5;              // Délai entre deux tirs
-pub const MISSILE_LIFE: f32 = 3.0;                      // Durée de vie (secondes)
-pub const MISSILE_SPEED: f32 = 400.0;                   // Vitesse de déplacement
-pub const MISSILE_TURN_SPEED: f32 = 3.0;                // Agilité du virage
+pub const SIM_TICK_RATE: f32 = 60.0;                    // Ticks de simulation par seconde
+pub const SIM_DT: f32 = 1.0 / SIM_TICK_RATE;            // Durée d'un tick (secondes)
+pub const MAX_FRAME_TIME: f32 = 0.25;                   // Temps max rattrapé par frame
 
 // -----------------------------------------------------------------------------
-// ARMES - LASER PERÇANT
+// ÉQUILIBRAGE
 // -----------------------------------------------------------------------------
-pub const LASER_DURATION: f32 = 1.;                    // Durée du tir (secondes)
-pub const LASER_COOLDOWN: f32 = 5.0;                    // Temps de recharge
-pub const LASER_DAMAGE: i32 = 2;                        // Dégâts par impact
-pub const LASER_MAX_PENETRATION: i32 = 5;               // Ennemis traversés max
+// Les valeurs de gameplay (vies, bouclier, armes, drones, vagues...) sont dans
+// BalanceConfig (balance.rs), chargée depuis balance.ron et rechargeable à chaud.
 
 // -----------------------------------------------------------------------------
 // CONSTANTES PHYSIQUES ET DE COLLISION
 // -----------------------------------------------------------------------------
 pub const SCREEN_WIDTH: f32 = 800.0;                    // Largeur écran (par défaut)
 pub const SCREEN_HEIGHT: f32 = 600.0;                   // Hauteur écran (par défaut)
-pub const LASER_RANGE: f32 = 800.0;                     // Portée du laser
-pub const SHIP_RADIUS: f32 = 15.0;                      // Rayon de collision vaisseau
-pub const DRONE_COLLISION_RADIUS: f32 = 20.0;           // Rayon collision drones
-pub const BULLET_COLLISION_RADIUS: f32 = 15.0;          // Rayon collision balles
-pub const POWERUP_COLLISION_RADIUS: f32 = 20.0;         // Rayon collision power-ups
+// Formes de collision (voir collider.rs), identiques à ce qui est dessiné
+pub const SHIP_RADIUS: f32 = 15.0;                      // Rayon du triangle du vaisseau
+pub const SHIELD_RADIUS: f32 = 28.0;                    // Rayon de la bulle du bouclier
+pub const DRONE_HALF_SIZE: f32 = 10.0;                  // Demi-côté du carré des drones
+pub const MISSILE_RADIUS: f32 = 6.0;                    // Rayon du halo des missiles
+pub const POWERUP_RADIUS: f32 = 10.0;                   // Rayon de l'hexagone des power-ups
+pub const BOSS_CORE_RADIUS: f32 = 48.0;                 // Rayon de l'octogone du vaisseau-mère
+pub const BOSS_TURRET_RADIUS: f32 = 12.0;               // Rayon de l'hexagone des tourelles
+pub const LASER_HALF_WIDTH: f32 = 4.0;                  // Demi-largeur du rayon laser
+pub const GRID_CELL_SIZE: f32 = 64.0;                   // Côté d'une cellule de la grille spatiale
+
+// -----------------------------------------------------------------------------
+// MONDE ET CAMÉRA (VOIR CAMERA.RS)
+// -----------------------------------------------------------------------------
+pub const WORLD_SCREENS: f32 = 3.0;                     // Taille du monde, en écrans (par axe)
+pub const CAMERA_LEAD_TIME: f32 = 0.5;                  // Avance de la vue : vitesse × ce temps, s
+pub const CAMERA_MAX_LEAD: f32 = 150.0;                 // Avance maximale de la vue, px
+pub const CAMERA_SMOOTHING: f32 = 0.05;                 // Part de l'écart d'avance rattrapée par tick
+pub const ASTEROID_SPAWN_SPREAD: f32 = 0.5;             // Écart max à la visée du centre de la vue, rad
+
+// -----------------------------------------------------------------------------
+// DÉGÂTS (VOIR DAMAGE.RS)
+// -----------------------------------------------------------------------------
+// Les dégâts des missiles et du laser sont dans BalanceConfig (weapons)
+pub const ASTEROID_RADIUS_PER_HP: f32 = 4.0;            // Rayon perdu par point de dégât
+pub const ASTEROID_LARGE_RADIUS: (f32, f32) = (28.0, 36.0);     // Rayons d'un grand astéroïde
+pub const ASTEROID_MEDIUM_RADIUS: (f32, f32) = (18.0, 22.0);    // Rayons d'un moyen astéroïde
+pub const ASTEROID_SMALL_RADIUS: (f32, f32) = (10.0, 13.0);     // Rayons d'un petit astéroïde
+pub const ASTEROID_MIN_VERTICES: i32 = 8;               // Sommets min du contour d'un astéroïde
+pub const ASTEROID_CRATER_CHANCE: f32 = 0.2;            // Chance qu'un sommet soit un creux
+pub const ASTEROID_CHIP: f32 = 0.75;                    // Part gardée par le sommet touché
+pub const ASTEROID_MIN_OUTLINE: f32 = 0.4;              // Creux maximal d'un sommet (× rayon)
+pub const BULLET_DAMAGE: i32 = 1;                       // Balle (alliée ou ennemie)
+pub const RAM_DAMAGE: i32 = 1;                          // Vaisseau percuté par un ennemi
+pub const IMPACT_SPEED_PER_DAMAGE: f32 = 120.0;         // Choc entre ennemis : vitesse d'impact par point de dégât, px/s
+pub const SHIELD_THROW_TIME: f32 = 2.0;                 // Durée pendant laquelle un astéroïde renvoyé écrase pour le joueur
+pub const DRONE_DRIFT_FRICTION: f32 = 0.95;             // Vitesse de dérive conservée à chaque tick (tireurs, bombardiers)
+pub const SHIELD_DAMAGE: i32 = 1;                       // Drone renvoyé par le bouclier
+pub const KNOCKBACK_BULLET: f32 = 15.0;                 // Recul d'une balle, px/s
+pub const KNOCKBACK_MISSILE: f32 = 60.0;                // Recul d'un missile, px/s
+pub const KNOCKBACK_LASER: f32 = 30.0;                  // Recul du laser, px/s
+pub const KNOCKBACK_SHIELD: f32 = 300.0;                // Rebond d'un drone sur le bouclier, px/s
+pub const KNOCKBACK_RAM: f32 = 150.0;                   // Recul du vaisseau percuté, px/s
+pub const SCORE_ASTEROID_HIT: i32 = 10;                 // Points par coup sur un astéroïde
+pub const SCORE_DRONE_HIT: i32 = 20;                    // Points par coup sur un drone
+pub const SCORE_DRONE_KILL: i32 = 50;                   // Points pour un drone détruit
+
+// -----------------------------------------------------------------------------
+// NIVEAU DE MENACE (MUSIQUE ADAPTATIVE)
+// -----------------------------------------------------------------------------
+pub const THREAT_DRONES: f32 = 8.0;                     // Drones pour une menace maximale
+pub const THREAT_BULLETS: f32 = 5.0;                    // Balles proches pour une menace maximale
+pub const THREAT_BULLET_RANGE: f32 = 200.0;             // Distance d'une balle « proche »
+pub const THREAT_WAVES: f32 = 10.0;                     // Vagues pour une menace maximale
+pub const THREAT_STEP: f32 = 0.05;                      // Écart minimal signalé aux abonnés
 
 // -----------------------------------------------------------------------------
 // CONSTANTES UI - POSITIONNEMENT
@@ -65,3 +109,5 @@ pub const POWERUP_COLLISION_RADIUS: f32 = 20.0;         // Rayon collision power
 pub const UI_MARGIN: f32 = 20.0;                        // Marge générale
 pub const UI_BAR_WIDTH: f32 = 150.0;                    // Largeur barres d'état
 pub const UI_BAR_HEIGHT: f32 = 10.0;                    // Hauteur barres d'état
+pub const UI_MINIMAP_WIDTH: f32 = 180.0;                // Largeur de la minicarte (hauteur selon le monde)
+pub const UI_BOSS_BAR_WIDTH: f32 = 400.0;               // Largeur de la barre de vie du boss
@@ -0,0 +1,377 @@
+// =============================================================================
+// MODÈLE DE DÉGÂTS
+// =============================================================================
+//
+// Toutes les armes infligent leurs dégâts par le même chemin :
+// - DamageInfo : un coup (points de vie, arme, point d'impact, recul)
+// - Damageable : santé d'une entité (Asteroid, Drone, BossPart, Ship)
+// - DamageContext : applique un coup et en tire les conséquences, en un
+//   seul endroit (score et combo, événements, comptage des drones
+//   détruits, tirage des power-ups, fragments, perte de vie et fin de partie)
+//
+// Points de vie : un drone en a `hp` ; un astéroïde en a un par tranche de
+// ASTEROID_RADIUS_PER_HP px de rayon au-dessus du rayon de rupture de sa
+// taille (il rétrécit à chaque coup, puis se brise en fragments) ; une
+// partie du vaisseau-mère en a `hp` ; le vaisseau en a autant que de vies.
+//
+// Seules les armes du joueur (tir, missile, laser, bouclier) rapportent des
+// points et des power-ups ; le laser, qui touche à chaque tick, ne rapporte
+// que ses destructions (ni points par coup, ni combo). Les collisions entre
+// ennemis détruisent sans récompense, mais les drones détruits comptent
+// toujours pour la vague. Le noyau du vaisseau-mère lâche ses power-ups quoi
+// qu'il arrive (Enemy::loot).
+//
+// En arène torique, le point d'impact (balle, missile déjà ramenés dans
+// l'arène) peut être de l'autre côté d'un bord : il est d'abord remplacé par
+// sa copie la plus proche de l'ennemi, qui s'en sert pour s'écailler et
+// orienter ses fragments ; l'événement EnemyDamaged (effets, son) part
+// aussi de ce point.
+//
+// =============================================================================
+
+use macroquad::prelude::*;
+use crate::arena::Arena;
+use crate::balance::BalanceConfig;
+use crate::boss::{BossPart, BossPartKind};
+use crate::consts::*;
+use crate::enemies::{Asteroid, AsteroidSize, Drone, EnemyKind, PowerUp, PowerUpType};
+use crate::events::{Event, EventBus};
+use crate::game::{Cooldown, GameState, ScreenShake, World};
+use crate::rng::Rng;
+use crate::ship::Ship;
+use crate::slotmap::EntityId;
+use crate::weapons::WeaponKind;
+
+// -----------------------------------------------------------------------------
+// COUP PORTÉ
+// -----------------------------------------------------------------------------
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct DamageInfo {
+    pub amount: i32,                // Points de vie retirés
+    pub weapon: WeaponKind,         // Arme à l'origine du coup
+    pub pos: Vec2,                  // Point d'impact
+    pub knockback: Vec2,            // Vitesse ajoutée à la cible, px/s
+    pub source: Option<EntityId>,   // Ennemi responsable (None = joueur)
+}
+
+impl DamageInfo {
+    pub fn new(amount: i32, weapon: WeaponKind, pos: Vec2) -> Self {
+        Self { amount, weapon, pos, knockback: Vec2::ZERO, source: None }
+    }
+
+    /// Recul de `strength` px/s dans la direction `dir`
+    pub fn with_knockback(mut self, dir: Vec2, strength: f32) -> Self {
+        self.knockback = dir.normalize_or_zero() * strength;
+        self
+    }
+
+    /// Ennemi responsable du coup
+    pub fn from_source(mut self, source: EntityId) -> Self {
+        self.source = Some(source);
+        self
+    }
+}
+
+// -----------------------------------------------------------------------------
+// ENTITÉS ENDOMMAGEABLES
+// -----------------------------------------------------------------------------
+pub trait Damageable {
+    /// Points de vie restants
+    fn health(&self) -> i32;
+
+    /// Applique un coup (sans vérifier si l'entité est déjà détruite)
+    fn take_damage(&mut self, info: &DamageInfo);
+
+    fn is_destroyed(&self) -> bool {
+        self.health() <= 0
+    }
+}
+
+/// Ennemi : cible des armes du joueur
+pub trait Enemy: Damageable {
+    fn kind(&self) -> EnemyKind;
+    fn position(&self) -> Vec2;
+
+    /// Débris laissés à sa destruction par le coup `info`
+    fn fragments(&self, _info: &DamageInfo, _balance: &BalanceConfig, _rng: &mut Rng) -> Vec<Asteroid> {
+        Vec::new()
+    }
+
+    /// Power-ups garantis à sa destruction (en plus du tirage des armes
+    /// du joueur)
+    fn loot(&self, _balance: &BalanceConfig, _rng: &mut Rng) -> Vec<PowerUp> {
+        Vec::new()
+    }
+}
+
+impl Damageable for Asteroid {
+    fn health(&self) -> i32 {
+        ((self.radius - self.size.break_radius()) / ASTEROID_RADIUS_PER_HP).ceil() as i32
+    }
+
+    fn take_damage(&mut self, info: &DamageInfo) {
+        self.radius -= info.amount as f32 * ASTEROID_RADIUS_PER_HP;
+        self.chip(info.pos);
+        self.vel += info.knockback;
+    }
+}
+
+impl Enemy for Asteroid {
+    fn kind(&self) -> EnemyKind {
+        EnemyKind::Asteroid(self.size)
+    }
+
+    fn position(&self) -> Vec2 {
+        self.pos
+    }
+
+    /// Le coup pousse les fragments (sinon, éclatement depuis l'impact)
+    fn fragments(&self, info: &DamageInfo, balance: &BalanceConfig, rng: &mut Rng) -> Vec<Asteroid> {
+        let impact_dir = if info.knockback != Vec2::ZERO {
+            info.knockback
+        } else {
+            self.pos - info.pos
+        };
+        self.split(impact_dir, &balance.asteroids, rng)
+    }
+}
+
+impl Damageable for Drone {
+    fn health(&self) -> i32 {
+        self.hp
+    }
+
+    fn take_damage(&mut self, info: &DamageInfo) {
+        self.hp -= info.amount;
+        self.vel += info.knockback;
+    }
+}
+
+impl Enemy for Drone {
+    fn kind(&self) -> EnemyKind {
+        EnemyKind::Drone(self.kind)
+    }
+
+    fn position(&self) -> Vec2 {
+        self.pos
+    }
+}
+
+impl Damageable for BossPart {
+    fn health(&self) -> i32 {
+        self.hp
+    }
+
+    /// Le vaisseau-mère ne recule pas : sa coque garde sa trajectoire
+    fn take_damage(&mut self, info: &DamageInfo) {
+        self.hp -= info.amount;
+    }
+}
+
+impl Enemy for BossPart {
+    fn kind(&self) -> EnemyKind {
+        EnemyKind::Boss(self.kind)
+    }
+
+    fn position(&self) -> Vec2 {
+        self.pos
+    }
+
+    /// Noyau : une vie, puis des power-ups au hasard
+    fn loot(&self, balance: &BalanceConfig, rng: &mut Rng) -> Vec<PowerUp> {
+        if self.kind != BossPartKind::Core {
+            return Vec::new();
+        }
+        (0..balance.boss.reward_drops)
+            .map(|i| {
+                let mut powerup = PowerUp::new(self.pos, rng);
+                if i == 0 {
+                    powerup.kind = PowerUpType::Life;
+                }
+                powerup
+            })
+            .collect()
+    }
+}
+
+impl Damageable for Ship {
+    fn health(&self) -> i32 {
+        self.lives
+    }
+
+    fn take_damage(&mut self, info: &DamageInfo) {
+        self.lives -= info.amount;
+        self.invincible_timer.reset();
+        self.vel += info.knockback;
+    }
+}
+
+/// Récompenses d'un ennemi : (points par coup, points à la destruction,
+/// chance de power-up à la destruction)
+fn enemy_rewards(kind: EnemyKind, balance: &BalanceConfig) -> (i32, i32, f32) {
+    let a = &balance.asteroids;
+    match kind {
+        EnemyKind::Asteroid(AsteroidSize::Large) => (SCORE_ASTEROID_HIT, a.large_score, a.large_drop_chance),
+        EnemyKind::Asteroid(AsteroidSize::Medium) => (SCORE_ASTEROID_HIT, a.medium_score, a.medium_drop_chance),
+        EnemyKind::Asteroid(AsteroidSize::Small) => (SCORE_ASTEROID_HIT, a.small_score, a.small_drop_chance),
+        EnemyKind::Drone(_) => (SCORE_DRONE_HIT, SCORE_DRONE_KILL, balance.powerups.spawn_chance),
+        EnemyKind::Boss(BossPartKind::Core) => (balance.boss.hit_score, balance.boss.kill_score, 0.0),
+        EnemyKind::Boss(BossPartKind::Turret) => {
+            (balance.boss.hit_score, balance.boss.turret_score, balance.powerups.spawn_chance)
+        }
+    }
+}
+
+// -----------------------------------------------------------------------------
+// APPLICATION DES DÉGÂTS
+// -----------------------------------------------------------------------------
+/// Sous-états du jeu modifiés par les dégâts
+/// Les power-ups tirés et les fragments sont mis de côté : l'appelant les
+/// ajoute au monde avec `spawn_into` (les collections du monde sont
+/// empruntées pendant les tests)
+pub struct DamageContext<'a> {
+    pub score: &'a mut i32,
+    pub combo: &'a mut i32,
+    pub combo_timer: &'a mut Cooldown,
+    pub enemies_killed: &'a mut i32,
+    pub shake: &'a mut ScreenShake,
+    pub state: &'a mut GameState,
+    pub rng: &'a mut Rng,
+    pub events: &'a mut EventBus,
+    pub balance: &'a BalanceConfig,
+    pub arena: &'a Arena,
+    pub drops: Vec<PowerUp>,
+    pub fragments: Vec<Asteroid>,
+}
+
+impl DamageContext<'_> {
+    /// Inflige un coup à l'ennemi `id` ; retourne true s'il le détruit
+    /// Un ennemi déjà détruit (en attente de nettoyage) n'est pas touché
+    pub fn damage_enemy(&mut self, id: EntityId, enemy: &mut impl Enemy, info: DamageInfo) -> bool {
+        if enemy.is_destroyed() {
+            return false;
+        }
+        // Impact vu depuis l'ennemi (à travers les bords en arène torique)
+        let hit = DamageInfo { pos: self.arena.image_near(info.pos, enemy.position()), ..info };
+        enemy.take_damage(&hit);
+
+        let kind = enemy.kind();
+        let (hit_score, kill_score, drop_chance) = enemy_rewards(kind, self.balance);
+        let by_player = info.weapon.is_player_weapon();
+        if by_player && !info.weapon.is_continuous() {
+            *self.score += hit_score;
+            *self.combo += 1;
+            self.combo_timer.reset();
+        }
+        self.events.emit(Event::EnemyDamaged { kind, pos: hit.pos, by_weapon: info.weapon });
+
+        if !enemy.is_destroyed() {
+            return false;
+        }
+        let pos = enemy.position();
+        if matches!(kind, EnemyKind::Drone(_) | EnemyKind::Boss(BossPartKind::Core)) {
+            *self.enemies_killed += 1;
+        }
+        self.events.emit(Event::EnemyKilled { id, kind, pos, by_weapon: info.weapon });
+        if by_player {
+            *self.score += kill_score;
+            if self.rng.chance(drop_chance) {
+                self.drops.push(PowerUp::new(pos, self.rng));
+            }
+        }
+        self.drops.extend(enemy.loot(self.balance, self.rng));
+        self.fragments.extend(enemy.fragments(&hit, self.balance, self.rng));
+        true
+    }
+
+    /// Ajoute au monde les power-ups et fragments produits par les coups
+    /// (ramenés dans l'arène torique : un fragment peut naître au-delà d'un bord)
+    pub fn spawn_into(self, world: &mut World) {
+        let arena = self.arena;
+        for mut powerup in self.drops {
+            arena.wrap_motion(&mut powerup.pos, &mut powerup.prev_pos);
+            world.powerups.push(powerup);
+        }
+        for mut fragment in self.fragments {
+            arena.wrap_motion(&mut fragment.pos, &mut fragment.prev_pos);
+            world.asteroids.push(fragment);
+        }
+    }
+
+    /// Inflige un coup au vaisseau ; retourne true si une vie est perdue
+    /// Le bouclier et l'invincibilité sont vérifiés par l'appelant, qui
+    /// décide de ce qu'ils bloquent
+    pub fn damage_ship(&mut self, ship: &mut Ship, info: DamageInfo) -> bool {
+        if ship.is_destroyed() {
+            return false;
+        }
+        ship.take_damage(&info);
+        self.shake.trigger(match info.weapon {
+            WeaponKind::Collision => 0.5,
+            _ => 0.3,
+        });
+        self.events.emit(Event::PlayerHit { pos: ship.pos, lives_left: ship.lives, by: info.source });
+
+        if ship.is_destroyed() {
+            *self.state = GameState::GameOver;
+        }
+        true
+    }
+}
+
+// =============================================================================
+// TESTS
+// =============================================================================
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::arena::ArenaMode;
+    use crate::slotmap::SlotMap;
+
+    #[test]
+    fn impact_across_toroidal_edge_chips_the_facing_side() {
+        let arena = Arena::new(vec2(800., 600.), ArenaMode::Toroidal);
+        let balance = BalanceConfig::default();
+        let mut rng = Rng::new(3);
+        let mut asteroid = Asteroid::new(vec2(790., 300.), 1.0, &balance.asteroids, &mut rng);
+        asteroid.size = AsteroidSize::Large;
+        asteroid.radius = 60.0;
+        asteroid.rotation = 0.0;
+        asteroid.outline = vec![1.0; 8];
+        let mut asteroids = SlotMap::new();
+        let id = asteroids.push(asteroid);
+
+        let (mut score, mut combo, mut killed) = (0, 0, 0);
+        let mut combo_timer = Cooldown::new(2.0);
+        let mut shake = ScreenShake::new();
+        let mut state = GameState::Playing;
+        let mut events = EventBus::new();
+        let mut ctx = DamageContext {
+            score: &mut score,
+            combo: &mut combo,
+            combo_timer: &mut combo_timer,
+            enemies_killed: &mut killed,
+            shake: &mut shake,
+            state: &mut state,
+            rng: &mut rng,
+            events: &mut events,
+            balance: &balance,
+            arena: &arena,
+            drops: Vec::new(),
+            fragments: Vec::new(),
+        };
+
+        // Balle ramenée au bord gauche : elle frappe le flanc droit
+        let hit = DamageInfo::new(1, WeaponKind::Cannon, vec2(5., 300.));
+        ctx.damage_enemy(id, &mut asteroids[0], hit);
+        let outline = &asteroids[0].outline;
+        assert!(outline[0] < 1.0, "flanc droit intact : {outline:?}");
+        assert_eq!(outline[4], 1.0, "flanc gauche écaillé : {outline:?}");
+
+        // Effets et son du coup suivent l'impact résolu, côté astéroïde
+        assert!(matches!(
+            events.pending(),
+            [Event::EnemyDamaged { pos, .. }] if *pos == vec2(805., 300.)
+        ));
+    }
+}
@@ -8,12 +8,18 @@
 // - Flashs de tir
 // - Effets de texte (power-ups)
 //
+// Les effets sont déclenchés par les événements du gameplay (abonné du bus,
+// voir events.rs) et tirent leur aléatoire d'un flux cosmétique séparé.
+//
 // =============================================================================
 
 use macroquad::prelude::*;
 use std::collections::VecDeque;
-use crate::events::EventQueue;
-use crate::events::Event;
+use crate::boss::BossPartKind;
+use crate::enemies::{AsteroidSize, EnemyKind, PowerUpType};
+use crate::events::{Event, EventListener};
+use crate::rng::Rng;
+use crate::weapons::WeaponKind;
 
 // -----------------------------------------------------------------------------
 // SYSTÈME D'EFFETS
@@ -21,6 +27,13 @@ use crate::events::Event;
 pub struct EffectSystem {
     pub particles: Vec<Particle>,
     pub trail: VecDeque<TrailPoint>,
+    pub rng: Rng,   // Flux cosmétique (n'influence jamais le gameplay)
+}
+
+impl Default for EffectSystem {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl EffectSystem {
@@ -29,6 +42,7 @@ impl EffectSystem {
         Self {
             particles: Vec::new(),
             trail: VecDeque::new(),
+            rng: Rng::default(),
         }
     }
 
@@ -57,7 +71,7 @@ impl EffectSystem {
     /// Crée une explosion de particules
     pub fn create_explosion(&mut self, pos: Vec2, color: Color, count: usize) {
         for _ in 0..count {
-            self.particles.push(create_explosion_particle(pos, color));
+            self.particles.push(create_explosion_particle(pos, color, &mut self.rng));
         }
     }
 
@@ -66,7 +80,7 @@ impl EffectSystem {
         for _ in 0..2 {
             self.particles.push(Particle {
                 pos,
-                vel: dir * 100.0 + vec2(rand::gen_range(-50., 50.), rand::gen_range(-50., 50.)),
+                vel: dir * 100.0 + vec2(self.rng.gen_range(-50., 50.), self.rng.gen_range(-50., 50.)),
                 life: 0.2,
                 color: SKYBLUE,
                 size: 1.5,
@@ -79,7 +93,7 @@ impl EffectSystem {
         for _ in 0..5 {
             self.particles.push(Particle {
                 pos,
-                vel: dir * 150.0 + vec2(rand::gen_range(-50., 50.), rand::gen_range(-50., 50.)),
+                vel: dir * 150.0 + vec2(self.rng.gen_range(-50., 50.), self.rng.gen_range(-50., 50.)),
                 life: 0.3,
                 color: ORANGE,
                 size: 2.0,
@@ -92,7 +106,7 @@ impl EffectSystem {
         for _ in 0..10 {
             self.particles.push(Particle {
                 pos: pos + dir * 30.0,
-                vel: dir * 300.0 + vec2(rand::gen_range(-100., 100.), rand::gen_range(-100., 100.)),
+                vel: dir * 300.0 + vec2(self.rng.gen_range(-100., 100.), self.rng.gen_range(-100., 100.)),
                 life: 0.3,
                 color: Color::new(1.0, 0.2, 0.2, 1.0),
                 size: 4.0,
@@ -103,11 +117,11 @@ impl EffectSystem {
     /// Crée l'effet du boost de recul
     pub fn create_reverse_boost(&mut self, pos: Vec2, rot: f32) {
         for _ in 0..3 {
-            let angle = rand::gen_range(rot - 0.5, rot + 0.5);
+            let angle = self.rng.gen_range(rot - 0.5, rot + 0.5);
             let boost_dir = Vec2::from_angle(angle);
             self.particles.push(Particle {
                 pos: pos + boost_dir * 25.0,
-                vel: boost_dir * 400.0 + vec2(rand::gen_range(-100., 100.), rand::gen_range(-100., 100.)),
+                vel: boost_dir * 400.0 + vec2(self.rng.gen_range(-100., 100.), self.rng.gen_range(-100., 100.)),
                 life: 0.4,
                 color: Color::new(0.8, 0.2, 0.8, 1.0),
                 size: 3.0,
@@ -120,7 +134,7 @@ impl EffectSystem {
         for _ in 0..20 {
             self.particles.push(Particle {
                 pos,
-                vel: vec2(rand::gen_range(-50., 50.), rand::gen_range(-100., -50.)),
+                vel: vec2(self.rng.gen_range(-50., 50.), self.rng.gen_range(-100., -50.)),
                 life: 1.0,
                 color,
                 size: 3.0,
@@ -135,24 +149,59 @@ impl EffectSystem {
             life: 0.5,
         });
     }
+}
 
-    /// Traite les événements du système d'effets
-    pub fn process_events(&mut self, event_queue: &mut EventQueue) {
-        while let Some(event) = event_queue.pop() {
-            match event {
-                Event::Explosion { pos, color, count } => {
-                    self.create_explosion(pos, color, count);
-                }
-                Event::PowerUpSpawn { pos } => {
-                    self.create_text_effect(pos, GOLD);
-                }
-                Event::LaserFired { pos, dir } => {
-                    self.create_laser_flash(pos, dir);
-                }
-                Event::GameOver => {
-                    // Rien à faire ici, géré par les scènes
+impl EventListener for EffectSystem {
+    /// Effets visuels associés à chaque événement du gameplay
+    fn on_event(&mut self, event: &Event) {
+        match *event {
+            Event::WeaponFired { weapon, pos, dir } => match weapon {
+                WeaponKind::Cannon => self.create_muzzle_flash(pos + dir * 10.0, dir),
+                WeaponKind::Missile => self.create_missile_launch(pos + dir * 20.0, dir),
+                WeaponKind::Laser => self.create_laser_flash(pos, dir),
+                WeaponKind::Shield | WeaponKind::Collision => {}
+            },
+            Event::EnemyDamaged { kind, pos, by_weapon } => match (by_weapon, kind) {
+                (WeaponKind::Cannon, EnemyKind::Asteroid(_)) => self.create_explosion(pos, WHITE, 10),
+                (WeaponKind::Cannon, EnemyKind::Drone(_)) => self.create_explosion(pos, RED, 8),
+                (WeaponKind::Cannon, EnemyKind::Boss(_)) => self.create_explosion(pos, MAGENTA, 8),
+                (WeaponKind::Missile, _) => self.create_explosion(pos, ORANGE, 12),
+                (WeaponKind::Laser, _) => self.create_explosion(pos, RED, 6),
+                (WeaponKind::Shield, _) => self.create_explosion(pos, SKYBLUE, 8),
+                (WeaponKind::Collision, _) => {}
+            },
+            Event::EnemyKilled { kind, pos, .. } => match kind {
+                EnemyKind::Asteroid(size) => {
+                    let particles = match size {
+                        AsteroidSize::Small => 8,
+                        AsteroidSize::Medium => 12,
+                        AsteroidSize::Large => 18,
+                    };
+                    self.create_explosion(pos, WHITE, particles)
                 }
+                EnemyKind::Drone(_) => self.create_explosion(pos, ORANGE, 15),
+                EnemyKind::Boss(BossPartKind::Turret) => self.create_explosion(pos, ORANGE, 20),
+                EnemyKind::Boss(BossPartKind::Core) => self.create_explosion(pos, GOLD, 80),
+            },
+            Event::PlayerHit { pos, .. } => self.create_explosion(pos, RED, 12),
+            Event::ShieldBlocked { pos } => self.create_explosion(pos, SKYBLUE, 6),
+            Event::PowerUpCollected { kind, pos } => {
+                let color = match kind {
+                    PowerUpType::Shield => SKYBLUE,
+                    PowerUpType::Life => GREEN,
+                    PowerUpType::SlowTime => PURPLE,
+                    PowerUpType::RapidFire => ORANGE,
+                };
+                self.create_text_effect(pos, color);
             }
+            Event::WaveCompleted { pos, .. } => self.create_explosion(pos, GOLD, 50),
+            Event::BossArrived { pos } => self.create_text_effect(pos, MAGENTA),
+            Event::BossPhaseChanged { pos, .. } => self.create_explosion(pos, MAGENTA, 40),
+            Event::RunStarted { .. }
+            | Event::ViewMoved { .. }
+            | Event::TimeScaleChanged { .. }
+            | Event::ThreatChanged { .. }
+            | Event::GameOver => {}   // Géré par les scènes
         }
     }
 }
@@ -181,12 +230,12 @@ pub struct TrailPoint {
 // -----------------------------------------------------------------------------
 
 /// Crée une particule d'explosion standard
-pub fn create_explosion_particle(pos: Vec2, color: Color) -> Particle {
+pub fn create_explosion_particle(pos: Vec2, color: Color, rng: &mut Rng) -> Particle {
     Particle {
         pos,
-        vel: vec2(rand::gen_range(-200., 200.), rand::gen_range(-200., 200.)),
-        life: rand::gen_range(0.3, 0.6),
+        vel: vec2(rng.gen_range(-200., 200.), rng.gen_range(-200., 200.)),
+        life: rng.gen_range(0.3, 0.6),
         color,
-        size: rand::gen_range(2.0, 5.0),
+        size: rng.gen_range(2.0, 5.0),
     }
 }
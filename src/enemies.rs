@@ -3,7 +3,7 @@
 // =============================================================================
 //
 // Ce fichier contient toutes les entités hostiles et items :
-// - Astéroïdes
+// - Astéroïdes (trois tailles, fragmentation, contour irrégulier)
 // - Drones (Sniper, Kamikaze, Bomber)
 // - Balles ennemies
 // - Power-ups (bonus)
@@ -11,69 +11,248 @@
 // =============================================================================
 
 use macroquad::prelude::*;
-use crate::ship::Ship;
+use serde::{Deserialize, Serialize};
+use crate::arena::Arena;
+use crate::balance::{AsteroidBalance, DroneBalance, ShipBalance};
+use crate::boss::BossPartKind;
+use crate::collider::{Collider, MAX_POLYGON_POINTS};
+use crate::consts::*;
+use crate::damage::Damageable;
 use crate::game::Cooldown;
+use crate::rng::Rng;
+use crate::slotmap::EntityId;
+
+// -----------------------------------------------------------------------------
+// TYPE D'ENNEMI (ÉVÉNEMENTS, STATISTIQUES)
+// -----------------------------------------------------------------------------
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum EnemyKind {
+    Asteroid(AsteroidSize),
+    Drone(DroneType),
+    Boss(BossPartKind),
+}
+
+// -----------------------------------------------------------------------------
+// TAILLE D'ASTÉROÏDE
+// -----------------------------------------------------------------------------
+// Un astéroïde détruit se brise en fragments de la taille inférieure ;
+// les petits disparaissent. Score et chance de drop : voir AsteroidBalance.
+#[derive(PartialEq, Eq, Clone, Copy, Debug, Default, Serialize, Deserialize)]
+pub enum AsteroidSize {
+    Small,
+    #[default]
+    Medium,
+    Large,
+}
+
+impl AsteroidSize {
+    /// Rayons possibles à l'apparition (min, max)
+    pub fn radius_range(&self) -> (f32, f32) {
+        match self {
+            AsteroidSize::Small => ASTEROID_SMALL_RADIUS,
+            AsteroidSize::Medium => ASTEROID_MEDIUM_RADIUS,
+            AsteroidSize::Large => ASTEROID_LARGE_RADIUS,
+        }
+    }
+
+    /// Rayon sous lequel l'astéroïde est détruit : un astéroïde de rayon
+    /// minimal encaisse un coup
+    pub fn break_radius(&self) -> f32 {
+        self.radius_range().0 - ASTEROID_RADIUS_PER_HP
+    }
+
+    /// Taille des fragments (None = pas de fragments)
+    pub fn smaller(&self) -> Option<AsteroidSize> {
+        match self {
+            AsteroidSize::Small => None,
+            AsteroidSize::Medium => Some(AsteroidSize::Small),
+            AsteroidSize::Large => Some(AsteroidSize::Medium),
+        }
+    }
+
+    /// Taille correspondant à un rayon (sauvegardes sans taille)
+    pub fn from_radius(radius: f32) -> Self {
+        if radius >= ASTEROID_LARGE_RADIUS.0 {
+            AsteroidSize::Large
+        } else if radius >= ASTEROID_MEDIUM_RADIUS.0 {
+            AsteroidSize::Medium
+        } else {
+            AsteroidSize::Small
+        }
+    }
+}
 
 // -----------------------------------------------------------------------------
 // ASTÉROÏDE
 // -----------------------------------------------------------------------------
+// Le contour est un polygone étoilé (voir collider.rs) : un sommet tous les
+// TAU / n radians, à `outline[i] × radius` du centre, tourné de `rotation`.
+// Il est tiré d'une graine à l'apparition ; chaque coup creuse le sommet le
+// plus proche de l'impact. C'est aussi la forme de collision.
+#[derive(Clone, Serialize, Deserialize)]
 pub struct Asteroid {
     pub pos: Vec2,
+    pub prev_pos: Vec2,     // Position au tick précédent (interpolation du rendu)
     pub vel: Vec2,
     pub radius: f32,
+    #[serde(default)]
+    pub size: AsteroidSize,
+    #[serde(default)]
+    pub outline: Vec<f32>,  // Distance de chaque sommet (× radius), vide = cercle
     pub rotation: f32,
     pub rotation_speed: f32,
+    #[serde(default)]
+    pub thrown_timer: f32,  // > 0 : renvoyé par le bouclier, ses chocs comptent pour le joueur
 }
 
 impl Asteroid {
-    /// Crée un nouvel astéroïde
-    pub fn new(pos: Vec2, difficulty: f32) -> Self {
+    /// Crée un nouvel astéroïde (grand ou moyen)
+    pub fn new(pos: Vec2, difficulty: f32, balance: &AsteroidBalance, rng: &mut Rng) -> Self {
+        let size = if rng.chance(balance.large_chance) {
+            AsteroidSize::Large
+        } else {
+            AsteroidSize::Medium
+        };
+        let (min, max) = size.radius_range();
         Self {
             pos,
+            prev_pos: pos,
             vel: vec2(
-                rand::gen_range(-50., 50.),
-                rand::gen_range(50., 150.) * difficulty.min(3.0),
+                rng.gen_range(-50., 50.),
+                rng.gen_range(50., 150.) * difficulty.min(3.0),
             ),
-            radius: rand::gen_range(15., 35.),
+            radius: rng.gen_range(min, max),
+            size,
+            outline: Self::random_outline(rng.next_u64()),
             rotation: 0.0,
-            rotation_speed: rand::gen_range(-2.0, 2.0),
+            rotation_speed: rng.gen_range(-2.0, 2.0),
+            thrown_timer: 0.0,
         }
     }
 
+    /// Contour irrégulier tiré de `seed` : quelques sommets en creux, les
+    /// autres proches du rayon
+    pub fn random_outline(seed: u64) -> Vec<f32> {
+        let mut rng = Rng::new(seed);
+        let count = rng.gen_range(ASTEROID_MIN_VERTICES, MAX_POLYGON_POINTS as i32 + 1);
+        (0..count)
+            .map(|_| {
+                if rng.chance(ASTEROID_CRATER_CHANCE) {
+                    rng.gen_range(0.55, 0.7)
+                } else {
+                    rng.gen_range(0.8, 1.0)
+                }
+            })
+            .collect()
+    }
+
+    /// Creuse le sommet du contour le plus proche du point d'impact
+    pub fn chip(&mut self, impact: Vec2) {
+        let count = self.outline.len().min(MAX_POLYGON_POINTS);
+        if count == 0 {
+            return;
+        }
+        let to_impact = impact - self.pos;
+        let angle = to_impact.y.atan2(to_impact.x) - self.rotation;
+        let step = std::f32::consts::TAU / count as f32;
+        let i = ((angle / step).round() as i32).rem_euclid(count as i32) as usize;
+        self.outline[i] = (self.outline[i] * ASTEROID_CHIP).max(ASTEROID_MIN_OUTLINE);
+    }
+
+    /// Fragments de l'astéroïde détruit (vide pour un petit)
+    /// Les fragments, de même rayon, partent en étoile autour d'une vitesse
+    /// commune : celle du parent (recul de l'impact compris), mise à
+    /// l'échelle de leur masse totale pour conserver la quantité de
+    /// mouvement (les écarts de l'étoile s'annulent). L'étoile est orientée
+    /// en travers de `impact_dir`
+    pub fn split(&self, impact_dir: Vec2, balance: &AsteroidBalance, rng: &mut Rng) -> Vec<Asteroid> {
+        let Some(size) = self.size.smaller() else {
+            return Vec::new();
+        };
+        let count = rng.gen_range(balance.fragments_min, balance.fragments_max + 1);
+        let (min, max) = size.radius_range();
+        let radius = rng.gen_range(min, max);
+        let fragments_mass = count as f32 * std::f32::consts::PI * radius * radius;
+        let drift = self.vel * self.mass() / fragments_mass;
+        let base = impact_dir.y.atan2(impact_dir.x) + std::f32::consts::FRAC_PI_2
+            + rng.gen_range(-0.3, 0.3);
+
+        (0..count)
+            .map(|i| {
+                let dir = Vec2::from_angle(base + i as f32 * std::f32::consts::TAU / count as f32);
+                let pos = self.pos + dir * radius * 0.5;
+                Self {
+                    pos,
+                    prev_pos: pos,
+                    vel: drift + dir * balance.fragment_speed,
+                    radius,
+                    size,
+                    outline: Self::random_outline(rng.next_u64()),
+                    rotation: self.rotation,
+                    rotation_speed: rng.gen_range(-3.0, 3.0),
+                    thrown_timer: 0.0,
+                }
+            })
+            .collect()
+    }
+
     /// Met à jour la position et la rotation
     pub fn update(&mut self, dt: f32) {
         self.pos += self.vel * dt;
         self.rotation += self.rotation_speed * dt;
+        self.thrown_timer = (self.thrown_timer - dt).max(0.0);
+    }
+
+    /// Masse (aire du disque) pour les chocs élastiques
+    pub fn mass(&self) -> f32 {
+        std::f32::consts::PI * self.radius * self.radius
     }
 
     /// Vérifie si l'astéroïde est toujours actif
-    pub fn is_alive(&self) -> bool {
-        self.radius > 10.0 
-            && self.pos.y > -100.0 
-            && self.pos.y < screen_height() + 100.0
-            && self.pos.x > -100.0 
-            && self.pos.x < screen_width() + 100.0
+    pub fn is_alive(&self, arena: &Arena) -> bool {
+        !self.is_destroyed() && arena.contains(self.pos, 100.0)
     }
 
-    /// Inflige des dégâts et retourne true si détruit
-    pub fn take_damage(&mut self, damage: f32) -> bool {
-        self.radius -= damage;
-        self.radius <= 10.0
+    /// Contour à une position (collisions et rendu)
+    /// Sans contour (sauvegarde antérieure), un simple cercle
+    pub fn hull_at(&self, pos: Vec2) -> Collider {
+        let count = self.outline.len().min(MAX_POLYGON_POINTS);
+        if count < 3 {
+            return Collider::circle(pos, self.radius);
+        }
+        let step = std::f32::consts::TAU / count as f32;
+        let mut points = [Vec2::ZERO; MAX_POLYGON_POINTS];
+        for (i, (point, &scale)) in points.iter_mut().zip(&self.outline).enumerate() {
+            *point = pos + Vec2::from_angle(self.rotation + i as f32 * step) * self.radius * scale;
+        }
+        Collider::star(pos, &points[..count])
+    }
+
+    /// Forme de collision
+    pub fn collider(&self) -> Collider {
+        self.hull_at(self.pos)
+    }
+
+    /// Forme de collision de la copie la plus proche de `near`
+    pub fn collider_near(&self, arena: &Arena, near: Vec2) -> Collider {
+        self.hull_at(arena.image_near(self.pos, near))
     }
 }
 
 // -----------------------------------------------------------------------------
 // DRONE ENNEMI
 // -----------------------------------------------------------------------------
-#[derive(PartialEq, Clone)]
+#[derive(PartialEq, Eq, Clone, Copy, Debug, Serialize, Deserialize)]
 pub enum DroneType {
     Sniper,     // Tireur à distance
     Kamikaze,   // Se précipite sur le joueur
     Bomber,     // Lâche des bombes
 }
 
+#[derive(Clone, Serialize, Deserialize)]
 pub struct Drone {
     pub pos: Vec2,
+    pub prev_pos: Vec2,     // Position au tick précédent (interpolation du rendu)
     pub vel: Vec2,
     pub cooldown: Cooldown,
     pub kind: DroneType,
@@ -83,23 +262,24 @@ pub struct Drone {
 
 impl Drone {
     /// Crée un nouveau drone avec un type aléatoire
-    pub fn new(pos: Vec2, difficulty: f32, wave: i32) -> Self {
-        let kind = match rand::gen_range(0, 3) {
+    pub fn new(pos: Vec2, difficulty: f32, wave: i32, balance: &DroneBalance, rng: &mut Rng) -> Self {
+        let kind = match rng.gen_range(0, 3) {
             0 => DroneType::Sniper,
             1 => DroneType::Kamikaze,
             _ => DroneType::Bomber,
         };
 
         let cooldown = match kind {
-            DroneType::Sniper => 2.0 / difficulty.min(3.0),
-            DroneType::Bomber => 3.0,
+            DroneType::Sniper => balance.sniper_first_shot / difficulty.min(3.0),
+            DroneType::Bomber => balance.bomber_cooldown,
             _ => 0.0,
         };
 
-        let max_hp = 2 + (wave / 3);
+        let max_hp = balance.base_hp + (wave / balance.waves_per_hp);
 
         Self {
             pos,
+            prev_pos: pos,
             vel: Vec2::ZERO,
             cooldown: Cooldown::new(cooldown),
             kind,
@@ -109,53 +289,71 @@ impl Drone {
     }
 
     /// Met à jour le comportement selon le type
-    pub fn update(&mut self, dt: f32, ship_pos: &Vec2) {
+    pub fn update(&mut self, dt: f32, ship_pos: &Vec2, balance: &DroneBalance) {
         self.cooldown.tick(dt);
         
         match self.kind {
             DroneType::Kamikaze => {
-                // Poursuite agressive
+                // Poursuite agressive (lissage appliqué à chaque tick fixe)
                 let to_player = (*ship_pos - self.pos).normalize();
-                self.vel = self.vel.lerp(to_player * 40.0, 0.1);
+                self.vel = self.vel.lerp(to_player * balance.kamikaze_speed, balance.kamikaze_steering);
                 self.pos += self.vel * dt;
             }
             DroneType::Sniper => {
                 // Descente lente
-                self.pos.y += 20.0 * dt;
+                self.pos.y += balance.sniper_speed * dt;
             }
             DroneType::Bomber => {
                 // Déplacement horizontal vers le joueur
-                self.pos.x += (ship_pos.x - self.pos.x).signum() * 80.0 * dt;
-                self.pos.y += 30.0 * dt;
+                self.pos.x += (ship_pos.x - self.pos.x).signum() * balance.bomber_speed_x * dt;
+                self.pos.y += balance.bomber_speed_y * dt;
             }
         }
+
+        // Dérive après un choc (le kamikaze, lui, se dirige par sa vitesse)
+        if self.kind != DroneType::Kamikaze {
+            self.pos += self.vel * dt;
+            self.vel *= DRONE_DRIFT_FRICTION;
+        }
     }
 
     /// Tire sur le joueur (retourne Option<Bullet>)
-    pub fn shoot(&mut self, _dt: f32, ship: &Ship) -> Option<Bullet> {
+    /// `id` : identifiant du drone, propriétaire de la balle
+    pub fn shoot(
+        &mut self,
+        id: EntityId,
+        _dt: f32,
+        ship_pos: &Vec2,
+        ship_vel: &Vec2,
+        balance: &DroneBalance,
+    ) -> Option<Bullet> {
         if self.cooldown.ready() {
             match self.kind {
                 DroneType::Sniper => {
                     // Tir avec avance (lead)
-                    let lead_dir = (ship.pos + ship.vel * 0.3 - self.pos).normalize();
-                    self.cooldown.time = 2.5;
+                    let lead_dir = (*ship_pos + *ship_vel * balance.sniper_lead - self.pos).normalize();
+                    self.cooldown.time = balance.sniper_cooldown;
                     Some(Bullet {
                         pos: self.pos,
-                        vel: lead_dir * 400.0,
+                        prev_pos: self.pos,
+                        vel: lead_dir * balance.sniper_bullet_speed,
                         enemy: true,
+                        owner: Some(id),
                         size: 4.0,
-                        life: 3.0,
+                        life: balance.bullet_life,
                     })
                 }
                 DroneType::Bomber => {
                     // Tir vertical
-                    self.cooldown.time = 3.0;
+                    self.cooldown.time = balance.bomber_cooldown;
                     Some(Bullet {
                         pos: self.pos,
-                        vel: vec2(0.0, 150.0),
+                        prev_pos: self.pos,
+                        vel: vec2(0.0, balance.bomber_bullet_speed),
                         enemy: true,
+                        owner: Some(id),
                         size: 6.0,
-                        life: 3.0,
+                        life: balance.bullet_life,
                     })
                 }
                 _ => None,
@@ -166,12 +364,28 @@ impl Drone {
     }
 
     /// Vérifie si le drone est toujours actif
-    pub fn is_alive(&self) -> bool {
-        self.hp > 0 
-            && self.pos.y > -50.0 
-            && self.pos.y < screen_height() + 50.0
-            && self.pos.x > -50.0 
-            && self.pos.x < screen_width() + 50.0
+    pub fn is_alive(&self, arena: &Arena) -> bool {
+        !self.is_destroyed() && arena.contains(self.pos, 50.0)
+    }
+
+    /// Carré du drone à une position (collisions et rendu)
+    pub fn hull_at(pos: Vec2) -> Collider {
+        Collider::rect(pos, Vec2::splat(DRONE_HALF_SIZE))
+    }
+
+    /// Forme de collision
+    pub fn collider(&self) -> Collider {
+        Self::hull_at(self.pos)
+    }
+
+    /// Forme de collision de la copie la plus proche de `near`
+    pub fn collider_near(&self, arena: &Arena, near: Vec2) -> Collider {
+        Self::hull_at(arena.image_near(self.pos, near))
+    }
+
+    /// Masse (aire du carré) pour les chocs élastiques
+    pub fn mass(&self) -> f32 {
+        4.0 * DRONE_HALF_SIZE * DRONE_HALF_SIZE
     }
 
     /// Pourcentage de vie restant (pour barre de vie)
@@ -183,10 +397,14 @@ impl Drone {
 // -----------------------------------------------------------------------------
 // BALLE (ALLIÉE OU ENNEMIE)
 // -----------------------------------------------------------------------------
+#[derive(Clone, Serialize, Deserialize)]
 pub struct Bullet {
     pub pos: Vec2,
+    pub prev_pos: Vec2,     // Position au tick précédent (rendu, balayage des collisions)
     pub vel: Vec2,
     pub enemy: bool,    // true = ennemi, false = allié
+    #[serde(default)]
+    pub owner: Option<EntityId>,    // Drone qui l'a tirée (None = joueur)
     pub size: f32,
     pub life: f32,      // Durée de vie avant disparition
 }
@@ -199,19 +417,20 @@ impl Bullet {
     }
 
     /// Vérifie si la balle est toujours active
-    pub fn is_alive(&self) -> bool {
-        self.life > 0.0
-            && self.pos.y > -50.0 
-            && self.pos.y < screen_height() + 50.0
-            && self.pos.x > -50.0 
-            && self.pos.x < screen_width() + 50.0
+    pub fn is_alive(&self, arena: &Arena) -> bool {
+        self.life > 0.0 && arena.contains(self.pos, 50.0)
+    }
+
+    /// Forme de collision (cercle dessiné)
+    pub fn collider(&self) -> Collider {
+        Collider::circle(self.pos, self.size)
     }
 }
 
 // -----------------------------------------------------------------------------
 // POWER-UP (BONUS)
 // -----------------------------------------------------------------------------
-#[derive(Clone)]
+#[derive(PartialEq, Eq, Clone, Copy, Debug, Serialize, Deserialize)]
 pub enum PowerUpType {
     Shield,     // Bouclier plein
     Life,       // Vie supplémentaire
@@ -219,8 +438,10 @@ pub enum PowerUpType {
     RapidFire,  // Tir rapide temporaire
 }
 
+#[derive(Clone, Serialize, Deserialize)]
 pub struct PowerUp {
     pub pos: Vec2,
+    pub prev_pos: Vec2,     // Position au tick précédent (interpolation du rendu)
     pub vel: Vec2,
     pub kind: PowerUpType,
     pub life: f32,
@@ -228,8 +449,8 @@ pub struct PowerUp {
 
 impl PowerUp {
     /// Crée un nouveau power-up de type aléatoire
-    pub fn new(pos: Vec2) -> Self {
-        let kind = match rand::gen_range(0, 4) {
+    pub fn new(pos: Vec2, rng: &mut Rng) -> Self {
+        let kind = match rng.gen_range(0, 4) {
             0 => PowerUpType::Shield,
             1 => PowerUpType::Life,
             2 => PowerUpType::SlowTime,
@@ -238,7 +459,8 @@ impl PowerUp {
 
         Self {
             pos,
-            vel: vec2(rand::gen_range(-30., 30.), rand::gen_range(-30., 30.)),
+            prev_pos: pos,
+            vel: vec2(rng.gen_range(-30., 30.), rng.gen_range(-30., 30.)),
             kind,
             life: 10.0,
         }
@@ -251,19 +473,30 @@ impl PowerUp {
         self.vel.y += 50.0 * dt;  // Gravité
     }
 
-    /// Vérifie si le power-up est toujours actif
-    pub fn is_alive(&self) -> bool {
-        self.life > 0.0 && self.pos.y < screen_height() + 50.0
+    /// Vérifie si le power-up est toujours actif (il tombe : seul le bas
+    /// de l'arène bornée le fait disparaître)
+    pub fn is_alive(&self, arena: &Arena) -> bool {
+        self.life > 0.0 && (arena.wraps() || self.pos.y < arena.size.y + 50.0)
+    }
+
+    /// Forme de collision (cercle inscrit dans l'hexagone dessiné)
+    pub fn collider(&self) -> Collider {
+        Collider::circle(self.pos, POWERUP_RADIUS)
     }
 
-    /// Applique l'effet du power-up
-    pub fn apply(&self, ship: &mut crate::ship::Ship, _weapons: &mut crate::weapons::WeaponSystem) {
+    /// Forme de collision de la copie la plus proche de `near`
+    pub fn collider_near(&self, arena: &Arena, near: Vec2) -> Collider {
+        Collider::circle(arena.image_near(self.pos, near), POWERUP_RADIUS)
+    }
+
+    /// Applique l'effet du power-up (vies plafonnées à `max_lives`)
+    pub fn apply(&self, ship: &mut crate::ship::Ship, balance: &ShipBalance) {
         match self.kind {
             PowerUpType::Shield => {
                 ship.shield_energy.time = ship.shield_energy.max;
             }
             PowerUpType::Life => {
-                ship.lives = (ship.lives + 1).min(5);
+                ship.lives = (ship.lives + 1).min(balance.max_lives);
             }
             PowerUpType::SlowTime => {
                 ship.slowmo_energy.time = ship.slowmo_energy.max;
@@ -284,3 +517,44 @@ impl PowerUp {
         }
     }
 }
+
+// =============================================================================
+// TESTS
+// =============================================================================
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn life_powerup_respects_balance_cap() {
+        let balance = ShipBalance { max_lives: 4, ..ShipBalance::default() };
+        let mut ship = crate::ship::Ship::new(Vec2::ZERO, &balance);
+        let mut powerup = PowerUp::new(Vec2::ZERO, &mut Rng::new(1));
+        powerup.kind = PowerUpType::Life;
+        ship.lives = 3;
+        powerup.apply(&mut ship, &balance);
+        powerup.apply(&mut ship, &balance);
+        assert_eq!(ship.lives, 4);
+    }
+
+    #[test]
+    fn split_conserves_momentum() {
+        let balance = AsteroidBalance::default();
+        for seed in 0..20 {
+            let mut rng = Rng::new(seed);
+            let mut parent = Asteroid::new(vec2(400., 300.), 1.0, &balance, &mut rng);
+            parent.size = AsteroidSize::Large;
+            parent.radius = 15.0;
+            parent.vel = vec2(40., -25.);
+            let fragments = parent.split(vec2(1., 0.3), &balance, &mut rng);
+            assert!((2..=3).contains(&fragments.len()));
+
+            let momentum: Vec2 = fragments.iter().map(|f| f.vel * f.mass()).sum();
+            let expected = parent.vel * parent.mass();
+            assert!(
+                momentum.distance(expected) < 1e-3 * expected.length(),
+                "graine {seed} : {momentum} au lieu de {expected}"
+            );
+        }
+    }
+}
@@ -1,98 +1,192 @@
 // =============================================================================
-// SYSTÈME D'ÉVÉNEMENTS
+// BUS D'ÉVÉNEMENTS DU GAMEPLAY
 // =============================================================================
 //
-// Ce fichier implémente un système de découplage entre la logique de jeu
-// et les effets visuels. Les événements sont émis par le gameplay et
-// consommés par le système d'effets.
+// Ce fichier découple la logique de jeu de ceux qui y réagissent.
+// Le gameplay (armes, collisions, vagues) émet des événements typés sur
+// l'unique bus du jeu (Game::events) ; à chaque frame, le bus les distribue
+// à tous ses abonnés :
+// - EffectSystem : particules et flashs
+// - RunStats : statistiques de la partie
+// - abonnés externes fournis par la boucle principale (audio...)
 //
-// Avantages :
-// - Réduction du couplage
-// - Code plus propre et modulaire
-// - Facilite l'ajout de nouveaux effets
+// Les abonnés ne modifient jamais la simulation : une partie rejouée
+// donne les mêmes événements, quels que soient les abonnés branchés.
 //
 // =============================================================================
 
 use macroquad::prelude::*;
-use std::collections::VecDeque;
-use crate::game::Game;
+use crate::arena::Arena;
+use crate::enemies::{EnemyKind, PowerUpType};
+use crate::slotmap::EntityId;
+use crate::weapons::WeaponKind;
 
 // -----------------------------------------------------------------------------
 // TYPES D'ÉVÉNEMENTS
 // -----------------------------------------------------------------------------
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum Event {
-    /// Explosion à une position
-    Explosion {
+    /// Nouvelle partie (ou partie reprise) dans cette arène
+    RunStarted {
+        arena: Arena,
+    },
+
+    /// La vue (caméra) s'est déplacée : nouveau centre dans le monde
+    ViewMoved {
+        center: Vec2,
+    },
+
+    /// Changement de vitesse du temps (slow-motion : facteur < 1)
+    TimeScaleChanged {
+        factor: f32,
+    },
+
+    /// Nouveau niveau de menace (0 calme à 1 danger maximal, voir
+    /// Game::threat_level), signalé quand il change sensiblement
+    ThreatChanged {
+        level: f32,
+    },
+
+    /// Tir d'une arme du joueur (position et direction du vaisseau)
+    WeaponFired {
+        weapon: WeaponKind,
+        pos: Vec2,
+        dir: Vec2,
+    },
+
+    /// Ennemi touché sans être détruit (ou avant de l'être)
+    EnemyDamaged {
+        kind: EnemyKind,
         pos: Vec2,
-        color: Color,
-        count: usize,
+        by_weapon: WeaponKind,
     },
-    
-    /// Spawn d'un power-up
-    PowerUpSpawn {
+
+    /// Ennemi détruit (`id` : son identifiant dans le monde)
+    EnemyKilled {
+        id: EntityId,
+        kind: EnemyKind,
         pos: Vec2,
+        by_weapon: WeaponKind,
     },
-    
-    /// Tir laser
-    LaserFired {
+
+    /// Le vaisseau perd une vie, à cause de l'ennemi `by` s'il est connu
+    /// (drone qui a tiré la balle, astéroïde ou drone percuté)
+    PlayerHit {
         pos: Vec2,
-        dir: Vec2,
+        lives_left: i32,
+        by: Option<EntityId>,
     },
-    
+
+    /// Un coup est absorbé par le bouclier
+    ShieldBlocked {
+        pos: Vec2,
+    },
+
+    /// Power-up ramassé par le vaisseau
+    PowerUpCollected {
+        kind: PowerUpType,
+        pos: Vec2,
+    },
+
+    /// Vague terminée (`wave` : numéro de la vague suivante)
+    WaveCompleted {
+        wave: i32,
+        bonus: i32,
+        pos: Vec2,
+    },
+
+    /// Un vaisseau-mère entre en scène (vague de boss)
+    BossArrived {
+        pos: Vec2,
+    },
+
+    /// Le noyau du vaisseau-mère passe à la phase `phase` (2 ou 3)
+    BossPhaseChanged {
+        phase: i32,
+        pos: Vec2,
+    },
+
     /// Partie terminée
     GameOver,
 }
 
 // -----------------------------------------------------------------------------
-// FILE D'ÉVÉNEMENTS
+// ABONNÉS
 // -----------------------------------------------------------------------------
 
-pub struct EventQueue {
-    queue: VecDeque<Event>,
+/// Système qui réagit aux événements du gameplay
+pub trait EventListener {
+    fn on_event(&mut self, event: &Event);
+}
+
+/// Aucun abonné externe (tests, outils sans fenêtre)
+impl EventListener for () {
+    fn on_event(&mut self, _event: &Event) {}
 }
 
-impl EventQueue {
-    /// Crée une nouvelle file d'événements vide
+/// Plusieurs abonnés externes, servis dans l'ordre
+impl EventListener for [&mut dyn EventListener] {
+    fn on_event(&mut self, event: &Event) {
+        for listener in self.iter_mut() {
+            listener.on_event(event);
+        }
+    }
+}
+
+// -----------------------------------------------------------------------------
+// BUS D'ÉVÉNEMENTS
+// -----------------------------------------------------------------------------
+
+pub struct EventBus {
+    pending: Vec<Event>,
+}
+
+impl EventBus {
+    /// Crée un bus sans événement en attente
     pub fn new() -> Self {
         Self {
-            queue: VecDeque::new(),
+            pending: Vec::new(),
         }
     }
 
-    /// Ajoute un événement à la file
-    pub fn push(&mut self, event: Event) {
-        self.queue.push_back(event);
+    /// Émet un événement (distribué au prochain dispatch)
+    pub fn emit(&mut self, event: Event) {
+        self.pending.push(event);
     }
 
-    /// Récupère et retire le prochain événement de la file
-    pub fn pop(&mut self) -> Option<Event> {
-        self.queue.pop_front()
+    /// Distribue les événements en attente à chaque abonné, dans l'ordre
+    /// d'émission, puis vide le bus
+    pub fn dispatch(&mut self, listeners: &mut [&mut dyn EventListener]) {
+        for event in self.pending.drain(..) {
+            for listener in listeners.iter_mut() {
+                listener.on_event(&event);
+            }
+        }
     }
 
-    /// Traite tous les événements en attente
-    pub fn process(&mut self, game: &mut Game) {
-        game.effects.process_events(self);
+    /// Événements en attente (pas encore distribués)
+    pub fn pending(&self) -> &[Event] {
+        &self.pending
     }
 
-    /// Vide la file d'événements
+    /// Abandonne les événements en attente
     pub fn clear(&mut self) {
-        self.queue.clear();
+        self.pending.clear();
     }
 
     /// Retourne le nombre d'événements en attente
     pub fn len(&self) -> usize {
-        self.queue.len()
+        self.pending.len()
     }
 
-    /// Vérifie si la file est vide
+    /// Vérifie si le bus est vide
     pub fn is_empty(&self) -> bool {
-        self.queue.is_empty()
+        self.pending.is_empty()
     }
 }
 
-impl Default for EventQueue {
+impl Default for EventBus {
     fn default() -> Self {
         Self::new()
     }
@@ -4,19 +4,37 @@
 //
 // Ce fichier contient le coeur du jeu avec :
 // - La structure Game qui agrège tous les états
-// - La gestion des vagues et du spawn
+// - La gestion des vagues et du spawn (autour de la vue, voir camera.rs),
+//   dont les vagues de boss (voir boss.rs)
 // - Le système de cooldown réutilisable
+// - La boucle à pas de temps fixe (indépendante du framerate)
 // - L'ordre d'exécution des systèmes
 //
 // =============================================================================
 
 use macroquad::prelude::*;
+use serde::{Deserialize, Serialize};
+use crate::consts::*;
+use crate::arena::Arena;
+use crate::camera::Camera;
+use crate::balance::BalanceConfig;
 use crate::ship::Ship;
 use crate::weapons::*;
 use crate::enemies::*;
+use crate::boss::{self, BossPart};
 use crate::effects::EffectSystem;
 use crate::collisions;
-use crate::events::EventQueue;
+use crate::damage::DamageContext;
+use crate::events::{Event, EventBus, EventListener};
+use crate::input::InputState;
+use crate::replay::Replay;
+use crate::scores::{MemoryScoreStore, ScoreStore};
+use crate::settings::Settings;
+use crate::stats::RunStats;
+use crate::rng::Rng;
+use crate::spatial::SpatialGrid;
+use crate::collider::Collider;
+use crate::slotmap::SlotMap;
 
 // -----------------------------------------------------------------------------
 // STRUCTURE PRINCIPALE - AGGRÉGATE DE TOUS LES ÉTATS
@@ -25,8 +43,13 @@ pub struct Game {
     // État de la partie
     pub state: GameState,
     pub score: i32,
-    pub high_score: i32,
+    pub scores: Box<dyn ScoreStore>,    // Classement des meilleures parties
+    pub settings: Settings,             // Préférences du joueur (touches)
     pub timer: f32,
+    pub arena: Arena,       // Aire de jeu : taille (indépendante de la fenêtre) et mode
+    pub seed: u64,          // Graine de la partie en cours
+    pub rng: Rng,           // Flux aléatoire du gameplay (spawns, drops...)
+    pub balance: BalanceConfig, // Réglages de gameplay (balance.ron)
     pub combo: i32,
     pub combo_timer: Cooldown,
 
@@ -34,14 +57,24 @@ pub struct Game {
     pub wave: i32,
     pub enemies_to_spawn: i32,
     pub enemies_killed: i32,
+    pub boss_spawned: bool,       // Vague de boss : vaisseau-mère déjà apparu
 
     // Sous-systèmes
     pub ship: Ship,
+    pub camera: Camera,           // Vue qui suit le vaisseau dans le monde
     pub world: World,
     pub weapons: WeaponSystem,
     pub effects: EffectSystem,
     pub shake: ScreenShake,
-    pub event_queue: EventQueue,  // File d'événements pour découplage
+    pub events: EventBus,         // Bus unique des événements du gameplay
+    pub stats: RunStats,          // Statistiques de la partie (abonné du bus)
+    pub clock: FixedTimestep,     // Accumulateur du pas de temps fixe
+    pub replay: Replay,           // Entrées enregistrées de la partie en cours
+    pub threat: f32,              // Dernier niveau de menace signalé (0 à 1)
+    pub show_hitboxes: bool,      // Debug : formes de collision dessinées
+
+    // Entrées de la frame précédente (détection des appuis)
+    pub(crate) last_input: InputState,
 }
 
 // -----------------------------------------------------------------------------
@@ -58,22 +91,59 @@ pub enum GameState {
 // -----------------------------------------------------------------------------
 // MONDE - COLLECTION DE TOUTES LES ENTITÉS DYNAMIQUES
 // -----------------------------------------------------------------------------
+// Chaque collection est une SlotMap (voir slotmap.rs) : parcourue et
+// indexée comme un Vec, avec en plus un EntityId stable par entité.
+#[derive(Clone, Serialize, Deserialize)]
 pub struct World {
-    pub asteroids: Vec<Asteroid>,
-    pub drones: Vec<Drone>,
-    pub bullets: Vec<Bullet>,
-    pub powerups: Vec<PowerUp>,
+    pub asteroids: SlotMap<Asteroid>,
+    pub drones: SlotMap<Drone>,
+    pub bullets: SlotMap<Bullet>,
+    pub powerups: SlotMap<PowerUp>,
+    #[serde(default)]
+    pub boss_parts: SlotMap<BossPart>,  // Vaisseau-mère : noyau et tourelles
+
+    // Broad-phase des collisions, reconstruite à chaque tick (non sauvegardée)
+    #[serde(skip)]
+    pub asteroid_grid: SpatialGrid,
+    #[serde(skip)]
+    pub drone_grid: SpatialGrid,
+    #[serde(skip)]
+    pub boss_grid: SpatialGrid,
 }
 
 impl World {
     pub fn new() -> Self {
         Self {
-            asteroids: Vec::new(),
-            drones: Vec::new(),
-            bullets: Vec::new(),
-            powerups: Vec::new(),
+            asteroids: SlotMap::new(),
+            drones: SlotMap::new(),
+            bullets: SlotMap::new(),
+            powerups: SlotMap::new(),
+            boss_parts: SlotMap::new(),
+            asteroid_grid: SpatialGrid::new(),
+            drone_grid: SpatialGrid::new(),
+            boss_grid: SpatialGrid::new(),
         }
     }
+
+    /// Range astéroïdes, drones et vaisseau-mère dans leurs grilles spatiales
+    /// À appeler après leur déplacement, avant les tests de collision :
+    /// les index restent valides jusqu'au nettoyage de fin de tick
+    pub fn rebuild_grids(&mut self, arena: &Arena) {
+        self.asteroid_grid.rebuild(arena, self.asteroids.iter().map(|a| bounds(&a.collider())));
+        self.drone_grid.rebuild(arena, self.drones.iter().map(|d| bounds(&d.collider())));
+        self.boss_grid.rebuild(arena, self.boss_parts.iter().map(|p| bounds(&p.collider())));
+    }
+}
+
+/// Cercle englobant d'une forme (entrée des grilles spatiales)
+fn bounds(collider: &Collider) -> (Vec2, f32) {
+    (collider.center(), collider.bounding_radius())
+}
+
+impl Default for World {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 // -----------------------------------------------------------------------------
@@ -82,6 +152,7 @@ impl World {
 pub struct ScreenShake {
     pub amount: f32,    // Intensité actuelle
     pub timer: f32,     // Durée restante
+    pub rng: Rng,       // Flux cosmétique propre au secouement
 }
 
 impl ScreenShake {
@@ -89,6 +160,7 @@ impl ScreenShake {
         Self {
             amount: 0.0,
             timer: 0.0,
+            rng: Rng::default(),
         }
     }
 
@@ -103,8 +175,8 @@ impl ScreenShake {
 
         if self.amount > 0.0 {
             vec2(
-                rand::gen_range(-self.amount, self.amount),
-                rand::gen_range(-self.amount, self.amount),
+                self.rng.gen_range(-self.amount, self.amount),
+                self.rng.gen_range(-self.amount, self.amount),
             )
         } else {
             Vec2::ZERO
@@ -117,10 +189,16 @@ impl ScreenShake {
     }
 }
 
+impl Default for ScreenShake {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 // -----------------------------------------------------------------------------
 // SYSTÈME DE COOLDOWN RÉUTILISABLE
 // -----------------------------------------------------------------------------
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, Serialize, Deserialize)]
 pub struct Cooldown {
     pub time: f32,  // Temps restant
     pub max: f32,   // Temps maximum
@@ -159,6 +237,14 @@ impl Cooldown {
         self.time = value.max(0.0);
     }
 
+    /// Change la valeur maximale (équilibrage) en bornant le temps restant
+    pub fn with_max(self, max: f32) -> Self {
+        Self {
+            time: self.time.min(max),
+            max,
+        }
+    }
+
     /// Retourne le pourcentage de temps restant (0.0 à 1.0)
     pub fn percent(&self) -> f32 {
         self.time / self.max
@@ -166,54 +252,192 @@ impl Cooldown {
 }
 
 // -----------------------------------------------------------------------------
-// IMPLÉMENTATION DU JEU
+// PAS DE TEMPS FIXE (ACCUMULATEUR)
 // -----------------------------------------------------------------------------
-impl Game {
-    /// Crée une nouvelle instance de jeu
+//
+// Le temps réel de chaque frame est accumulé puis consommé par tranches.
+// Un tick simule toujours SIM_DT secondes de jeu ; en slow-motion, un tick
+// coûte simplement plus de temps réel (SIM_DT / facteur). La physique et les
+// probabilités de spawn sont donc identiques à 60 Hz comme à 144 Hz.
+#[derive(Clone, Copy)]
+pub struct FixedTimestep {
+    pub accumulator: f32,   // Temps réel en attente (secondes)
+    pub step: f32,          // Coût en temps réel du dernier tick
+}
+
+impl FixedTimestep {
     pub fn new() -> Self {
         Self {
+            accumulator: 0.0,
+            step: SIM_DT,
+        }
+    }
+
+    /// Ajoute le temps réel écoulé (borné pour éviter la spirale de rattrapage)
+    pub fn accumulate(&mut self, frame_dt: f32) {
+        self.accumulator = (self.accumulator + frame_dt).min(MAX_FRAME_TIME);
+    }
+
+    /// Consomme un tick coûtant `step` secondes réelles si possible
+    pub fn consume(&mut self, step: f32) -> bool {
+        self.step = step;
+        if self.accumulator >= step {
+            self.accumulator -= step;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Fraction du prochain tick déjà écoulée (0.0 à 1.0), pour l'interpolation
+    pub fn alpha(&self) -> f32 {
+        (self.accumulator / self.step).clamp(0.0, 1.0)
+    }
+}
+
+impl Default for FixedTimestep {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// -----------------------------------------------------------------------------
+// IMPLÉMENTATION DU JEU
+// -----------------------------------------------------------------------------
+impl Game {
+    /// Crée une nouvelle instance de jeu pour une arène donnée
+    /// (aucun accès à la fenêtre : utilisable sans contexte graphique)
+    pub fn new(arena: Arena, seed: u64) -> Self {
+        let mut game = Self {
             state: GameState::Menu,
             score: 0,
-            high_score: 0,
+            scores: Box::new(MemoryScoreStore::new()),
+            settings: Settings::new(),
             timer: 0.0,
+            arena,
+            seed,
+            rng: Rng::new(seed),
+            balance: BalanceConfig::default(),
             combo: 0,
             combo_timer: Cooldown::new(2.0),
             wave: 1,
-            enemies_to_spawn: 5,
+            enemies_to_spawn: 0,
             enemies_killed: 0,
-            ship: Ship::new(),
+            boss_spawned: false,
+            ship: Ship::new(arena.center(), &BalanceConfig::default().ship),
+            camera: Camera::new(),
             world: World::new(),
-            weapons: WeaponSystem::new(),
+            weapons: WeaponSystem::default(),
             effects: EffectSystem::new(),
             shake: ScreenShake::new(),
-            event_queue: EventQueue::new(),
-        }
+            events: EventBus::new(),
+            stats: RunStats::new(),
+            clock: FixedTimestep::new(),
+            replay: Replay::new(seed, arena),
+            threat: 0.0,
+            show_hitboxes: false,
+            last_input: InputState::new(),
+        };
+        game.enemies_to_spawn = game.balance.waves.base_size;
+        game.seed_cosmetics();
+        game
     }
 
     /// Réinitialise complètement le jeu pour une nouvelle partie
-    pub fn reset(&mut self) {
+    /// La graine détermine tout l'aléatoire du gameplay de cette partie
+    pub fn reset(&mut self, seed: u64) {
+        self.seed = seed;
+        self.rng = Rng::new(seed);
         self.score = 0;
         self.timer = 0.0;
         self.combo = 0;
         self.combo_timer = Cooldown::new(2.0);
         self.wave = 1;
-        self.enemies_to_spawn = 5;
+        self.enemies_to_spawn = self.balance.waves.base_size;
         self.enemies_killed = 0;
+        self.boss_spawned = false;
         self.state = GameState::Playing;
         self.weapons.missiles.clear();
-        self.ship = Ship::new();
+        self.ship = Ship::new(self.arena.center(), &self.balance.ship);
+        self.camera = Camera::new();
         self.world.asteroids.clear();
         self.world.drones.clear();
         self.world.bullets.clear();
         self.world.powerups.clear();
-        self.weapons = WeaponSystem::new();
+        self.world.boss_parts.clear();
+        self.weapons = WeaponSystem::new(&self.balance.weapons);
         self.effects.clear();
         self.shake = ScreenShake::new();
-        self.event_queue = EventQueue::new();
+        self.events.clear();
+        self.events.emit(Event::RunStarted { arena: self.arena });
+        self.stats = RunStats::new();
+        self.clock = FixedTimestep::new();
+        self.replay = Replay::new(seed, self.arena);
+        self.threat = 0.0;
+        self.last_input = InputState::new();
+        self.seed_cosmetics();
+    }
+
+    /// Remplace l'équilibrage (rechargement à chaud de balance.ron)
+    /// La partie en cours continue avec les nouvelles valeurs
+    pub fn set_balance(&mut self, balance: BalanceConfig) {
+        self.balance = balance;
+        self.ship.rebalance(&self.balance.ship);
+        self.weapons.rebalance(&self.balance.weapons);
+    }
+
+    /// Coût en temps réel d'un tick (plus long en slow-motion)
+    pub fn tick_cost(&self) -> f32 {
+        SIM_DT / self.ship.get_slow_time_factor(&self.balance.ship)
+    }
+
+    /// Centre de la vue (caméra qui suit le vaisseau)
+    pub fn view_center(&self) -> Vec2 {
+        self.camera.center(self.ship.pos, &self.arena)
+    }
+
+    /// La vague en cours est-elle une vague de boss ?
+    pub fn is_boss_wave(&self) -> bool {
+        self.wave % self.balance.boss.every == 0
+    }
+
+    /// Meilleur score du classement
+    pub fn high_score(&self) -> i32 {
+        self.scores.best()
+    }
+
+    /// Initialise les flux cosmétiques (particules, shake) à partir de la graine
+    /// Ils sont séparés du gameplay : un effet en plus ne change pas la partie
+    fn seed_cosmetics(&mut self) {
+        self.effects.rng = Rng::cosmetic(self.seed);
+        self.shake.rng = Rng::cosmetic(self.seed.rotate_left(32));
+    }
+
+    /// Avance la simulation du temps réel écoulé depuis la dernière frame
+    /// Exécute zéro, un ou plusieurs ticks fixes selon l'accumulateur
+    pub fn advance(&mut self, frame_dt: f32, input: &InputState) {
+        self.clock.accumulate(frame_dt);
+        while self.state == GameState::Playing
+            && self.clock.consume(self.tick_cost())
+        {
+            self.update(SIM_DT, input);
+        }
     }
 
-    /// Met à jour toute la logique du jeu pour une frame
-    pub fn update(&mut self, dt: f32) {
+    /// Distribue les événements de la frame aux abonnés : effets visuels,
+    /// statistiques, puis les abonnés externes (audio...)
+    pub fn dispatch_events(&mut self, external: &mut dyn EventListener) {
+        self.events.dispatch(&mut [&mut self.effects, &mut self.stats, external]);
+    }
+
+    /// Exécute un tick de simulation de durée `dt` (SIM_DT dans le jeu)
+    /// Les entrées sont fournies par l'appelant (clavier, replay, test...)
+    pub fn update(&mut self, dt: f32, input: &InputState) {
+        let pressed = input.pressed_since(&self.last_input);
+        self.last_input = *input;
+        self.replay.record(input);
+        self.snapshot_positions();
+
         self.timer += dt;
         self.combo_timer.tick(dt);
 
@@ -222,10 +446,29 @@ impl Game {
         }
 
         // Mise à jour du vaisseau (on ignore la direction retournée)
-        let _ship_dir = self.ship.update(dt);
+        let time_scale = self.ship.get_slow_time_factor(&self.balance.ship);
+        let _ship_dir = self.ship.update(dt, input, &self.arena, &self.balance.ship);
+        let new_time_scale = self.ship.get_slow_time_factor(&self.balance.ship);
+        if new_time_scale != time_scale {
+            self.events.emit(Event::TimeScaleChanged { factor: new_time_scale });
+        }
+        self.camera.update(self.ship.vel);
+        let view_center = self.view_center();
+        if view_center != self.camera.render_center(self.ship.prev_pos, &self.arena, 0.0) {
+            self.events.emit(Event::ViewMoved { center: view_center });
+        }
 
         // Mise à jour des armes
-        self.weapons.update(dt, &mut self.ship, &mut self.world, &mut self.effects, &mut self.event_queue);
+        self.weapons.update(
+            dt,
+            input,
+            &pressed,
+            &mut self.ship,
+            &mut self.world,
+            &self.arena,
+            &mut self.events,
+            &self.balance.weapons,
+        );
 
         // Mise à jour des entités
         self.update_entities(dt);
@@ -244,56 +487,140 @@ impl Game {
 
         // Mise à jour du screen shake
         self.shake.update(dt);
+
+        // Niveau de menace (signalé seulement s'il a assez changé)
+        let threat = self.threat_level();
+        if (threat - self.threat).abs() >= THREAT_STEP
+            || (threat != self.threat && (threat == 0.0 || threat == 1.0))
+        {
+            self.threat = threat;
+            self.events.emit(Event::ThreatChanged { level: threat });
+        }
+    }
+
+    /// Niveau de menace actuel, de 0 (calme) à 1 (danger maximal)
+    /// Combine le nombre de drones (au maximum face à un boss), les balles ennemies proches du vaisseau,
+    /// les vies restantes et l'avancée dans les vagues
+    pub fn threat_level(&self) -> f32 {
+        let drones = if boss::core(&self.world.boss_parts).is_some() {
+            1.0
+        } else {
+            self.world.drones.len() as f32 / THREAT_DRONES
+        };
+        let bullets = self
+            .world
+            .bullets
+            .iter()
+            .filter(|b| b.enemy && self.arena.distance(b.pos, self.ship.pos) < THREAT_BULLET_RANGE)
+            .count() as f32
+            / THREAT_BULLETS;
+        let lives_lost = (self.balance.ship.lives - self.ship.lives) as f32
+            / (self.balance.ship.lives - 1).max(1) as f32;
+        let waves = (self.wave - 1) as f32 / THREAT_WAVES;
+
+        (drones.min(1.0) * 0.35
+            + bullets.min(1.0) * 0.3
+            + lives_lost.clamp(0.0, 1.0) * 0.2
+            + waves.clamp(0.0, 1.0) * 0.15)
+            .clamp(0.0, 1.0)
+    }
+
+    /// Mémorise les positions avant le tick (interpolées au rendu)
+    fn snapshot_positions(&mut self) {
+        self.ship.prev_pos = self.ship.pos;
+        self.ship.prev_rot = self.ship.rot;
+        for a in self.world.asteroids.iter_mut() {
+            a.prev_pos = a.pos;
+        }
+        for d in self.world.drones.iter_mut() {
+            d.prev_pos = d.pos;
+        }
+        for b in self.world.bullets.iter_mut() {
+            b.prev_pos = b.pos;
+        }
+        for p in self.world.powerups.iter_mut() {
+            p.prev_pos = p.pos;
+        }
+        for p in self.world.boss_parts.iter_mut() {
+            p.prev_pos = p.pos;
+        }
+        for m in self.weapons.missiles.iter_mut() {
+            m.prev_pos = m.pos;
+        }
     }
 
     /// Met à jour toutes les entités dynamiques
+    /// En arène torique, chaque entité déplacée est ramenée dans l'arène
     fn update_entities(&mut self, dt: f32) {
+        let arena = self.arena;
+
         // Astéroïdes
         for a in self.world.asteroids.iter_mut() {
             a.update(dt);
+            arena.wrap_motion(&mut a.pos, &mut a.prev_pos);
         }
 
-        // Drones
-        for d in self.world.drones.iter_mut() {
-            d.update(dt, &self.ship.pos);
+        // Drones (ils poursuivent et visent la copie du vaisseau la plus proche)
+        for (id, d) in self.world.drones.iter_mut_with_ids() {
+            let ship_pos = arena.image_near(self.ship.pos, d.pos);
+            d.update(dt, &ship_pos, &self.balance.drones);
+            arena.wrap_motion(&mut d.pos, &mut d.prev_pos);
 
             // Tir des drones
-            if let Some(bullet) = d.shoot(dt, &self.ship) {
+            let ship_pos = arena.image_near(self.ship.pos, d.pos);
+            if let Some(bullet) = d.shoot(id, dt, &ship_pos, &self.ship.vel, &self.balance.drones) {
                 self.world.bullets.push(bullet);
             }
         }
 
-        // Missiles téléguidés
-        self.weapons.update_missiles(
-            dt, 
-            &mut self.world, 
-            &mut self.effects, 
-            &mut self.score, 
-            &mut self.combo, 
-            &mut self.combo_timer, 
-            &mut self.enemies_killed, 
-            &mut self.event_queue
+        // Vaisseau-mère (noyau puis tourelles)
+        boss::update_boss(
+            &mut self.world.boss_parts,
+            &mut self.world.bullets,
+            dt,
+            self.ship.pos,
+            &arena,
+            &mut self.events,
+            &self.balance,
         );
 
+        // Grilles spatiales (positions définitives du tick)
+        self.world.rebuild_grids(&arena);
+
+        // Dégâts des armes du joueur (score, événements, power-ups)
+        let mut ctx = DamageContext {
+            score: &mut self.score,
+            combo: &mut self.combo,
+            combo_timer: &mut self.combo_timer,
+            enemies_killed: &mut self.enemies_killed,
+            shake: &mut self.shake,
+            state: &mut self.state,
+            rng: &mut self.rng,
+            events: &mut self.events,
+            balance: &self.balance,
+            arena: &self.arena,
+            drops: Vec::new(),
+            fragments: Vec::new(),
+        };
+
+        // Missiles téléguidés
+        self.weapons.update_missiles(dt, &mut self.world, &arena, &mut ctx);
+
         // Laser perçant
-        self.weapons.update_laser(
-            dt, 
-            &self.ship, 
-            &mut self.world, 
-            &mut self.effects, 
-            &mut self.score, 
-            &mut self.enemies_killed, 
-            &mut self.event_queue
-        );
+        self.weapons.update_laser(dt, &self.ship, &mut self.world, &arena, &mut ctx);
+
+        ctx.spawn_into(&mut self.world);
 
         // Balles
         for b in self.world.bullets.iter_mut() {
             b.update(dt);
+            arena.wrap_motion(&mut b.pos, &mut b.prev_pos);
         }
 
         // Power-ups
         for p in self.world.powerups.iter_mut() {
             p.update(dt);
+            arena.wrap_motion(&mut p.pos, &mut p.prev_pos);
         }
     }
 
@@ -301,133 +628,336 @@ impl Game {
     fn handle_collisions(&mut self) {
         // On extrait les valeurs nécessaires AVANT les appels pour éviter
         // les problèmes de borrow checker (emprunts simultanés)
-        let ship_pos = self.ship.pos;
-        let ship_invincible = self.ship.invincible_timer.time;
-        let ship_shield_active = self.ship.shield_active;
-
-        // Collisions balles alliées vs ennemis
-        collisions::handle_friendly_bullet_collisions(
-            &mut self.world.bullets,
+        let was_over = self.state == GameState::GameOver;
+        let mut ctx = DamageContext {
+            score: &mut self.score,
+            combo: &mut self.combo,
+            combo_timer: &mut self.combo_timer,
+            enemies_killed: &mut self.enemies_killed,
+            shake: &mut self.shake,
+            state: &mut self.state,
+            rng: &mut self.rng,
+            events: &mut self.events,
+            balance: &self.balance,
+            arena: &self.arena,
+            drops: Vec::new(),
+            fragments: Vec::new(),
+        };
+
+        // Chocs entre ennemis
+        collisions::handle_enemy_collisions(
             &mut self.world.asteroids,
             &mut self.world.drones,
-            &mut self.score,
-            &mut self.combo,
-            &mut self.combo_timer,
-            &mut self.enemies_killed,
-            &mut self.effects,
-            &mut self.world.powerups,
-            &mut self.event_queue,
+            &mut self.world.boss_parts,
+            &self.world.asteroid_grid,
+            &self.arena,
+            &mut ctx,
         );
 
+        // Collisions balles alliées vs ennemis
+        collisions::handle_friendly_bullet_collisions(&mut self.world, &self.arena, &mut ctx);
+
         // Collisions balles ennemies vs vaisseau
-        collisions::handle_enemy_bullet_collisions(
-            &mut self.world.bullets,
-            &ship_pos,
-            ship_invincible,
-            ship_shield_active,
-            &mut self.ship.lives,
-            &mut self.ship.invincible_timer,
-            &mut self.shake,
-            &mut self.effects,
-            &mut self.state,
-            &mut self.event_queue,
-        );
+        collisions::handle_enemy_bullet_collisions(&mut self.world.bullets, &mut self.ship, &self.arena, &mut ctx);
 
         // Collisions vaisseau vs astéroïdes
         collisions::handle_ship_asteroid_collisions(
             &mut self.world.asteroids,
-            &ship_pos,
-            ship_invincible,
-            ship_shield_active,
-            &mut self.ship.lives,
-            &mut self.ship.invincible_timer,
-            &mut self.shake,
-            &mut self.score,
-            &mut self.effects,
-            &mut self.state,
-            &mut self.event_queue,
+            &self.world.asteroid_grid,
+            &mut self.ship,
+            &self.arena,
+            &mut ctx,
         );
 
         // Collisions vaisseau vs drones
         collisions::handle_ship_drone_collisions(
             &mut self.world.drones,
-            &mut self.world.asteroids,
-            &ship_pos,
-            ship_invincible,
-            ship_shield_active,
-            &mut self.ship.lives,
-            &mut self.ship.invincible_timer,
-            &mut self.shake,
-            &mut self.effects,
-            &mut self.state,
-            &mut self.score,
-            &mut self.enemies_killed,
-            &mut self.event_queue,
+            &self.world.drone_grid,
+            &mut self.ship,
+            &self.arena,
+            &mut ctx,
+        );
+
+        // Collisions vaisseau vs vaisseau-mère
+        collisions::handle_ship_boss_collisions(
+            &mut self.world.boss_parts,
+            &self.world.boss_grid,
+            &mut self.ship,
+            &self.arena,
+            &mut ctx,
         );
 
+        // Noyau détruit : les tourelles restantes sautent avec lui
+        boss::scuttle(&mut self.world.boss_parts, &mut ctx);
+
+        // Power-ups et fragments des ennemis détruits
+        ctx.spawn_into(&mut self.world);
+
         // Collisions power-ups vs vaisseau
         collisions::handle_powerup_collisions(
             &mut self.world.powerups,
-            &self.ship.pos,
-            &mut self.ship.shield_energy,
-            &mut self.ship.lives,
-            &mut self.ship.slowmo_energy,
-            &mut self.ship.rapid_fire_timer,
+            &mut self.ship,
+            &self.arena,
             &mut self.shake,
-            &mut self.effects,
-            &mut self.event_queue,
+            &mut self.events,
+            &self.balance.ship,
         );
+
+        // Fin de partie (un seul événement, même si plusieurs coups la causent)
+        if !was_over && self.state == GameState::GameOver {
+            self.events.emit(Event::GameOver);
+        }
     }
 
     /// Gère le spawn des ennemis et les changements de vague
     fn handle_waves(&mut self, _dt: f32) {
-        // Vérifier si la vague est terminée
-        if self.enemies_killed >= self.enemies_to_spawn {
+        let waves = self.balance.waves.clone();   // Copie : les spawns empruntent self
+
+        // Vérifier si la vague est terminée (vague de boss : noyau détruit)
+        let completed = if self.is_boss_wave() {
+            self.boss_spawned && boss::core(&self.world.boss_parts).is_none()
+        } else {
+            self.enemies_killed >= self.enemies_to_spawn
+        };
+        if completed {
             self.wave += 1;
             self.enemies_killed = 0;
-            self.enemies_to_spawn = 5 + self.wave * 3;
-            self.score += self.wave * 100;
+            self.boss_spawned = false;
+            self.enemies_to_spawn = if self.is_boss_wave() {
+                1
+            } else {
+                waves.base_size + self.wave * waves.size_per_wave
+            };
+            let bonus = self.wave * waves.completion_bonus;
+            self.score += bonus;
             self.shake.trigger(0.5);
 
-            // Effet visuel de fin de vague
-            for _ in 0..50 {
-                self.effects.particles.push(crate::effects::create_explosion_particle(
-                        vec2(screen_width() / 2., screen_height() / 2.),
-                        GOLD,
-                ));
+            self.events.emit(Event::WaveCompleted {
+                wave: self.wave,
+                bonus,
+                pos: self.view_center(),
+            });
+        }
+
+        // Vague de boss : le vaisseau-mère remplace les spawns aléatoires
+        if self.is_boss_wave() {
+            if !self.boss_spawned {
+                self.spawn_boss();
             }
+            return;
         }
 
         // Difficulté progressive
-        let time_difficulty = 1.5;
-        let wave_difficulty = 1.0 + (self.wave as f32 * 0.5);
+        let time_difficulty = waves.base_difficulty;
+        let wave_difficulty = 1.0 + (self.wave as f32 * waves.difficulty_per_wave);
         let total_difficulty = time_difficulty * wave_difficulty;
 
-        // Spawn aléatoire d'astéroïdes
-        if rand::gen_range(0, (80.0 / total_difficulty) as i32) == 0 {
-            self.world.asteroids.push(Asteroid::new(
-                    vec2(rand::gen_range(0., screen_width()), -50.),
+        // Spawn aléatoire d'astéroïdes autour de la vue, lancés vers elle
+        // (limité en arène torique : ils ne sortent plus de l'arène)
+        if self.rng.gen_range(0, (waves.asteroid_spawn_rate / total_difficulty) as i32) == 0
+            && (!self.arena.wraps() || self.world.asteroids.len() < waves.asteroid_cap as usize) {
+            let pos = self.spawn_around_view(50.);
+            let mut asteroid = Asteroid::new(
+                    pos,
                     total_difficulty,
-            ));
+                    &self.balance.asteroids,
+                    &mut self.rng,
+            );
+            let spread = self.rng.gen_range(-ASTEROID_SPAWN_SPREAD, ASTEROID_SPAWN_SPREAD);
+            let aim = self.arena.direction(pos, self.view_center());
+            asteroid.vel = Vec2::from_angle(spread).rotate(aim) * asteroid.vel.length();
+            self.world.asteroids.push(asteroid);
         }
 
         // Spawn aléatoire de drones (limité par la vague)
-        if rand::gen_range(0, (200.0 / total_difficulty) as i32) == 0 
-            && self.world.drones.len() < 5 + self.wave as usize {
+        if self.rng.gen_range(0, (waves.drone_spawn_rate / total_difficulty) as i32) == 0 
+            && self.world.drones.len() < (waves.drone_cap + self.wave) as usize {
+                let pos = self.spawn_above_view(20.);
                 self.world.drones.push(Drone::new(
-                        vec2(rand::gen_range(0., screen_width()), -20.),
+                        pos,
                         total_difficulty,
                         self.wave,
+                        &self.balance.drones,
+                        &mut self.rng,
                 ));
         }
     }
 
+    /// Fait entrer le vaisseau-mère au-dessus de la vue, plus résistant à
+    /// chaque nouveau boss
+    fn spawn_boss(&mut self) {
+        let pos = self.spawn_above_view(BOSS_CORE_RADIUS * 2.);
+        let rank = self.wave / self.balance.boss.every - 1;
+        for part in BossPart::mothership(pos, rank, &self.balance.boss) {
+            self.world.boss_parts.push(part);
+        }
+        self.boss_spawned = true;
+        self.events.emit(Event::BossArrived { pos });
+    }
+
+    /// Point de spawn à `margin` px hors de la vue, sur tout son pourtour
+    fn spawn_around_view(&mut self, margin: f32) -> Vec2 {
+        let view = Camera::view_rect(self.view_center(), &self.arena);
+        let (w, h) = (view.w + 2. * margin, view.h + 2. * margin);
+        let t = self.rng.gen_range(0., 2. * (w + h));
+        let offset = if t < w {
+            vec2(t, 0.)
+        } else if t < w + h {
+            vec2(w, t - w)
+        } else if t < 2. * w + h {
+            vec2(t - w - h, h)
+        } else {
+            vec2(0., t - 2. * w - h)
+        };
+        self.place_spawn(view.point() - Vec2::splat(margin) + offset, margin)
+    }
+
+    /// Point de spawn à `margin` px au-dessus de la vue (les drones
+    /// descendent vers le joueur)
+    fn spawn_above_view(&mut self, margin: f32) -> Vec2 {
+        let view = Camera::view_rect(self.view_center(), &self.arena);
+        let pos = vec2(view.x + self.rng.gen_range(0., view.w), view.y - margin);
+        self.place_spawn(pos, margin)
+    }
+
+    /// Ramène un point de spawn dans l'arène torique, ou à `margin` px au
+    /// plus de ses bords en arène bornée
+    fn place_spawn(&self, pos: Vec2, margin: f32) -> Vec2 {
+        if self.arena.wraps() {
+            self.arena.wrap(pos)
+        } else {
+            pos.clamp(Vec2::splat(-margin), self.arena.size + margin)
+        }
+    }
+
     /// Nettoie les entités mortes ou hors écran
     fn cleanup(&mut self) {
-        self.world.bullets.retain(|b| b.is_alive());
-        self.world.drones.retain(|d| d.is_alive());
-        self.world.asteroids.retain(|a| a.is_alive());
-        self.world.powerups.retain(|p| p.is_alive());
-        self.weapons.missiles.retain(|m| m.is_alive());
+        let arena = self.arena;
+        self.world.bullets.retain(|b| b.is_alive(&arena));
+        self.world.drones.retain(|d| d.is_alive(&arena));
+        self.world.asteroids.retain(|a| a.is_alive(&arena));
+        self.world.powerups.retain(|p| p.is_alive(&arena));
+        self.world.boss_parts.retain(|p| p.is_alive());
+        self.weapons.missiles.retain(|m| m.is_alive(&arena));
+    }
+}
+
+// =============================================================================
+// TESTS (SIMULATION SANS FENÊTRE)
+// =============================================================================
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::arena::ArenaMode;
+
+    /// Partie lancée dans une arène d'un écran, sans fenêtre ni GPU
+    fn headless(mode: ArenaMode, seed: u64) -> Game {
+        let mut game = Game::new(Arena::new(vec2(800., 600.), mode), seed);
+        game.reset(seed);
+        game
+    }
+
+    #[test]
+    fn update_runs_without_window() {
+        let mut game = headless(ArenaMode::Bounded, 1);
+        let start = game.ship.pos;
+        let input = InputState { thrust: true, ..InputState::new() };
+        for _ in 0..30 {
+            game.update(SIM_DT, &input);
+        }
+        assert!(game.ship.pos.distance(start) > 1.0, "le vaisseau n'a pas bougé");
+    }
+
+    #[test]
+    fn bounded_arena_stops_the_ship_at_the_edge() {
+        let mut game = headless(ArenaMode::Bounded, 1);
+        game.ship.pos = vec2(790., 300.);
+        game.ship.rot = 0.0;
+        let input = InputState { thrust: true, ..InputState::new() };
+        for _ in 0..30 {
+            game.update(SIM_DT, &input);
+            assert!(game.ship.pos.x <= game.arena.size.x, "le vaisseau est sorti de l'arène");
+        }
+        assert_eq!(game.ship.pos.x, game.arena.size.x);
+        assert_eq!(game.ship.vel.x, 0.0);
+    }
+
+    /// Le laser tiré vers le bord droit touche-t-il un drone placé juste
+    /// après le bord gauche ?
+    fn laser_hits_across_edge(mode: ArenaMode) -> bool {
+        let mut game = headless(mode, 5);
+        game.ship.pos = vec2(700., 300.);
+        game.ship.rot = 0.0;
+        let mut rng = Rng::new(1);
+        game.world.drones.push(Drone::new(vec2(60., 300.), 1.0, 1, &game.balance.drones, &mut rng));
+        game.update(SIM_DT, &InputState { laser: true, ..InputState::new() });
+        game.events.pending().iter().any(|e| {
+            matches!(
+                e,
+                Event::EnemyDamaged { by_weapon: WeaponKind::Laser, .. }
+                    | Event::EnemyKilled { by_weapon: WeaponKind::Laser, .. }
+            )
+        })
+    }
+
+    #[test]
+    fn laser_crosses_toroidal_edges() {
+        assert!(laser_hits_across_edge(ArenaMode::Toroidal));
+        assert!(!laser_hits_across_edge(ArenaMode::Bounded));
+    }
+
+    /// Entrées scriptées : un motif différent toutes les 15 ticks
+    fn scripted_input(tick: usize) -> InputState {
+        let step = tick / 15;
+        InputState {
+            thrust: step % 3 != 2,
+            turn_left: step % 5 == 1,
+            turn_right: step % 7 == 2,
+            shoot: step % 4 != 3,
+            shield: step % 11 == 5,
+            missile: step % 6 == 4,
+            laser: step % 13 == 8,
+            ..InputState::new()
+        }
+    }
+
+    /// Positions de toutes les entités du monde
+    fn positions(world: &World) -> Vec<Vec2> {
+        world
+            .asteroids
+            .iter()
+            .map(|a| a.pos)
+            .chain(world.drones.iter().map(|d| d.pos))
+            .chain(world.bullets.iter().map(|b| b.pos))
+            .chain(world.powerups.iter().map(|p| p.pos))
+            .chain(world.boss_parts.iter().map(|p| p.pos))
+            .collect()
+    }
+
+    #[test]
+    fn same_seed_and_inputs_give_same_world() {
+        for mode in [ArenaMode::Bounded, ArenaMode::Toroidal] {
+            let mut a = headless(mode, 42);
+            let mut b = headless(mode, 42);
+            for tick in 0..3000 {
+                let input = scripted_input(tick);
+                a.update(SIM_DT, &input);
+                b.update(SIM_DT, &input);
+                a.events.clear();
+                b.events.clear();
+                if a.state != GameState::Playing {
+                    break;
+                }
+            }
+            assert_eq!(a.score, b.score, "{mode:?}");
+            assert_eq!(a.wave, b.wave, "{mode:?}");
+            assert_eq!(a.ship.pos, b.ship.pos, "{mode:?}");
+            assert_eq!(a.ship.lives, b.ship.lives, "{mode:?}");
+            assert_eq!(a.world.asteroids.len(), b.world.asteroids.len(), "{mode:?}");
+            assert_eq!(a.world.drones.len(), b.world.drones.len(), "{mode:?}");
+            assert_eq!(a.world.bullets.len(), b.world.bullets.len(), "{mode:?}");
+            assert_eq!(a.world.powerups.len(), b.world.powerups.len(), "{mode:?}");
+            assert_eq!(positions(&a.world), positions(&b.world), "{mode:?}");
+        }
     }
 }
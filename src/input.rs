@@ -0,0 +1,162 @@
+// =============================================================================
+// ENTRÉES DU JOUEUR
+// =============================================================================
+//
+// Ce fichier découple la simulation du clavier :
+// - InputState : état des actions du joueur pour une frame
+// - Lecture du clavier selon les touches configurées (KeyBindings)
+// - Visée à la souris (schéma twin-stick) : angle quantifié sur 16 bits
+// - Encodage compact en champ de bits (replays)
+//
+// La simulation (Game::update) ne lit jamais le clavier directement,
+// ce qui permet de la piloter sans fenêtre (tests, outils, replays).
+//
+// =============================================================================
+
+use macroquad::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::f32::consts::TAU;
+use crate::bindings::{Action, KeyBindings};
+
+// -----------------------------------------------------------------------------
+// BITS DES ACTIONS (FORMAT DES REPLAYS - NE PAS RÉORDONNER)
+// -----------------------------------------------------------------------------
+const BIT_THRUST: u16 = 1 << 0;
+const BIT_BRAKE: u16 = 1 << 1;
+const BIT_TURN_LEFT: u16 = 1 << 2;
+const BIT_TURN_RIGHT: u16 = 1 << 3;
+const BIT_SHOOT: u16 = 1 << 4;
+const BIT_SHIELD: u16 = 1 << 5;
+const BIT_SLOWMO: u16 = 1 << 6;
+const BIT_MISSILE: u16 = 1 << 7;
+const BIT_LASER: u16 = 1 << 8;
+const BIT_BOOST: u16 = 1 << 9;
+const BIT_AIM: u16 = 1 << 10;       // Angle de visée présent (16 bits de poids fort)
+
+/// Nombre de pas de l'angle de visée quantifié (un tour complet)
+const AIM_STEPS: f32 = 65536.0;
+
+// -----------------------------------------------------------------------------
+// ÉTAT DES ACTIONS (TOUCHES MAINTENUES)
+// -----------------------------------------------------------------------------
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct InputState {
+    pub thrust: bool,       // Avancer
+    pub brake: bool,        // Reculer
+    pub turn_left: bool,    // Tourner à gauche
+    pub turn_right: bool,   // Tourner à droite
+    pub shoot: bool,        // Tir normal
+    pub shield: bool,       // Bouclier
+    pub slowmo: bool,       // Slow-motion
+    pub missile: bool,      // Missile téléguidé
+    pub laser: bool,        // Laser perçant
+    pub boost: bool,        // Recul boosté
+    #[serde(default)]
+    pub aim: Option<u16>,   // Visée souris (angle quantifié), None au clavier seul
+}
+
+impl InputState {
+    /// Crée un état sans aucune action (aucune touche enfoncée)
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Lit l'état courant du clavier (nécessite une fenêtre macroquad)
+    pub fn from_keyboard(bindings: &KeyBindings) -> Self {
+        Self {
+            thrust: bindings.down(Action::Thrust),
+            brake: bindings.down(Action::Brake),
+            turn_left: bindings.down(Action::TurnLeft),
+            turn_right: bindings.down(Action::TurnRight),
+            shoot: bindings.down(Action::Shoot),
+            shield: bindings.down(Action::Shield),
+            slowmo: bindings.down(Action::Slowmo),
+            missile: bindings.down(Action::Missile),
+            laser: bindings.down(Action::Laser),
+            boost: bindings.down(Action::Boost),
+            aim: None,
+        }
+    }
+
+    /// Ajoute la visée à la souris (schéma twin-stick)
+    /// Le vaisseau vise le curseur ; clic gauche = tir, clic droit = missile
+    pub fn with_mouse(mut self, ship_screen_pos: Vec2) -> Self {
+        let to_cursor = Vec2::from(mouse_position()) - ship_screen_pos;
+        self.set_aim(to_cursor.y.atan2(to_cursor.x));
+        self.shoot |= is_mouse_button_down(MouseButton::Left);
+        self.missile |= is_mouse_button_down(MouseButton::Right);
+        self
+    }
+
+    /// Fixe l'angle de visée (radians, quantifié sur 16 bits)
+    pub fn set_aim(&mut self, angle: f32) {
+        let steps = (angle.rem_euclid(TAU) / TAU * AIM_STEPS).round() as u32;
+        self.aim = Some(steps as u16);  // 2π revient à 0
+    }
+
+    /// Angle de visée en radians (None si le joueur ne vise pas à la souris)
+    pub fn aim_angle(&self) -> Option<f32> {
+        self.aim.map(|steps| steps as f32 / AIM_STEPS * TAU)
+    }
+
+    /// Encode l'état en champ de bits (un u32 par tick dans les replays) :
+    /// actions sur les 16 bits de poids faible, visée sur les 16 autres
+    pub fn to_bits(&self) -> u32 {
+        let flags = [
+            (self.thrust, BIT_THRUST),
+            (self.brake, BIT_BRAKE),
+            (self.turn_left, BIT_TURN_LEFT),
+            (self.turn_right, BIT_TURN_RIGHT),
+            (self.shoot, BIT_SHOOT),
+            (self.shield, BIT_SHIELD),
+            (self.slowmo, BIT_SLOWMO),
+            (self.missile, BIT_MISSILE),
+            (self.laser, BIT_LASER),
+            (self.boost, BIT_BOOST),
+            (self.aim.is_some(), BIT_AIM),
+        ];
+        let actions = flags
+            .iter()
+            .filter(|(down, _)| *down)
+            .fold(0, |bits, (_, bit)| bits | bit);
+        ((self.aim.unwrap_or(0) as u32) << 16) | actions as u32
+    }
+
+    /// Décode un champ de bits produit par to_bits
+    /// (les replays au format 1 ne contiennent que les 16 bits d'actions)
+    pub fn from_bits(packed: u32) -> Self {
+        let bits = packed as u16;
+        Self {
+            thrust: bits & BIT_THRUST != 0,
+            brake: bits & BIT_BRAKE != 0,
+            turn_left: bits & BIT_TURN_LEFT != 0,
+            turn_right: bits & BIT_TURN_RIGHT != 0,
+            shoot: bits & BIT_SHOOT != 0,
+            shield: bits & BIT_SHIELD != 0,
+            slowmo: bits & BIT_SLOWMO != 0,
+            missile: bits & BIT_MISSILE != 0,
+            laser: bits & BIT_LASER != 0,
+            boost: bits & BIT_BOOST != 0,
+            aim: (bits & BIT_AIM != 0).then_some((packed >> 16) as u16),
+        }
+    }
+
+    /// Retourne les actions qui viennent d'être enfoncées
+    /// (actives maintenant mais pas dans l'état précédent)
+    /// La visée n'est pas une touche : elle est reprise telle quelle
+    pub fn pressed_since(&self, previous: &InputState) -> InputState {
+        InputState {
+            thrust: self.thrust && !previous.thrust,
+            brake: self.brake && !previous.brake,
+            turn_left: self.turn_left && !previous.turn_left,
+            turn_right: self.turn_right && !previous.turn_right,
+            shoot: self.shoot && !previous.shoot,
+            shield: self.shield && !previous.shield,
+            slowmo: self.slowmo && !previous.slowmo,
+            missile: self.missile && !previous.missile,
+            laser: self.laser && !previous.laser,
+            boost: self.boost && !previous.boost,
+            aim: self.aim,
+        }
+    }
+}
@@ -4,24 +4,62 @@
 
 pub mod consts;
 pub mod game;
+pub mod arena;
+pub mod camera;
 pub mod ship;
 pub mod weapons;
 pub mod enemies;
+pub mod boss;
 pub mod collisions;
+pub mod damage;
+pub mod spatial;
+pub mod collider;
+pub mod slotmap;
 pub mod effects;
 pub mod rendering;
 pub mod ui;
 pub mod scenes;
 pub mod events;
+pub mod input;
+pub mod rng;
+pub mod replay;
+pub mod save;
+pub mod scores;
+pub mod balance;
+pub mod bindings;
+pub mod settings;
+pub mod stats;
+pub mod audio;
+pub mod synth;
+pub mod music;
 
 pub use consts::*;
 pub use game::{Game, GameState, World, ScreenShake, Cooldown};
+pub use arena::{Arena, ArenaMode};
+pub use camera::Camera;
 pub use ship::{Ship, TrailPoint};
-pub use weapons::{WeaponSystem, HomingMissile, Laser};
-pub use enemies::{Asteroid, Drone, DroneType, Bullet, PowerUp, PowerUpType};
+pub use weapons::{WeaponSystem, WeaponKind, HomingMissile, Laser};
+pub use enemies::{Asteroid, AsteroidSize, Drone, DroneType, EnemyKind, Bullet, PowerUp, PowerUpType};
+pub use boss::{BossPart, BossPartKind};
 pub use effects::{EffectSystem, Particle, TrailPoint as EffectTrailPoint};
 pub use collisions::*;
+pub use damage::{DamageContext, DamageInfo, Damageable, Enemy};
+pub use spatial::SpatialGrid;
+pub use collider::{Collider, ConvexPolygon, StarPolygon};
+pub use slotmap::{EntityId, SlotMap};
 pub use rendering::Renderer;
 pub use ui::*;
 pub use scenes::*;
-pub use events::{Event, EventQueue};
+pub use events::{Event, EventBus, EventListener};
+pub use input::InputState;
+pub use rng::Rng;
+pub use replay::{Replay, ReplayError};
+pub use save::{SaveGame, SaveError};
+pub use scores::{ScoreEntry, ScoreStore, MemoryScoreStore, FileScoreStore};
+pub use balance::{BalanceConfig, BalanceError, BalanceWatcher};
+pub use bindings::{Action, ControlScheme, KeyBindings, KeyboardLayout};
+pub use settings::{Settings, SettingsError};
+pub use stats::RunStats;
+pub use audio::{AudioBackend, AudioSystem, Mixer, NullBackend, SoundCue};
+pub use synth::{SfxConfig, SfxParams, SynthError, Waveform};
+pub use music::{Music, MusicBank, MusicLayer, Stinger};
@@ -8,35 +8,104 @@
 // Architecture :
 // - Game : contient tout l'état du jeu
 // - Scene : gère l'état actuel (menu, jeu, pause, game over)
-// - EventQueue : file d'événements pour découplage
+// - EventBus : bus d'événements du jeu (Game::events), distribué par les scènes
+// - BalanceWatcher : rechargement à chaud de balance.ron (natif)
+// - AudioSystem : effets sonores, abonné externe du bus d'événements
+//   (synthétisés au démarrage depuis sounds.ron) et musique adaptative
 //
 // =============================================================================
 
 use macroquad::prelude::*;
 use void_survivor::*;
 use void_survivor::game::Game;
-use void_survivor::events::EventQueue;
 
 #[macroquad::main("Void Survivor")]
 async fn main() {
     // Configuration initiale
-    let mut game = Game::new();
-    let mut event_queue = EventQueue::new();
+    let mut game = Game::new(Arena::bounded(vec2(screen_width(), screen_height())), fresh_seed());
+
+    // Classement persistant (le web garde le tableau en mémoire)
+    #[cfg(not(target_arch = "wasm32"))]
+    match FileScoreStore::open(scores::SCORES_PATH) {
+        Ok(store) => game.scores = Box::new(store),
+        Err(e) => eprintln!("Classement indisponible : {e}"),
+    }
+
+    // Réglages du joueur (touches)
+    #[cfg(not(target_arch = "wasm32"))]
+    match Settings::load(settings::SETTINGS_PATH) {
+        Ok(settings) => game.settings = settings,
+        Err(e) => eprintln!("{e} (touches par défaut utilisées)"),
+    }
+
+    // Équilibrage : balance.ron s'il existe, surveillé pendant le jeu
+    #[cfg(not(target_arch = "wasm32"))]
+    let mut balance_watcher = BalanceWatcher::new(balance::BALANCE_PATH);
+    #[cfg(not(target_arch = "wasm32"))]
+    match balance_watcher.poll(0.0) {
+        Some(Ok(config)) => game.set_balance(config),
+        Some(Err(e)) => eprintln!("{e} (valeurs par défaut utilisées)"),
+        None => {}
+    }
+    // Audio : muet si le jeu est compilé sans la feature `audio`
+    #[cfg(feature = "audio")]
+    let backend = {
+        // Sons synthétisés depuis sounds.ron s'il existe
+        #[cfg(not(target_arch = "wasm32"))]
+        let sfx = SfxConfig::load(synth::SOUNDS_PATH).unwrap_or_else(|e| {
+            eprintln!("{e} (sons par défaut utilisés)");
+            SfxConfig::new()
+        });
+        #[cfg(target_arch = "wasm32")]
+        let sfx = SfxConfig::new();
+        audio::MacroquadBackend::load(&audio::SoundBank::render(&sfx), &MusicBank::render()).await
+    };
+    #[cfg(not(feature = "audio"))]
+    let backend = NullBackend::new();
+    let mut audio = AudioSystem::new(backend, game.settings.mixer);
+
+    let mut notice: Option<(String, bool, f32)> = None;    // (message, erreur, durée restante)
     let mut current_scene: Box<dyn Scene> = Box::new(MenuScene::new());
 
     // Boucle principale du jeu
     loop {
-        // Temps écoulé depuis la dernière frame
-        let dt_raw = get_frame_time();
-        
-        let dt = dt_raw * game.ship.get_slow_time_factor();
-        
+        // Temps réel écoulé depuis la dernière frame
+        // (le slow-motion est appliqué par le pas de temps fixe du jeu)
+        let dt = get_frame_time();
+
+        // Rechargement de l'équilibrage (automatique ou forcé par F5)
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            if is_key_pressed(KEY_RELOAD_BALANCE) {
+                balance_watcher.invalidate();
+            }
+            match balance_watcher.poll(dt) {
+                Some(Ok(config)) => {
+                    game.set_balance(config);
+                    notice = Some(("EQUILIBRAGE RECHARGE".to_string(), false, 2.0));
+                }
+                Some(Err(e)) => {
+                    eprintln!("{e}");
+                    notice = Some((e.to_string(), true, 5.0));
+                }
+                None => {}
+            }
+        }
+
+        // Affichage des formes de collision (debug)
+        if is_key_pressed(KEY_DEBUG_HITBOXES) {
+            game.show_hitboxes = !game.show_hitboxes;
+        }
+
         // Effacer l'écran (noir profond)
         clear_background(color_u8!(10, 10, 30, 255));
 
-        // Mise à jour de la scène courante
+        // Mise à jour de la scène courante (l'audio écoute ses événements)
         // Si elle retourne une nouvelle scène, on change
-        let transition = current_scene.update(&mut game, &mut event_queue, dt);
+        audio.mixer = game.settings.mixer;
+        audio.set_music_active(current_scene.plays_music());
+        audio.update(dt);
+        let transition = current_scene.update(&mut game, &mut audio, dt);
         
         if let Some(new_scene) = transition {
             current_scene = new_scene;
@@ -45,6 +114,15 @@ async fn main() {
         // Dessin de la scène courante
         current_scene.draw(&mut game);
 
+        // Notification de rechargement
+        if let Some((message, is_error, timer)) = notice.as_mut() {
+            UI::draw_notice(message, *is_error);
+            *timer -= dt;
+            if *timer <= 0.0 {
+                notice = None;
+            }
+        }
+
         // Attendre la prochaine frame
         next_frame().await;
     }
@@ -0,0 +1,411 @@
+// =============================================================================
+// MUSIQUE ADAPTATIVE (CHIPTUNE GÉNÉRÉE)
+// =============================================================================
+//
+// Ce fichier compose et pilote la musique du jeu :
+// - MusicBank : boucles générées au démarrage avec le synthétiseur
+//   (synth.rs), une par couche (basse, batterie, mélodie), plus des
+//   jingles (fin de vague, arrivée d'un boss)
+// - Music : volume de chaque couche selon le niveau de menace
+//   (Game::threat_level, reçu par le bus d'événements)
+//
+// Toutes les boucles ont la même durée et tournent en permanence : une
+// couche « entre » ou « sort » seulement par son volume, elle reste donc
+// calée sur les autres. Le slow-motion ne peut pas ralentir une boucle
+// déjà lancée (macroquad n'a pas de réglage de hauteur) : chaque couche
+// existe aussi en version ralentie, plus grave, et l'on passe en fondu de
+// l'une à l'autre.
+//
+// =============================================================================
+
+use std::collections::BTreeMap;
+use crate::synth::{self, SfxParams, Waveform, SAMPLE_RATE};
+
+/// Tempo de la musique (battements par minute)
+pub const MUSIC_TEMPO: f32 = 132.0;
+
+/// Vitesse de la version ralentie (tempo et hauteur, comme une bande)
+pub const SLOWMO_MUSIC_SPEED: f32 = 0.75;
+
+/// Pas (doubles croches) par battement et par mesure
+const STEPS_PER_BEAT: usize = 4;
+const STEPS_PER_BAR: usize = 16;
+
+/// Grille d'accords d'une boucle : (note fondamentale MIDI, accord mineur)
+/// La mineur, Fa, Do, Sol
+const PROGRESSION: [(i32, bool); 4] = [(45, true), (41, false), (48, false), (43, false)];
+
+/// Basse : intervalle joué à chaque croche de la mesure
+const BASS_PATTERN: [i32; 8] = [0, 0, 12, 0, 7, 0, 12, 7];
+
+/// Mélodie : degré de l'accord joué à chaque pas (fondamentale, tierce,
+/// quinte, octave)
+const LEAD_PATTERN: [usize; STEPS_PER_BAR] = [0, 1, 2, 3, 2, 1, 0, 1, 2, 3, 2, 1, 0, 2, 1, 3];
+
+/// Batterie : pas de la grosse caisse et de la caisse claire
+const KICK_STEPS: [usize; 3] = [0, 8, 10];
+const SNARE_STEPS: [usize; 2] = [4, 12];
+
+/// Plage de menace sur laquelle une couche passe du silence au plein volume
+const LAYER_FADE: f32 = 0.15;
+
+/// Vitesse des fondus de couche (volume par seconde)
+const FADE_SPEED: f32 = 1.5;
+
+/// Vitesse du fondu vers la version ralentie (par seconde)
+const SLOWMO_FADE_SPEED: f32 = 4.0;
+
+/// Volume des boucles au début d'un jingle, puis durée du retour à la normale
+const STINGER_DUCK: f32 = 0.35;
+const STINGER_DUCK_TIME: f32 = 1.2;
+
+// -----------------------------------------------------------------------------
+// COUCHES ET JINGLES
+// -----------------------------------------------------------------------------
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum MusicLayer {
+    Bass,   // Toujours présente pendant une partie
+    Drums,  // Menace moyenne
+    Lead,   // Menace forte
+}
+
+impl MusicLayer {
+    /// Toutes les couches
+    pub const ALL: [MusicLayer; 3] = [MusicLayer::Bass, MusicLayer::Drums, MusicLayer::Lead];
+
+    /// Niveau de menace à partir duquel la couche joue à plein volume
+    /// (elle apparaît progressivement sur les LAYER_FADE précédents)
+    pub fn threshold(self) -> f32 {
+        match self {
+            MusicLayer::Bass => 0.0,
+            MusicLayer::Drums => 0.25,
+            MusicLayer::Lead => 0.55,
+        }
+    }
+
+    fn index(self) -> usize {
+        self as usize
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Stinger {
+    WaveComplete,   // Fin de vague
+    Boss,           // Arrivée d'un boss
+}
+
+impl Stinger {
+    /// Tous les jingles
+    pub const ALL: [Stinger; 2] = [Stinger::WaveComplete, Stinger::Boss];
+}
+
+// -----------------------------------------------------------------------------
+// PILOTAGE DES COUCHES
+// -----------------------------------------------------------------------------
+pub struct Music {
+    pub active: bool,   // Musique jouée (partie en cours à l'écran)
+    threat: f32,        // Dernier niveau de menace reçu
+    time_scale: f32,    // Facteur de slow-motion (1 = vitesse normale)
+    gains: [f32; 3],    // Volume actuel de chaque couche
+    slowed: f32,        // Part de la version ralentie (0 à 1)
+    duck: f32,          // Temps restant d'atténuation (jingle)
+}
+
+impl Music {
+    pub fn new() -> Self {
+        Self {
+            active: false,
+            threat: 0.0,
+            time_scale: 1.0,
+            gains: [0.0; 3],
+            slowed: 0.0,
+            duck: 0.0,
+        }
+    }
+
+    /// Nouvelle partie : calme et vitesse normale
+    pub fn reset(&mut self) {
+        self.threat = 0.0;
+        self.time_scale = 1.0;
+    }
+
+    pub fn set_threat(&mut self, level: f32) {
+        self.threat = level.clamp(0.0, 1.0);
+    }
+
+    pub fn set_time_scale(&mut self, factor: f32) {
+        self.time_scale = factor;
+    }
+
+    /// Atténue les boucles le temps d'un jingle
+    pub fn duck(&mut self) {
+        self.duck = STINGER_DUCK_TIME;
+    }
+
+    /// Volume visé par une couche (selon la menace)
+    pub fn target(&self, layer: MusicLayer) -> f32 {
+        if !self.active {
+            return 0.0;
+        }
+        (1.0 + (self.threat - layer.threshold()) / LAYER_FADE).clamp(0.0, 1.0)
+    }
+
+    /// Fait avancer les fondus (temps réel de la frame)
+    pub fn update(&mut self, dt: f32) {
+        for layer in MusicLayer::ALL {
+            let target = self.target(layer);
+            let gain = &mut self.gains[layer.index()];
+            *gain = approach(*gain, target, FADE_SPEED * dt);
+        }
+        let slowed = if self.time_scale < 1.0 { 1.0 } else { 0.0 };
+        self.slowed = approach(self.slowed, slowed, SLOWMO_FADE_SPEED * dt);
+        self.duck = (self.duck - dt).max(0.0);
+    }
+
+    /// Volume d'une boucle (`slowed` : version ralentie), de 0 à 1
+    pub fn volume(&self, layer: MusicLayer, slowed: bool) -> f32 {
+        let blend = if slowed { self.slowed } else { 1.0 - self.slowed };
+        let duck = STINGER_DUCK + (1.0 - STINGER_DUCK) * (1.0 - self.duck / STINGER_DUCK_TIME);
+        self.gains[layer.index()] * blend * duck
+    }
+}
+
+impl Default for Music {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Rapproche `value` de `target` d'au plus `step`
+fn approach(value: f32, target: f32, step: f32) -> f32 {
+    if value < target {
+        (value + step).min(target)
+    } else {
+        (value - step).max(target)
+    }
+}
+
+// -----------------------------------------------------------------------------
+// BANQUE MUSICALE (ÉCHANTILLONS MONO)
+// -----------------------------------------------------------------------------
+pub struct MusicBank {
+    pub sample_rate: u32,
+    pub loops: BTreeMap<(MusicLayer, bool), Vec<f32>>,  // (couche, version ralentie)
+    pub stingers: BTreeMap<Stinger, Vec<f32>>,
+}
+
+impl MusicBank {
+    /// Compose toutes les boucles et tous les jingles
+    pub fn render() -> Self {
+        let mut loops = BTreeMap::new();
+        for layer in MusicLayer::ALL {
+            loops.insert((layer, false), render_layer(layer, 1.0));
+            loops.insert((layer, true), render_layer(layer, SLOWMO_MUSIC_SPEED));
+        }
+        let stingers = Stinger::ALL
+            .into_iter()
+            .map(|stinger| (stinger, render_stinger(stinger)))
+            .collect();
+        Self {
+            sample_rate: SAMPLE_RATE,
+            loops,
+            stingers,
+        }
+    }
+}
+
+impl Default for MusicBank {
+    fn default() -> Self {
+        Self::render()
+    }
+}
+
+// -----------------------------------------------------------------------------
+// COMPOSITION
+// -----------------------------------------------------------------------------
+
+/// Durée d'un pas (double croche) en secondes
+fn step_time() -> f32 {
+    60.0 / MUSIC_TEMPO / STEPS_PER_BEAT as f32
+}
+
+/// Fréquence d'une note MIDI (69 = La 440 Hz)
+fn midi(note: i32) -> f32 {
+    440.0 * ((note - 69) as f32 / 12.0).exp2()
+}
+
+/// Génère une boucle complète d'une couche, jouée à la vitesse `speed`
+fn render_layer(layer: MusicLayer, speed: f32) -> Vec<f32> {
+    let step = step_time();
+    let length = step * (STEPS_PER_BAR * PROGRESSION.len()) as f32 / speed;
+    let mut buffer = vec![0.0; (length * SAMPLE_RATE as f32) as usize];
+
+    for (bar, &(root, minor)) in PROGRESSION.iter().enumerate() {
+        let chord = [0, if minor { 3 } else { 4 }, 7, 12];
+        for s in 0..STEPS_PER_BAR {
+            let start = (bar * STEPS_PER_BAR + s) as f32 * step;
+            match layer {
+                MusicLayer::Bass => {
+                    if s % 2 == 0 {
+                        let note = midi(root + BASS_PATTERN[s / 2]);
+                        mix_note(&mut buffer, start, &bass(note, step), speed);
+                    }
+                }
+                MusicLayer::Drums => {
+                    if KICK_STEPS.contains(&s) {
+                        mix_note(&mut buffer, start, &kick(), speed);
+                    }
+                    if SNARE_STEPS.contains(&s) {
+                        mix_note(&mut buffer, start, &snare(), speed);
+                    }
+                    if s % 2 == 0 {
+                        mix_note(&mut buffer, start, &hat(), speed);
+                    }
+                }
+                MusicLayer::Lead => {
+                    let note = midi(root + 24 + chord[LEAD_PATTERN[s]]);
+                    mix_note(&mut buffer, start, &lead(note, step), speed);
+                }
+            }
+        }
+    }
+    buffer
+}
+
+/// Génère un jingle (joué une fois, à vitesse normale)
+fn render_stinger(stinger: Stinger) -> Vec<f32> {
+    let step = step_time();
+    let notes: Vec<(f32, SfxParams)> = match stinger {
+        // Arpège montant en La majeur
+        Stinger::WaveComplete => {
+            let mut notes: Vec<(f32, SfxParams)> = [69, 73, 76, 81]
+                .iter()
+                .enumerate()
+                .map(|(i, &n)| (i as f32 * step * 2.0, lead(midi(n), step * 2.0)))
+                .collect();
+            notes.push((0.0, bass(midi(45), step * 8.0)));
+            notes
+        }
+        // Demi-ton grave répété, sur des coups de grosse caisse
+        Stinger::Boss => (0..4)
+            .flat_map(|i| {
+                let start = i as f32 * step * 4.0;
+                let note = midi(if i % 2 == 0 { 45 } else { 46 });
+                [(start, brass(note, step * 4.0)), (start, kick())]
+            })
+            .collect(),
+    };
+
+    let length = notes
+        .iter()
+        .map(|(start, params)| start + params.duration())
+        .fold(0.0, f32::max);
+    let mut buffer = vec![0.0; (length * SAMPLE_RATE as f32) as usize + 1];
+    for (start, params) in &notes {
+        mix_note(&mut buffer, *start, params, 1.0);
+    }
+    buffer
+}
+
+/// Ajoute une note au tampon à l'instant `start` (secondes à vitesse
+/// normale). Une note qui dépasse la fin reprend au début : la boucle
+/// s'enchaîne sans coupure.
+fn mix_note(buffer: &mut [f32], start: f32, params: &SfxParams, speed: f32) {
+    let scaled = SfxParams {
+        frequency: params.frequency * speed,
+        slide: params.slide * speed,
+        delta_slide: params.delta_slide * speed * speed,
+        vibrato_speed: params.vibrato_speed * speed,
+        attack: params.attack / speed,
+        sustain: params.sustain / speed,
+        decay: params.decay / speed,
+        ..params.clone()
+    };
+    let offset = (start / speed * SAMPLE_RATE as f32) as usize;
+    let len = buffer.len();
+    for (i, sample) in synth::render(&scaled, SAMPLE_RATE).into_iter().enumerate() {
+        buffer[(offset + i) % len] += sample;
+    }
+}
+
+// -----------------------------------------------------------------------------
+// INSTRUMENTS
+// -----------------------------------------------------------------------------
+
+fn bass(frequency: f32, step: f32) -> SfxParams {
+    SfxParams {
+        waveform: Waveform::Triangle,
+        volume: 0.45,
+        sustain: step * 1.2,
+        decay: step * 0.6,
+        frequency,
+        lowpass: 0.6,
+        ..SfxParams::new()
+    }
+}
+
+fn lead(frequency: f32, step: f32) -> SfxParams {
+    SfxParams {
+        waveform: Waveform::Square,
+        volume: 0.18,
+        sustain: step * 0.5,
+        decay: step * 0.8,
+        frequency,
+        vibrato_depth: 0.01,
+        vibrato_speed: 6.0,
+        duty: 0.25,
+        lowpass: 0.7,
+        ..SfxParams::new()
+    }
+}
+
+fn brass(frequency: f32, step: f32) -> SfxParams {
+    SfxParams {
+        waveform: Waveform::Sawtooth,
+        volume: 0.4,
+        attack: 0.02,
+        sustain: step * 0.7,
+        decay: step * 0.5,
+        frequency,
+        slide: -0.2,
+        lowpass: 0.4,
+        ..SfxParams::new()
+    }
+}
+
+fn kick() -> SfxParams {
+    SfxParams {
+        waveform: Waveform::Sine,
+        volume: 0.6,
+        sustain: 0.02,
+        decay: 0.14,
+        frequency: 150.0,
+        slide: -5.0,
+        ..SfxParams::new()
+    }
+}
+
+fn snare() -> SfxParams {
+    SfxParams {
+        waveform: Waveform::Noise,
+        volume: 0.3,
+        sustain: 0.01,
+        decay: 0.12,
+        frequency: 5000.0,
+        lowpass: 0.8,
+        seed: 3,
+        ..SfxParams::new()
+    }
+}
+
+fn hat() -> SfxParams {
+    SfxParams {
+        waveform: Waveform::Noise,
+        volume: 0.1,
+        sustain: 0.0,
+        decay: 0.04,
+        frequency: 9000.0,
+        seed: 5,
+        ..SfxParams::new()
+    }
+}
@@ -2,28 +2,67 @@
 // MOTEUR DE RENDU
 // =============================================================================
 //
+// Les positions sont interpolées entre les deux derniers ticks de simulation
+// (voir FixedTimestep) pour un mouvement fluide quel que soit le framerate.
+//
 // Ce fichier gère tout l'affichage graphique :
 // - Étoiles de fond
 // - Vaisseau et sa traînée
 // - Ennemis (astéroïdes, drones)
 // - Armes (balles, missiles, laser)
 // - Effets visuels (particules)
+// - Formes de collision en debug (touche KEY_DEBUG_HITBOXES)
 // - Interface utilisateur (UI)
 //
+// Le vaisseau et les drones sont dessinés à partir de leur forme de
+// collision (collider.rs) : ce qui est vu est exactement ce qui touche.
+//
+// Le monde est dessiné à travers la caméra (camera.rs), qui suit le
+// vaisseau ; les étoiles, la minicarte et le HUD restent fixes à l'écran.
+// Seules les entités qui tombent dans la vue sont dessinées ; en arène
+// torique, une entité vue à travers un bord est dessinée à sa copie dans
+// la vue (Arena::images_in) : le passage d'un bord à l'autre ne se voit pas.
+//
 // =============================================================================
 
 use macroquad::prelude::*;
 use std::collections::VecDeque;
 use crate::consts::*;
+use crate::arena::Arena;
+use crate::camera::Camera;
 use crate::game::*;
 use crate::ship::Ship;
+use crate::collider::Collider;
+use crate::slotmap::EntityId;
 use crate::enemies::*;
+use crate::boss::{BossPart, BossPartKind};
 use crate::weapons::*;
 use crate::effects;
 use crate::ui::*;
 
 pub struct Renderer;
 
+/// Distance au-delà de laquelle un déplacement est une téléportation (wrap)
+const TELEPORT_DISTANCE: f32 = 100.0;
+
+/// Défilement des étoiles par rapport à celui de la caméra (profondeur)
+const STAR_PARALLAX: f32 = 0.3;
+
+/// Position interpolée entre le tick précédent et le tick courant
+fn lerp_pos(prev: Vec2, pos: Vec2, alpha: f32) -> Vec2 {
+    if prev.distance_squared(pos) > TELEPORT_DISTANCE * TELEPORT_DISTANCE {
+        pos
+    } else {
+        prev.lerp(pos, alpha)
+    }
+}
+
+impl Default for Renderer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl Renderer {
     /// Crée un nouveau renderer
     pub fn new() -> Self {
@@ -34,13 +73,17 @@ impl Renderer {
     // FOND
     // -------------------------------------------------------------------------
 
-    /// Dessine les étoiles animées en arrière-plan
-    pub fn draw_stars(&self) {
+    /// Dessine les étoiles animées en arrière-plan (en coordonnées écran),
+    /// décalées avec la caméra centrée en `camera_center`
+    pub fn draw_stars(&self, camera_center: Vec2) {
         let time = get_time() as f32;
+        let scroll = -camera_center * STAR_PARALLAX;
         for i in 0..150 {
             let i_f32 = i as f32;
             let x = (time * 0.3 + i_f32 * 0.07).sin() * 30.0 + (i_f32 * 13.7).sin() * screen_width();
             let y = (i_f32 * 7.3 + time * 0.2).sin() * screen_height();
+            let x = (x + scroll.x).rem_euclid(screen_width());
+            let y = (y + scroll.y).rem_euclid(screen_height());
             let size = ((i % 4) + 1) as f32;
             let brightness = (80.0 + (175.0 * ((time * 2.0 + i_f32 * 0.3).sin() * 0.5 + 0.5))) as u8;
             let alpha = 150 + (brightness / 10) as u8;
@@ -59,19 +102,56 @@ impl Renderer {
 
     /// Dessine la scène de jeu complète
     pub fn draw_game(&self, game: &Game, shake_vec: Vec2) {
-        self.draw_stars();
+        let alpha = game.clock.alpha();
+        let arena = &game.arena;
+        let center = self.camera_center(game);
+        let view = Camera::view_rect(center, arena);
+        self.draw_stars(center);
+
+        set_camera(&Camera::camera2d(center, arena));
         self.draw_ship_trail(&game.effects.trail, &shake_vec);
         self.draw_particles(&game.effects.particles);
-        self.draw_asteroids(&game.world.asteroids);
-        self.draw_drones(&game.world.drones, game.wave);
-        self.draw_bullets(&game.world.bullets, &game.ship.rapid_fire_timer);
-        self.draw_missiles(&game.weapons.missiles);
-        self.draw_laser(&game.weapons.laser, &shake_vec);
-        self.draw_powerups(&game.world.powerups);
-        self.draw_ship(&game.ship, &shake_vec);
+        self.draw_asteroids(&game.world.asteroids, arena, view, alpha);
+        self.draw_drones(&game.world.drones, game.wave, arena, view, alpha);
+        self.draw_boss(&game.world.boss_parts, arena, view, alpha);
+        self.draw_locks(&game.weapons, &game.world, arena, view, alpha);
+        self.draw_bullets(&game.world.bullets, &game.ship.rapid_fire_timer, arena, view, alpha);
+        self.draw_missiles(&game.weapons.missiles, arena, view, alpha);
+        self.draw_laser(&game.weapons.laser, game.balance.weapons.laser_range, &shake_vec);
+        self.draw_powerups(&game.world.powerups, arena, view, alpha);
+        self.draw_ship(&game.ship, &shake_vec, arena, view, alpha);
+        if game.show_hitboxes {
+            self.draw_hitboxes(game);
+        }
+        set_default_camera();
+
+        UI::draw_minimap(game, view);
         UI::draw_hud(game);
     }
 
+    // -------------------------------------------------------------------------
+    // CAMÉRA
+    // -------------------------------------------------------------------------
+
+    /// Centre de la vue pour cette frame (interpolé comme les entités)
+    fn camera_center(&self, game: &Game) -> Vec2 {
+        let ship_pos = lerp_pos(game.ship.prev_pos, game.ship.pos, game.clock.alpha());
+        game.camera.render_center(ship_pos, &game.arena, game.clock.alpha())
+    }
+
+    /// Active la caméra du monde : les dessins suivants sont en coordonnées
+    /// du monde, jusqu'à `set_default_camera`
+    pub fn set_world_camera(&self, game: &Game) {
+        set_camera(&Camera::camera2d(self.camera_center(game), &game.arena));
+    }
+
+    /// Position à l'écran d'un point du monde (visée à la souris)
+    pub fn world_to_screen(&self, game: &Game, pos: Vec2) -> Vec2 {
+        let center = self.camera_center(game);
+        let pos = game.arena.image_near(pos, center);
+        Camera::camera2d(center, &game.arena).world_to_screen(pos)
+    }
+
     /// Dessine la traînée du vaisseau
     fn draw_ship_trail(&self, trail: &VecDeque<effects::TrailPoint>, shake_vec: &Vec2) {
         for (i, tp) in trail.iter().enumerate() {
@@ -100,38 +180,106 @@ impl Renderer {
     }
 
     /// Dessine les astéroïdes
-    fn draw_asteroids(&self, asteroids: &Vec<Asteroid>) {
+    fn draw_asteroids(&self, asteroids: &[Asteroid], arena: &Arena, view: Rect, alpha: f32) {
         for a in asteroids {
-            draw_circle_lines(a.pos.x, a.pos.y, a.radius, 2., WHITE);
-            let detail_pos = a.pos + Vec2::from_angle(a.rotation) * (a.radius * 0.7);
-            draw_circle(detail_pos.x, detail_pos.y, a.radius * 0.2, DARKGRAY);
+            let pos = lerp_pos(a.prev_pos, a.pos, alpha);
+            for offset in arena.images_in(pos, a.radius, view) {
+                a.hull_at(pos + offset).draw_lines(2., WHITE);
+            }
         }
     }
 
     /// Dessine les drones
-    fn draw_drones(&self, drones: &Vec<Drone>, wave: i32) {
+    fn draw_drones(&self, drones: &[Drone], wave: i32, arena: &Arena, view: Rect, alpha: f32) {
         for d in drones {
             let c = match d.kind {
                 DroneType::Kamikaze => ORANGE,
                 DroneType::Sniper => RED,
                 DroneType::Bomber => PURPLE,
             };
-            
-            draw_rectangle(d.pos.x - 10., d.pos.y - 10., 20., 20., c);
-            draw_rectangle_lines(d.pos.x - 10., d.pos.y - 10., 20., 20., 2., WHITE);
-
-            // Barre de vie
-            let max_hp = 2.0 + (wave / 3) as f32;
-            let health_width = 20.0 * (d.hp as f32 / max_hp);
-            draw_rectangle(d.pos.x - 10., d.pos.y - 15., health_width, 3., GREEN);
-            
-            // Indicateur de menace pour les drones qui tirent
-            if d.kind == DroneType::Sniper || d.kind == DroneType::Bomber {
-                self.draw_threat_indicator(d.pos);
+            let center = lerp_pos(d.prev_pos, d.pos, alpha);
+            // Portée : indicateur de menace et son texte compris
+            for offset in arena.images_in(center, 50.0, view) {
+                let pos = center + offset;
+                let hull = Drone::hull_at(pos);
+                hull.draw(c);
+                hull.draw_lines(2., WHITE);
+
+                // Barre de vie
+                let max_hp = 2.0 + (wave / 3) as f32;
+                let health_width = 20.0 * (d.hp as f32 / max_hp);
+                draw_rectangle(pos.x - 10., pos.y - 15., health_width, 3., GREEN);
+
+                // Indicateur de menace pour les drones qui tirent
+                if d.kind == DroneType::Sniper || d.kind == DroneType::Bomber {
+                    self.draw_threat_indicator(pos);
+                }
             }
         }
     }
 
+    /// Dessine le vaisseau-mère : coque du noyau (plus rouge à chaque
+    /// phase), puis les tourelles et leur barre de vie
+    fn draw_boss(&self, parts: &[BossPart], arena: &Arena, view: Rect, alpha: f32) {
+        for p in parts {
+            let center = lerp_pos(p.prev_pos, p.pos, alpha);
+            for offset in arena.images_in(center, p.radius(), view) {
+                let pos = center + offset;
+                let hull = p.hull_at(pos);
+                match p.kind {
+                    BossPartKind::Core => {
+                        let fill = match p.phase {
+                            1 => Color::new(0.35, 0.05, 0.35, 1.0),
+                            2 => Color::new(0.55, 0.05, 0.35, 1.0),
+                            _ => Color::new(0.75, 0.05, 0.15, 1.0),
+                        };
+                        hull.draw(fill);
+                        hull.draw_lines(3., WHITE);
+                        draw_circle(pos.x, pos.y, 10.0, MAGENTA);
+                    }
+                    BossPartKind::Turret => {
+                        hull.draw(DARKGRAY);
+                        hull.draw_lines(2., MAGENTA);
+                        let health_width = 20.0 * p.health_percent();
+                        draw_rectangle(pos.x - 10., pos.y - 18., health_width, 3., GREEN);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Dessine les réticules des cibles verrouillées, et un cercle discret
+    /// autour des cibles poursuivies par les missiles en vol
+    fn draw_locks(&self, weapons: &WeaponSystem, world: &World, arena: &Arena, view: Rect, alpha: f32) {
+        let time = get_time() as f32;
+        for (i, target) in weapons.locks.iter().enumerate() {
+            if let Some(pos) = target.render_position(world, alpha) {
+                for offset in arena.images_in(pos, 24.0, view) {
+                    self.draw_reticle(pos + offset, 24.0, time * 2.0 + i as f32, ORANGE);
+                }
+            }
+        }
+        for m in weapons.missiles.iter() {
+            if let Some(pos) = m.target.and_then(|t| t.render_position(world, alpha)) {
+                for offset in arena.images_in(pos, 20.0, view) {
+                    let pos = pos + offset;
+                    draw_circle_lines(pos.x, pos.y, 20.0, 1.0, Color::new(1.0, 0.6, 0.0, 0.5));
+                }
+            }
+        }
+    }
+
+    /// Réticule : cercle et quatre traits orientés par `rotation` (radians)
+    fn draw_reticle(&self, pos: Vec2, size: f32, rotation: f32, color: Color) {
+        draw_circle_lines(pos.x, pos.y, size * 0.75, 1.5, color);
+        for k in 0..4 {
+            let dir = Vec2::from_angle(rotation + k as f32 * std::f32::consts::FRAC_PI_2);
+            let inner = pos + dir * size * 0.55;
+            let outer = pos + dir * size;
+            draw_line(inner.x, inner.y, outer.x, outer.y, 2.0, color);
+        }
+    }
+
     /// Dessine un indicateur de menace autour d'un drone
     fn draw_threat_indicator(&self, pos: Vec2) {
         let time = get_time() as f32;
@@ -150,7 +298,7 @@ impl Renderer {
     }
 
     /// Dessine les balles
-    fn draw_bullets(&self, bullets: &Vec<Bullet>, rapid_fire_timer: &Cooldown) {
+    fn draw_bullets(&self, bullets: &[Bullet], rapid_fire_timer: &Cooldown, arena: &Arena, view: Rect, alpha: f32) {
         for b in bullets {
             let color = if b.enemy {
                 if b.size > 5.0 {
@@ -165,36 +313,44 @@ impl Renderer {
                     SKYBLUE
                 }
             };
-            draw_circle(b.pos.x, b.pos.y, b.size, color);
+            let pos = lerp_pos(b.prev_pos, b.pos, alpha);
+            for offset in arena.images_in(pos, b.size, view) {
+                draw_circle(pos.x + offset.x, pos.y + offset.y, b.size, color);
+            }
         }
     }
 
     /// Dessine les missiles téléguidés
-    fn draw_missiles(&self, missiles: &Vec<HomingMissile>) {
+    fn draw_missiles(&self, missiles: &[HomingMissile], arena: &Arena, view: Rect, alpha: f32) {
         for m in missiles {
-            // Traînée du missile
-            let trail_len = 5;
-            for i in 0..trail_len {
-                let t = i as f32 / trail_len as f32;
-                let pos_trail = m.pos - m.vel * t * 0.05;
-                draw_circle(
-                    pos_trail.x,
-                    pos_trail.y,
-                    3.0 * (1.0 - t),
-                    Color::new(1.0, 0.5, 0.0, 0.5 - t * 0.4),
-                );
+            let center = lerp_pos(m.prev_pos, m.pos, alpha);
+            // Portée : halo et traînée compris
+            for offset in arena.images_in(center, MISSILE_RADIUS + m.vel.length() * 0.05, view) {
+                let pos = center + offset;
+                // Traînée du missile
+                let trail_len = 5;
+                for i in 0..trail_len {
+                    let t = i as f32 / trail_len as f32;
+                    let pos_trail = pos - m.vel * t * 0.05;
+                    draw_circle(
+                        pos_trail.x,
+                        pos_trail.y,
+                        3.0 * (1.0 - t),
+                        Color::new(1.0, 0.5, 0.0, 0.5 - t * 0.4),
+                    );
+                }
+                draw_circle(pos.x, pos.y, 4.0, ORANGE);
+                draw_circle(pos.x, pos.y, MISSILE_RADIUS, Color::new(1.0, 0.6, 0.0, 0.4));
             }
-            draw_circle(m.pos.x, m.pos.y, 4.0, ORANGE);
-            draw_circle(m.pos.x, m.pos.y, 6.0, Color::new(1.0, 0.6, 0.0, 0.4));
         }
     }
 
     /// Dessine le laser
-    fn draw_laser(&self, laser: &Laser, shake_vec: &Vec2) {
+    fn draw_laser(&self, laser: &Laser, range: f32, shake_vec: &Vec2) {
         if laser.active {
             let beam_start = laser.start_pos + *shake_vec;
             let beam_dir = laser.direction;
-            let beam_end = beam_start + beam_dir * LASER_RANGE;
+            let beam_end = beam_start + beam_dir * range;
 
             draw_line(beam_start.x, beam_start.y, beam_end.x, beam_end.y, 3.0, RED);
             draw_line(
@@ -202,30 +358,34 @@ impl Renderer {
                 beam_start.y,
                 beam_end.x,
                 beam_end.y,
-                8.0,
+                LASER_HALF_WIDTH * 2.0,
                 Color::new(1.0, 0.0, 0.0, 0.2),
             );
 
             // Étincelles sur le trajet
             for _ in 0..3 {
                 let t = rand::gen_range(0.0, 1.0);
-                let p = beam_start + beam_dir * t * LASER_RANGE;
+                let p = beam_start + beam_dir * t * range;
                 draw_circle(p.x, p.y, 2.0, RED);
             }
         }
     }
 
     /// Dessine les power-ups
-    fn draw_powerups(&self, powerups: &Vec<PowerUp>) {
+    fn draw_powerups(&self, powerups: &[PowerUp], arena: &Arena, view: Rect, alpha: f32) {
         for p in powerups {
             let time = get_time() as f32;
-            draw_poly(p.pos.x, p.pos.y, 6, 10.0, time * 100.0, p.color());
-            draw_poly_lines(p.pos.x, p.pos.y, 6, 10.0, time * 100.0, 2.0, WHITE);
+            let center = lerp_pos(p.prev_pos, p.pos, alpha);
+            for offset in arena.images_in(center, POWERUP_RADIUS, view) {
+                let pos = center + offset;
+                draw_poly(pos.x, pos.y, 6, 10.0, time * 100.0, p.color());
+                draw_poly_lines(pos.x, pos.y, 6, 10.0, time * 100.0, 2.0, WHITE);
+            }
         }
     }
 
     /// Dessine le vaisseau du joueur
-    fn draw_ship(&self, ship: &Ship, shake_vec: &Vec2) {
+    fn draw_ship(&self, ship: &Ship, shake_vec: &Vec2, arena: &Arena, view: Rect, alpha: f32) {
         // Couleur selon l'état (invincible, bouclier, etc.)
         let mut ship_color = if ship.is_invincible() && (ship.invincible_timer.time * 10.0) as i32 % 2 == 0 {
             Color::new(1.0, 1.0, 1.0, 0.5)  // Clignotement
@@ -246,28 +406,62 @@ impl Renderer {
             );
         }
 
-        let pos = ship.pos + *shake_vec;
-        let rot_deg = ship.rot.to_degrees();
-
-        draw_poly(pos.x, pos.y, 3, SHIP_RADIUS, rot_deg, ship_color);
-        draw_poly_lines(pos.x, pos.y, 3, SHIP_RADIUS, rot_deg, 2.0, WHITE);
-
-        // Effet de moteur
-        let rear_dir = -ship.dir;
-        let rear_pos = ship.pos + rear_dir * 10.0;
-        draw_line(
-            rear_pos.x,
-            rear_pos.y,
-            rear_pos.x + rear_dir.x * 8.0,
-            rear_pos.y + rear_dir.y * 8.0,
-            2.0,
-            Color::new(1.0, 1.0, 1.0, 0.7),
-        );
+        let center = lerp_pos(ship.prev_pos, ship.pos, alpha);
+        let rot = ship.prev_rot + (ship.rot - ship.prev_rot) * alpha;
+        for offset in arena.images_in(center, SHIELD_RADIUS, view) {
+            let ship_pos = center + offset;
+            let hull = Ship::hull_at(ship_pos + *shake_vec, rot);
+            hull.draw(ship_color);
+            hull.draw_lines(2.0, WHITE);
+
+            // Effet de moteur
+            let rear_dir = -Vec2::from_angle(rot);
+            let rear_pos = ship_pos + rear_dir * 10.0;
+            draw_line(
+                rear_pos.x,
+                rear_pos.y,
+                rear_pos.x + rear_dir.x * 8.0,
+                rear_pos.y + rear_dir.y * 8.0,
+                2.0,
+                Color::new(1.0, 1.0, 1.0, 0.7),
+            );
+
+            // Bouclier visuel
+            if ship.shield_active {
+                draw_circle_lines(ship_pos.x, ship_pos.y, SHIELD_RADIUS, 2., SKYBLUE);
+                draw_circle_lines(ship_pos.x, ship_pos.y, 25., 1., Color::new(0.5, 0.8, 1.0, 0.5));
+            }
+        }
+    }
+
+    /// Dessine les formes de collision à la position simulée (sans
+    /// interpolation) : vert pour le joueur, rouge pour les ennemis,
+    /// jaune pour les power-ups. Ennemis et missiles sont étiquetés
+    /// avec leur EntityId.
+    fn draw_hitboxes(&self, game: &Game) {
+        let friendly = Color::new(0.2, 1.0, 0.2, 0.8);
+        let hostile = Color::new(1.0, 0.2, 0.2, 0.8);
+        let pickup = Color::new(1.0, 1.0, 0.2, 0.8);
 
-        // Bouclier visuel
-        if ship.shield_active {
-            draw_circle_lines(ship.pos.x, ship.pos.y, 28., 2., SKYBLUE);
-            draw_circle_lines(ship.pos.x, ship.pos.y, 25., 1., Color::new(0.5, 0.8, 1.0, 0.5));
+        let mut shapes: Vec<(Collider, Color, Option<EntityId>)> = vec![(game.ship.collider(), friendly, None)];
+        shapes.extend(game.world.asteroids.iter_with_ids().map(|(id, a)| (a.collider(), hostile, Some(id))));
+        shapes.extend(game.world.drones.iter_with_ids().map(|(id, d)| (d.collider(), hostile, Some(id))));
+        shapes.extend(game.world.boss_parts.iter_with_ids().map(|(id, p)| (p.collider(), hostile, Some(id))));
+        shapes.extend(game.world.bullets.iter().map(|b| {
+            (b.collider(), if b.enemy { hostile } else { friendly }, None)
+        }));
+        shapes.extend(game.weapons.missiles.iter_with_ids().map(|(id, m)| (m.collider(), friendly, Some(id))));
+        if game.weapons.laser.active {
+            shapes.push((game.weapons.laser.collider(game.balance.weapons.laser_range), friendly, None));
+        }
+        shapes.extend(game.world.powerups.iter().map(|p| (p.collider(), pickup, None)));
+
+        for (shape, color, id) in shapes {
+            shape.draw_lines(1.0, color);
+            if let Some(id) = id {
+                let corner = shape.center() + Vec2::splat(shape.bounding_radius()) * 0.7;
+                draw_text(id.to_string(), corner.x, corner.y, 14.0, color);
+            }
         }
     }
 }
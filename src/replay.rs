@@ -0,0 +1,368 @@
+// =============================================================================
+// REPLAYS (ENREGISTREMENT ET RELECTURE DES PARTIES)
+// =============================================================================
+//
+// Une partie est entièrement déterminée par :
+// - sa graine (voir rng.rs)
+// - la taille, le mode et la vue de l'arène (voir arena.rs)
+// - les entrées du joueur à chaque tick fixe (voir input.rs)
+//
+// Le replay stocke donc uniquement ces données, dans un format binaire compact
+// (entrées compressées par plages : un état répété sur N ticks = 6 octets).
+//
+// Format 4 (little-endian) :
+//   "VSRP" | format u8 | version du jeu (u8 + UTF-8) | graine u64
+//   | arène f32 f32 | mode de l'arène u8 (0 bornée, 1 torique)
+//   | vue f32 f32 | nb de plages u32 | plages (bits u32, durée u16)...
+//
+// Les formats 1 (bits u16, sans visée à la souris), 2 (sans mode d'arène :
+// arène bornée) et 3 (sans vue : arène entièrement visible) restent lisibles.
+//
+// =============================================================================
+
+use macroquad::prelude::*;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+use crate::arena::{Arena, ArenaMode};
+use crate::input::InputState;
+
+/// Signature en tête de fichier
+const REPLAY_MAGIC: &[u8; 4] = b"VSRP";
+
+/// Version du format binaire (à incrémenter si la structure change)
+pub const REPLAY_FORMAT: u8 = 4;
+
+/// Version du jeu qui a enregistré le replay
+pub const GAME_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// Dossier des replays et fichier de la dernière partie
+pub const REPLAY_DIR: &str = "replays";
+pub const LAST_REPLAY_PATH: &str = "replays/last.vsr";
+
+// -----------------------------------------------------------------------------
+// ERREURS DE CHARGEMENT
+// -----------------------------------------------------------------------------
+#[derive(Debug)]
+pub enum ReplayError {
+    Io(io::Error),              // Lecture / écriture impossible
+    BadMagic,                   // Pas un fichier de replay
+    UnsupportedFormat(u8),      // Format plus récent que ce binaire
+    Truncated,                  // Fichier incomplet
+}
+
+impl fmt::Display for ReplayError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ReplayError::Io(e) => write!(f, "erreur d'accès au replay : {e}"),
+            ReplayError::BadMagic => write!(f, "ce fichier n'est pas un replay"),
+            ReplayError::UnsupportedFormat(v) => write!(f, "format de replay {v} non supporté"),
+            ReplayError::Truncated => write!(f, "replay tronqué"),
+        }
+    }
+}
+
+impl std::error::Error for ReplayError {}
+
+impl From<io::Error> for ReplayError {
+    fn from(e: io::Error) -> Self {
+        ReplayError::Io(e)
+    }
+}
+
+// -----------------------------------------------------------------------------
+// REPLAY
+// -----------------------------------------------------------------------------
+#[derive(Clone, Debug, PartialEq)]
+pub struct Replay {
+    pub game_version: String,
+    pub seed: u64,
+    pub arena: Arena,
+    pub inputs: Vec<u32>,   // Un champ de bits par tick
+}
+
+impl Replay {
+    /// Crée un replay vide pour une nouvelle partie
+    pub fn new(seed: u64, arena: Arena) -> Self {
+        Self {
+            game_version: GAME_VERSION.to_string(),
+            seed,
+            arena,
+            inputs: Vec::new(),
+        }
+    }
+
+    /// Enregistre les entrées d'un tick
+    pub fn record(&mut self, input: &InputState) {
+        self.inputs.push(input.to_bits());
+    }
+
+    /// Entrées du tick demandé (None après la fin du replay)
+    pub fn input_at(&self, tick: usize) -> Option<InputState> {
+        self.inputs.get(tick).map(|bits| InputState::from_bits(*bits))
+    }
+
+    /// Nombre de ticks enregistrés
+    pub fn len(&self) -> usize {
+        self.inputs.len()
+    }
+
+    /// Vérifie si le replay est vide
+    pub fn is_empty(&self) -> bool {
+        self.inputs.is_empty()
+    }
+
+    /// Vérifie si le replay a été enregistré par cette version du jeu
+    /// (sinon l'équilibrage a pu changer et la relecture diverger)
+    pub fn matches_game_version(&self) -> bool {
+        self.game_version == GAME_VERSION
+    }
+
+    // -------------------------------------------------------------------------
+    // ENCODAGE
+    // -------------------------------------------------------------------------
+
+    /// Sérialise le replay au format binaire
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let runs = encode_runs(&self.inputs);
+        let version = self.game_version.as_bytes();
+        let version_len = version.len().min(u8::MAX as usize);
+
+        let mut out = Vec::with_capacity(32 + version_len + runs.len() * 6);
+        out.extend_from_slice(REPLAY_MAGIC);
+        out.push(REPLAY_FORMAT);
+        out.push(version_len as u8);
+        out.extend_from_slice(&version[..version_len]);
+        out.extend_from_slice(&self.seed.to_le_bytes());
+        out.extend_from_slice(&self.arena.size.x.to_le_bytes());
+        out.extend_from_slice(&self.arena.size.y.to_le_bytes());
+        out.push(match self.arena.mode {
+            ArenaMode::Bounded => 0,
+            ArenaMode::Toroidal => 1,
+        });
+        out.extend_from_slice(&self.arena.view.x.to_le_bytes());
+        out.extend_from_slice(&self.arena.view.y.to_le_bytes());
+        out.extend_from_slice(&(runs.len() as u32).to_le_bytes());
+        for (bits, count) in runs {
+            out.extend_from_slice(&bits.to_le_bytes());
+            out.extend_from_slice(&count.to_le_bytes());
+        }
+        out
+    }
+
+    /// Désérialise un replay
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, ReplayError> {
+        let mut r = Reader { bytes, pos: 0 };
+
+        if r.take(4)? != REPLAY_MAGIC {
+            return Err(ReplayError::BadMagic);
+        }
+        let format = r.u8()?;
+        if format > REPLAY_FORMAT {
+            return Err(ReplayError::UnsupportedFormat(format));
+        }
+
+        let version_len = r.u8()? as usize;
+        let game_version = String::from_utf8_lossy(r.take(version_len)?).into_owned();
+        let seed = r.u64()?;
+        let size = vec2(r.f32()?, r.f32()?);
+        let mode = if format >= 3 && r.u8()? == 1 {
+            ArenaMode::Toroidal
+        } else {
+            ArenaMode::Bounded
+        };
+        let view = if format >= 4 { vec2(r.f32()?, r.f32()?) } else { size };
+        let arena = Arena::new(size, mode).with_view(view);
+
+        let run_count = r.u32()? as usize;
+        let mut inputs = Vec::new();
+        for _ in 0..run_count {
+            let bits = if format == 1 { r.u16()? as u32 } else { r.u32()? };
+            let count = r.u16()? as usize;
+            inputs.extend(std::iter::repeat_n(bits, count));
+        }
+
+        Ok(Self {
+            game_version,
+            seed,
+            arena,
+            inputs,
+        })
+    }
+
+    /// Écrit le replay sur disque (crée le dossier si nécessaire)
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), ReplayError> {
+        let path = path.as_ref();
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(path, self.to_bytes())?;
+        Ok(())
+    }
+
+    /// Charge un replay depuis le disque
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, ReplayError> {
+        Self::from_bytes(&fs::read(path)?)
+    }
+}
+
+/// Compresse les entrées en plages (état, nombre de ticks consécutifs)
+fn encode_runs(inputs: &[u32]) -> Vec<(u32, u16)> {
+    let mut runs: Vec<(u32, u16)> = Vec::new();
+    for &bits in inputs {
+        match runs.last_mut() {
+            Some((last, count)) if *last == bits && *count < u16::MAX => *count += 1,
+            _ => runs.push((bits, 1)),
+        }
+    }
+    runs
+}
+
+// -----------------------------------------------------------------------------
+// LECTEUR BINAIRE
+// -----------------------------------------------------------------------------
+struct Reader<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, n: usize) -> Result<&'a [u8], ReplayError> {
+        let end = self.pos.checked_add(n).ok_or(ReplayError::Truncated)?;
+        let slice = self.bytes.get(self.pos..end).ok_or(ReplayError::Truncated)?;
+        self.pos = end;
+        Ok(slice)
+    }
+
+    fn array<const N: usize>(&mut self) -> Result<[u8; N], ReplayError> {
+        let mut out = [0; N];
+        out.copy_from_slice(self.take(N)?);
+        Ok(out)
+    }
+
+    fn u8(&mut self) -> Result<u8, ReplayError> {
+        Ok(self.take(1)?[0])
+    }
+
+    fn u16(&mut self) -> Result<u16, ReplayError> {
+        Ok(u16::from_le_bytes(self.array()?))
+    }
+
+    fn u32(&mut self) -> Result<u32, ReplayError> {
+        Ok(u32::from_le_bytes(self.array()?))
+    }
+
+    fn u64(&mut self) -> Result<u64, ReplayError> {
+        Ok(u64::from_le_bytes(self.array()?))
+    }
+
+    fn f32(&mut self) -> Result<f32, ReplayError> {
+        Ok(f32::from_le_bytes(self.array()?))
+    }
+}
+
+// =============================================================================
+// TESTS
+// =============================================================================
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn arena() -> Arena {
+        Arena::new(vec2(2400., 1800.), ArenaMode::Toroidal).with_view(vec2(800., 600.))
+    }
+
+    fn round_trip(replay: &Replay) -> Replay {
+        Replay::from_bytes(&replay.to_bytes()).expect("replay illisible")
+    }
+
+    /// En-tête d'un ancien format, jusqu'à la taille de l'arène comprise
+    fn legacy_header(format: u8, seed: u64, size: Vec2) -> Vec<u8> {
+        let mut out = REPLAY_MAGIC.to_vec();
+        out.push(format);
+        out.push(5);
+        out.extend_from_slice(b"0.1.0");
+        out.extend_from_slice(&seed.to_le_bytes());
+        out.extend_from_slice(&size.x.to_le_bytes());
+        out.extend_from_slice(&size.y.to_le_bytes());
+        out
+    }
+
+    #[test]
+    fn long_runs_round_trip() {
+        let mut replay = Replay::new(7, arena());
+        let held = InputState { thrust: true, shoot: true, ..InputState::new() };
+        // Plus de u16::MAX ticks identiques : la plage est coupée en deux
+        for _ in 0..(u16::MAX as usize + 1000) {
+            replay.record(&held);
+        }
+        replay.record(&InputState::new());
+        assert_eq!(encode_runs(&replay.inputs).len(), 3);
+        assert_eq!(round_trip(&replay), replay);
+    }
+
+    #[test]
+    fn alternating_inputs_round_trip() {
+        let mut replay = Replay::new(99, arena());
+        for tick in 0..500 {
+            let mut input = InputState { shoot: tick % 2 == 1, turn_left: tick % 3 == 1, ..InputState::new() };
+            if tick % 5 == 1 {
+                input.set_aim(tick as f32 * 0.1);
+            }
+            replay.record(&input);
+        }
+        let back = round_trip(&replay);
+        assert_eq!(back, replay);
+        assert_eq!(back.input_at(11), replay.input_at(11));
+    }
+
+    #[test]
+    fn reads_format_1() {
+        let mut bytes = legacy_header(1, 3, vec2(800., 600.));
+        bytes.extend_from_slice(&1u32.to_le_bytes());
+        bytes.extend_from_slice(&0b1u16.to_le_bytes());
+        bytes.extend_from_slice(&4u16.to_le_bytes());
+        let replay = Replay::from_bytes(&bytes).unwrap();
+        assert_eq!(replay.seed, 3);
+        assert_eq!(replay.arena, Arena::new(vec2(800., 600.), ArenaMode::Bounded));
+        assert_eq!(replay.inputs, vec![1; 4]);
+    }
+
+    #[test]
+    fn reads_formats_2_and_3() {
+        let mut v2 = legacy_header(2, 5, vec2(800., 600.));
+        v2.extend_from_slice(&1u32.to_le_bytes());
+        v2.extend_from_slice(&0x1_0000u32.to_le_bytes());
+        v2.extend_from_slice(&2u16.to_le_bytes());
+        let replay = Replay::from_bytes(&v2).unwrap();
+        assert_eq!(replay.arena.mode, ArenaMode::Bounded);
+        assert_eq!(replay.inputs, vec![0x1_0000; 2]);
+
+        let mut v3 = legacy_header(3, 5, vec2(1000., 700.));
+        v3.push(1);
+        v3.extend_from_slice(&0u32.to_le_bytes());
+        let replay = Replay::from_bytes(&v3).unwrap();
+        assert_eq!(replay.arena, Arena::new(vec2(1000., 700.), ArenaMode::Toroidal));
+        assert_eq!(replay.arena.view, vec2(1000., 700.));
+        assert!(replay.is_empty());
+    }
+
+    #[test]
+    fn truncated_buffer_is_rejected() {
+        let mut replay = Replay::new(1, arena());
+        replay.record(&InputState { thrust: true, ..InputState::new() });
+        replay.record(&InputState::new());
+        let bytes = replay.to_bytes();
+        for len in [3, 10, bytes.len() - 1] {
+            assert!(matches!(Replay::from_bytes(&bytes[..len]), Err(ReplayError::Truncated)), "{len} octets");
+        }
+    }
+
+    #[test]
+    fn newer_format_is_rejected() {
+        let mut bytes = Replay::new(1, arena()).to_bytes();
+        bytes[4] = REPLAY_FORMAT + 1;
+        assert!(matches!(Replay::from_bytes(&bytes), Err(ReplayError::UnsupportedFormat(_))));
+    }
+}
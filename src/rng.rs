@@ -0,0 +1,105 @@
+// =============================================================================
+// GÉNÉRATEUR ALÉATOIRE DÉTERMINISTE
+// =============================================================================
+//
+// Ce fichier fournit un générateur pseudo-aléatoire initialisé par une graine :
+// - Rng : générateur SplitMix64 (état sur 64 bits, rapide, reproductible)
+// - SampleRange : tirage uniforme dans un intervalle [low, high)
+//
+// Chaque partie possède ses propres flux (gameplay et cosmétique) au lieu
+// d'utiliser le générateur global de macroquad : deux parties lancées avec
+// la même graine et les mêmes entrées produisent exactement le même monde.
+//
+// =============================================================================
+
+use serde::{Deserialize, Serialize};
+
+/// Décalage appliqué à la graine pour le flux cosmétique (particules, shake)
+pub const COSMETIC_STREAM: u64 = 0xC0FF_EE00_D15E_A5E5;
+
+// -----------------------------------------------------------------------------
+// GÉNÉRATEUR
+// -----------------------------------------------------------------------------
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    /// Crée un générateur à partir d'une graine
+    pub fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    /// Crée le flux cosmétique associé à une graine de partie
+    pub fn cosmetic(seed: u64) -> Self {
+        Self::new(seed ^ COSMETIC_STREAM)
+    }
+
+    /// Retourne les 64 bits suivants (SplitMix64)
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// Retourne 32 bits aléatoires
+    pub fn next_u32(&mut self) -> u32 {
+        (self.next_u64() >> 32) as u32
+    }
+
+    /// Retourne un flottant uniforme dans [0, 1)
+    pub fn next_f32(&mut self) -> f32 {
+        (self.next_u32() >> 8) as f32 / (1u32 << 24) as f32
+    }
+
+    /// Tire une valeur uniforme dans [low, high)
+    pub fn gen_range<T: SampleRange>(&mut self, low: T, high: T) -> T {
+        T::sample(self, low, high)
+    }
+
+    /// Retourne true avec une probabilité `p` (0.0 à 1.0)
+    pub fn chance(&mut self, p: f32) -> bool {
+        self.next_f32() < p
+    }
+}
+
+impl Default for Rng {
+    fn default() -> Self {
+        Self::new(0)
+    }
+}
+
+// -----------------------------------------------------------------------------
+// TIRAGE DANS UN INTERVALLE
+// -----------------------------------------------------------------------------
+pub trait SampleRange: Sized {
+    fn sample(rng: &mut Rng, low: Self, high: Self) -> Self;
+}
+
+impl SampleRange for f32 {
+    fn sample(rng: &mut Rng, low: f32, high: f32) -> f32 {
+        low + (high - low) * rng.next_f32()
+    }
+}
+
+impl SampleRange for i32 {
+    fn sample(rng: &mut Rng, low: i32, high: i32) -> i32 {
+        if high <= low {
+            return low;
+        }
+        let span = (high as i64 - low as i64) as u64;
+        (low as i64 + (rng.next_u64() % span) as i64) as i32
+    }
+}
+
+impl SampleRange for usize {
+    fn sample(rng: &mut Rng, low: usize, high: usize) -> usize {
+        if high <= low {
+            return low;
+        }
+        low + (rng.next_u64() % (high - low) as u64) as usize
+    }
+}
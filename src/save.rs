@@ -0,0 +1,396 @@
+// =============================================================================
+// SAUVEGARDE ET REPRISE D'UNE PARTIE
+// =============================================================================
+//
+// Ce fichier permet de quitter une partie en cours et de la reprendre plus tard :
+// - SaveGame : instantané complet de la simulation (vaisseau, monde, armes,
+//   vagues, combo, générateur aléatoire, replay)
+// - Format texte RON versionné (SAVE_VERSION)
+//
+// Compatibilité :
+// - Les champs ajoutés après coup portent #[serde(default)]
+// - Les anciennes versions passent par `migrate` avant restauration
+// - Les limites d'équilibrage (cooldowns max, munitions) sont réappliquées
+//   au chargement : une sauvegarde reste valide après un rééquilibrage
+//
+// Les effets visuels (particules, traînées, shake) ne sont pas sauvegardés.
+//
+// =============================================================================
+
+use macroquad::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+use crate::arena::{Arena, ArenaMode};
+use crate::camera::Camera;
+use crate::enemies::AsteroidSize;
+use crate::game::{Cooldown, Game, GameState, World};
+use crate::input::InputState;
+use crate::replay::{Replay, ReplayError, GAME_VERSION};
+use crate::rng::Rng;
+use crate::ship::Ship;
+use crate::stats::RunStats;
+use crate::weapons::WeaponSystem;
+
+/// Version du schéma de sauvegarde (à incrémenter à chaque changement de structure)
+pub const SAVE_VERSION: u32 = 3;
+
+/// Emplacement de la partie sauvegardée
+pub const SAVE_PATH: &str = "saves/run.ron";
+
+// -----------------------------------------------------------------------------
+// ERREURS
+// -----------------------------------------------------------------------------
+#[derive(Debug)]
+pub enum SaveError {
+    Io(io::Error),                      // Lecture / écriture impossible
+    Encode(ron::Error),                 // Sérialisation impossible
+    Parse(ron::error::SpannedError),    // Fichier illisible
+    NewerVersion(u32),                  // Sauvegarde d'une version plus récente
+    Replay(ReplayError),                // Replay embarqué corrompu
+}
+
+impl fmt::Display for SaveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SaveError::Io(e) => write!(f, "erreur d'accès à la sauvegarde : {e}"),
+            SaveError::Encode(e) => write!(f, "sauvegarde impossible : {e}"),
+            SaveError::Parse(e) => write!(f, "sauvegarde illisible : {e}"),
+            SaveError::NewerVersion(v) => {
+                write!(f, "sauvegarde v{v} plus récente que le jeu (v{SAVE_VERSION})")
+            }
+            SaveError::Replay(e) => write!(f, "replay de la sauvegarde invalide : {e}"),
+        }
+    }
+}
+
+impl std::error::Error for SaveError {}
+
+impl From<io::Error> for SaveError {
+    fn from(e: io::Error) -> Self {
+        SaveError::Io(e)
+    }
+}
+
+impl From<ron::Error> for SaveError {
+    fn from(e: ron::Error) -> Self {
+        SaveError::Encode(e)
+    }
+}
+
+impl From<ron::error::SpannedError> for SaveError {
+    fn from(e: ron::error::SpannedError) -> Self {
+        SaveError::Parse(e)
+    }
+}
+
+impl From<ReplayError> for SaveError {
+    fn from(e: ReplayError) -> Self {
+        SaveError::Replay(e)
+    }
+}
+
+// -----------------------------------------------------------------------------
+// EN-TÊTE (LU AVANT LE RESTE POUR CONNAÎTRE LA VERSION)
+// -----------------------------------------------------------------------------
+#[derive(Deserialize)]
+struct SaveHeader {
+    version: u32,
+}
+
+// -----------------------------------------------------------------------------
+// INSTANTANÉ DE LA PARTIE
+// -----------------------------------------------------------------------------
+#[derive(Clone, Serialize, Deserialize)]
+pub struct SaveGame {
+    pub version: u32,
+    pub game_version: String,
+
+    // Déterminisme
+    pub seed: u64,
+    pub arena: Vec2,
+    #[serde(default)]
+    pub arena_mode: ArenaMode,
+    #[serde(default)]
+    pub arena_view: Option<Vec2>,   // Absente : arène entièrement visible
+    pub rng: Rng,
+    pub last_input: InputState,
+
+    // État de la partie
+    pub timer: f32,
+    pub score: i32,
+    pub combo: i32,
+    pub combo_timer: Cooldown,
+
+    // Vagues
+    pub wave: i32,
+    pub enemies_to_spawn: i32,
+    pub enemies_killed: i32,
+    #[serde(default)]
+    pub boss_spawned: bool,
+
+    // Sous-systèmes
+    pub ship: Ship,
+    pub world: World,
+    pub weapons: WeaponSystem,
+    #[serde(default)]
+    pub camera: Camera,
+
+    // Replay de la partie jusqu'ici (format binaire de replay.rs)
+    #[serde(default)]
+    pub replay: Vec<u8>,
+
+    // Statistiques de la partie jusqu'ici
+    #[serde(default)]
+    pub stats: RunStats,
+}
+
+impl SaveGame {
+    /// Capture l'état complet de la partie
+    pub fn capture(game: &Game) -> Self {
+        Self {
+            version: SAVE_VERSION,
+            game_version: GAME_VERSION.to_string(),
+            seed: game.seed,
+            arena: game.arena.size,
+            arena_mode: game.arena.mode,
+            arena_view: Some(game.arena.view),
+            rng: game.rng.clone(),
+            last_input: game.last_input,
+            timer: game.timer,
+            score: game.score,
+            combo: game.combo,
+            combo_timer: game.combo_timer,
+            wave: game.wave,
+            enemies_to_spawn: game.enemies_to_spawn,
+            enemies_killed: game.enemies_killed,
+            boss_spawned: game.boss_spawned,
+            ship: game.ship.clone(),
+            world: game.world.clone(),
+            weapons: game.weapons.clone(),
+            camera: game.camera,
+            replay: game.replay.to_bytes(),
+            stats: game.stats.clone(),
+        }
+    }
+
+    /// Restaure la partie sauvegardée dans `game` (prête à jouer)
+    pub fn restore(self, game: &mut Game) -> Result<(), SaveError> {
+        let arena = Arena::new(self.arena, self.arena_mode)
+            .with_view(self.arena_view.unwrap_or(self.arena));
+        let replay = if self.replay.is_empty() {
+            Replay::new(self.seed, arena)
+        } else {
+            Replay::from_bytes(&self.replay)?
+        };
+
+        game.arena = arena;
+        game.reset(self.seed);
+        game.rng = self.rng;
+        game.last_input = self.last_input;
+        game.timer = self.timer;
+        game.score = self.score;
+        game.combo = self.combo;
+        game.combo_timer = self.combo_timer;
+        game.wave = self.wave;
+        game.enemies_to_spawn = self.enemies_to_spawn;
+        game.enemies_killed = self.enemies_killed;
+        game.boss_spawned = self.boss_spawned;
+        game.ship = self.ship;
+        game.world = self.world;
+        game.weapons = self.weapons;
+        game.camera = self.camera;
+        game.replay = replay;
+        game.stats = self.stats;
+        game.state = GameState::Playing;
+
+        // Limites d'équilibrage de la version actuelle
+        game.ship.rebalance(&game.balance.ship);
+        game.weapons.rebalance(&game.balance.weapons);
+        Ok(())
+    }
+
+    // -------------------------------------------------------------------------
+    // FORMAT TEXTE
+    // -------------------------------------------------------------------------
+
+    /// Sérialise la sauvegarde en RON
+    pub fn to_ron(&self) -> Result<String, SaveError> {
+        Ok(ron::ser::to_string_pretty(self, ron::ser::PrettyConfig::default())?)
+    }
+
+    /// Désérialise une sauvegarde (en migrant les anciennes versions)
+    pub fn from_ron(text: &str) -> Result<Self, SaveError> {
+        let header: SaveHeader = ron::from_str(text)?;
+        if header.version > SAVE_VERSION {
+            return Err(SaveError::NewerVersion(header.version));
+        }
+
+        let mut save: SaveGame = ron::from_str(text)?;
+        migrate(&mut save, header.version);
+        Ok(save)
+    }
+
+    // -------------------------------------------------------------------------
+    // FICHIER
+    // -------------------------------------------------------------------------
+
+    /// Écrit la sauvegarde sur disque (crée le dossier si nécessaire)
+    pub fn write(&self, path: impl AsRef<Path>) -> Result<(), SaveError> {
+        let path = path.as_ref();
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(path, self.to_ron()?)?;
+        Ok(())
+    }
+
+    /// Lit une sauvegarde depuis le disque
+    pub fn read(path: impl AsRef<Path>) -> Result<Self, SaveError> {
+        Self::from_ron(&fs::read_to_string(path)?)
+    }
+
+    /// Vérifie si une sauvegarde existe
+    pub fn exists(path: impl AsRef<Path>) -> bool {
+        path.as_ref().is_file()
+    }
+
+    /// Supprime la sauvegarde (partie terminée)
+    pub fn delete(path: impl AsRef<Path>) {
+        let _ = fs::remove_file(path);
+    }
+}
+
+// -----------------------------------------------------------------------------
+// MIGRATIONS
+// -----------------------------------------------------------------------------
+
+/// Met à niveau une sauvegarde lue depuis une version antérieure du schéma
+/// Chaque changement de SAVE_VERSION ajoute ici l'étape `if from < N { ... }`
+/// (v1 : schéma initial)
+/// v2 : collections du monde en SlotMap ; les listes v1 sont relues
+/// directement par SlotMap (nouveaux ID) et les balles v1 n'ont pas de
+/// propriétaire, rien d'autre à migrer
+/// v3 : tailles d'astéroïdes, déduites du rayon
+fn migrate(save: &mut SaveGame, from: u32) {
+    if from < 3 {
+        for asteroid in save.world.asteroids.iter_mut() {
+            asteroid.size = AsteroidSize::from_radius(asteroid.radius);
+        }
+    }
+    save.version = SAVE_VERSION;
+}
+
+// =============================================================================
+// TESTS
+// =============================================================================
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::consts::SIM_DT;
+
+    fn new_game(seed: u64) -> Game {
+        let mut game = Game::new(Arena::new(vec2(800., 600.), ArenaMode::Bounded), seed);
+        game.reset(seed);
+        game
+    }
+
+    /// Entrées scriptées : tir continu, virages et poussée alternés
+    fn input_at(tick: usize) -> InputState {
+        let step = tick / 20;
+        InputState {
+            thrust: step % 3 != 1,
+            turn_left: step % 4 == 1,
+            shoot: true,
+            missile: step % 5 == 2,
+            ..InputState::new()
+        }
+    }
+
+    /// Remplace le champ `field:( ... )` d'un RON compact par `value`
+    fn replace_field(text: &str, field: &str, value: &str) -> String {
+        let start = text.find(&format!("{field}:(")).expect("champ absent");
+        let open = start + field.len() + 1;
+        let mut depth = 0;
+        let mut end = open;
+        for (i, c) in text[open..].char_indices() {
+            match c {
+                '(' | '[' => depth += 1,
+                ')' | ']' => depth -= 1,
+                _ => {}
+            }
+            if depth == 0 {
+                end = open + i + 1;
+                break;
+            }
+        }
+        format!("{}{field}:{value}{}", &text[..start], &text[end..])
+    }
+
+    #[test]
+    fn v1_save_migrates_asteroid_sizes() {
+        let mut game = new_game(3);
+        game.world = World::new();
+        let text = ron::to_string(&SaveGame::capture(&game)).unwrap();
+        let text = text.replacen(&format!("version:{SAVE_VERSION}"), "version:1", 1);
+        // Monde v1 : simples listes, astéroïdes sans taille
+        let text = replace_field(
+            &text,
+            "world",
+            "(asteroids:[\
+                (pos:(100.0,100.0),prev_pos:(100.0,100.0),vel:(10.0,0.0),radius:45.0,rotation:0.0,rotation_speed:1.0),\
+                (pos:(300.0,200.0),prev_pos:(300.0,200.0),vel:(0.0,10.0),radius:12.0,rotation:0.0,rotation_speed:1.0)\
+            ],drones:[],bullets:[],powerups:[])",
+        );
+
+        let save = SaveGame::from_ron(&text).expect("sauvegarde v1 illisible");
+        assert_eq!(save.version, SAVE_VERSION);
+        let sizes: Vec<AsteroidSize> = save.world.asteroids.iter().map(|a| a.size).collect();
+        assert_eq!(sizes, vec![AsteroidSize::from_radius(45.0), AsteroidSize::from_radius(12.0)]);
+        assert_ne!(sizes[0], sizes[1]);
+
+        let mut restored = new_game(0);
+        save.restore(&mut restored).unwrap();
+        assert_eq!(restored.world.asteroids.len(), 2);
+    }
+
+    #[test]
+    fn newer_version_is_rejected() {
+        let text = SaveGame::capture(&new_game(1)).to_ron().unwrap();
+        let text = text.replacen(&format!("version: {SAVE_VERSION}"), "version: 99", 1);
+        assert!(matches!(SaveGame::from_ron(&text), Err(SaveError::NewerVersion(99))));
+    }
+
+    #[test]
+    fn restored_game_continues_like_the_original() {
+        let mut original = new_game(11);
+        for tick in 0..600 {
+            original.update(SIM_DT, &input_at(tick));
+        }
+        let text = SaveGame::capture(&original).to_ron().unwrap();
+        let mut restored = new_game(0);
+        SaveGame::from_ron(&text).unwrap().restore(&mut restored).unwrap();
+
+        for tick in 600..1200 {
+            original.update(SIM_DT, &input_at(tick));
+            restored.update(SIM_DT, &input_at(tick));
+        }
+        assert_eq!(restored.score, original.score);
+        assert_eq!(restored.wave, original.wave);
+        assert_eq!(restored.ship.pos, original.ship.pos);
+        assert_eq!(restored.ship.lives, original.ship.lives);
+        let positions = |game: &Game| -> Vec<Vec2> {
+            game.world
+                .asteroids
+                .iter()
+                .map(|a| a.pos)
+                .chain(game.world.drones.iter().map(|d| d.pos))
+                .chain(game.world.bullets.iter().map(|b| b.pos))
+                .collect()
+        };
+        assert_eq!(positions(&restored), positions(&original));
+        assert_eq!(restored.replay.len(), original.replay.len());
+    }
+}
@@ -7,51 +7,172 @@
 // - GameScene : partie en cours
 // - PauseScene : jeu en pause
 // - GameOverScene : fin de partie
+// - ReplayScene : relecture d'une partie enregistrée
+// - BindingsScene : configuration des touches
 //
 // =============================================================================
 
 use macroquad::prelude::*;
 use crate::consts::*;
+use crate::arena::{Arena, ArenaMode};
+use crate::balance::BalanceConfig;
+use crate::bindings::{is_bindable, key_name, Action, ControlScheme, KeyboardLayout};
 use crate::game::*;
 use crate::rendering::Renderer;
 use crate::ui::UI;
-use crate::events::EventQueue;
+use crate::events::EventListener;
+use crate::input::InputState;
+use crate::replay::{Replay, LAST_REPLAY_PATH};
+#[cfg(not(target_arch = "wasm32"))]
+use crate::replay::REPLAY_DIR;
+use crate::save::{SaveGame, SAVE_PATH};
+use crate::scores::{format_date, ScoreEntry, MAX_NAME_LEN};
+use crate::settings::SETTINGS_PATH;
 
 // -----------------------------------------------------------------------------
 // TRAIT SCENE - INTERFACE COMMUNE À TOUTES LES SCÈNES
 // -----------------------------------------------------------------------------
 pub trait Scene {
     /// Met à jour la logique de la scène
+    /// `listeners` : abonnés externes aux événements du gameplay (audio...)
     /// Retourne Option<Box<dyn Scene>> pour changer de scène
-    fn update(&mut self, game: &mut Game, events: &mut EventQueue, dt: f32) -> Option<Box<dyn Scene>>;
+    fn update(&mut self, game: &mut Game, listeners: &mut dyn EventListener, dt: f32) -> Option<Box<dyn Scene>>;
     
     /// Dessine la scène
     fn draw(&self, game: &mut Game);  // &mut Game car on modifie le screen shake
+
+    /// La musique de partie joue-t-elle pendant cette scène ?
+    fn plays_music(&self) -> bool {
+        false
+    }
+}
+
+/// Monde de WORLD_SCREENS fenêtres de côté, vu à travers la fenêtre actuelle
+fn window_arena(mode: ArenaMode) -> Arena {
+    let window = vec2(screen_width(), screen_height());
+    Arena::new(window * WORLD_SCREENS, mode).with_view(window)
+}
+
+/// Nouvelle graine de partie (dérivée de l'horloge système)
+pub fn fresh_seed() -> u64 {
+    (miniquad::date::now() * 1000.0) as u64
+}
+
+/// Sauvegarde le replay de la partie qui vient de se terminer
+/// (dernier replay + copie nommée d'après la graine)
+/// Pas de système de fichiers sur le web : ni replay ni partie sauvegardés
+#[cfg(not(target_arch = "wasm32"))]
+fn save_run_replay(game: &Game) {
+    if game.replay.is_empty() {
+        return;
+    }
+    let named = format!("{}/run-{}.vsr", REPLAY_DIR, game.replay.seed);
+    for path in [LAST_REPLAY_PATH, named.as_str()] {
+        if let Err(e) = game.replay.save(path) {
+            eprintln!("Impossible d'écrire le replay {path} : {e}");
+        }
+    }
+}
+
+/// Sauvegarde la partie en cours pour pouvoir la reprendre depuis le menu
+#[cfg(not(target_arch = "wasm32"))]
+fn save_run(game: &Game) {
+    if let Err(e) = SaveGame::capture(game).write(SAVE_PATH) {
+        eprintln!("Impossible de sauvegarder la partie : {e}");
+    }
 }
 
 // -----------------------------------------------------------------------------
 // SCÈNE 1 : MENU PRINCIPAL
 // -----------------------------------------------------------------------------
-pub struct MenuScene;
+pub struct MenuScene {
+    status: Option<String>,     // Message affiché (ex : replay introuvable)
+    can_continue: bool,         // Une partie sauvegardée est disponible
+    show_scores: bool,          // Affiche le classement au lieu des contrôles
+}
 
 impl MenuScene {
     pub fn new() -> Self {
-        Self
+        Self {
+            status: None,
+            can_continue: SaveGame::exists(SAVE_PATH),
+            show_scores: false,
+        }
+    }
+
+    /// Menu affichant un message (ex : erreur d'enregistrement)
+    pub fn with_status(status: String) -> Self {
+        Self {
+            status: Some(status),
+            ..Self::new()
+        }
+    }
+}
+
+impl Default for MenuScene {
+    fn default() -> Self {
+        Self::new()
     }
 }
 
 impl Scene for MenuScene {
-    fn update(&mut self, game: &mut Game, _events: &mut EventQueue, _dt: f32) -> Option<Box<dyn Scene>> {
+    fn update(&mut self, game: &mut Game, _listeners: &mut dyn EventListener, _dt: f32) -> Option<Box<dyn Scene>> {
         // Appuyer sur ENTREE pour commencer
         if is_key_pressed(KEY_START) {
-            game.reset();
+            game.arena = window_arena(game.settings.arena_mode);
+            game.reset(fresh_seed());
             return Some(Box::new(GameScene::new()));
         }
+        // Changer le mode de l'arène (enregistré dans les réglages)
+        if is_key_pressed(KEY_ARENA_MODE) {
+            game.settings.arena_mode = game.settings.arena_mode.next();
+            #[cfg(not(target_arch = "wasm32"))]
+            if let Err(e) = game.settings.save(SETTINGS_PATH) {
+                self.status = Some(e.to_string());
+            }
+        }
+        // Basculer entre contrôles et classement
+        if is_key_pressed(KEY_SCORES) {
+            self.show_scores = !self.show_scores;
+        }
+        // Configurer les touches
+        if is_key_pressed(KEY_BINDINGS) {
+            return Some(Box::new(BindingsScene::new()));
+        }
+        // Reprendre la partie sauvegardée
+        if self.can_continue && is_key_pressed(KEY_CONTINUE) {
+            match SaveGame::read(SAVE_PATH).and_then(|save| save.restore(game)) {
+                Ok(()) => return Some(Box::new(PauseScene::new())),
+                Err(e) => self.status = Some(e.to_string()),
+            }
+        }
+        // Revoir la dernière partie enregistrée
+        if is_key_pressed(KEY_REPLAY) {
+            match Replay::load(LAST_REPLAY_PATH) {
+                Ok(replay) => {
+                    return Some(Box::new(ReplayScene::new(replay, game.balance.clone())));
+                }
+                Err(e) => self.status = Some(e.to_string()),
+            }
+        }
         None
     }
 
     fn draw(&self, game: &mut Game) {
-        UI::draw_menu(game.high_score);
+        if self.show_scores {
+            UI::draw_leaderboard(game.scores.entries());
+        } else {
+            UI::draw_menu(
+                game.high_score(),
+                self.can_continue,
+                &game.settings.bindings,
+                game.settings.control_scheme,
+                game.settings.arena_mode,
+            );
+        }
+        if let Some(status) = &self.status {
+            UI::draw_menu_status(status);
+        }
     }
 }
 
@@ -70,26 +191,41 @@ impl GameScene {
     }
 }
 
+impl Default for GameScene {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl Scene for GameScene {
-    fn update(&mut self, game: &mut Game, events: &mut EventQueue, dt: f32) -> Option<Box<dyn Scene>> {
+    fn update(&mut self, game: &mut Game, listeners: &mut dyn EventListener, dt: f32) -> Option<Box<dyn Scene>> {
         // Vérifier les entrées de changement de scène
-        if is_key_pressed(KEY_PAUSE) {
+        if game.settings.bindings.pressed(Action::Pause) {
             return Some(Box::new(PauseScene::new()));
         }
         if is_key_pressed(KEY_MENU) {
+            #[cfg(not(target_arch = "wasm32"))]
+            {
+                save_run_replay(game);
+                save_run(game);
+            }
             return Some(Box::new(MenuScene::new()));
         }
 
-        // Mise à jour du jeu
-        game.update(dt);
-        events.process(game);
+        // Mise à jour du jeu (ticks fixes)
+        let mut input = InputState::from_keyboard(&game.settings.bindings);
+        if game.settings.control_scheme == ControlScheme::TwinStick {
+            input = input.with_mouse(self.renderer.world_to_screen(game, game.ship.pos));
+        }
+        game.advance(dt, &input);
+        game.dispatch_events(listeners);
         
         // Vérifier si la partie est terminée
         if game.state == GameState::GameOver {
-            if game.score > game.high_score {
-                game.high_score = game.score;
-            }
-            return Some(Box::new(GameOverScene::new()));
+            #[cfg(not(target_arch = "wasm32"))]
+            save_run_replay(game);
+            SaveGame::delete(SAVE_PATH);
+            return Some(Box::new(GameOverScene::new(game)));
         }
 
         None
@@ -99,6 +235,13 @@ impl Scene for GameScene {
         // Récupérer le vecteur de screen shake
         let shake_vec = game.shake.update(0.0);  // dt déjà appliqué dans update
         self.renderer.draw_game(game, shake_vec);
+        if game.settings.control_scheme == ControlScheme::TwinStick {
+            UI::draw_crosshair();
+        }
+    }
+
+    fn plays_music(&self) -> bool {
+        true
     }
 }
 
@@ -113,14 +256,25 @@ impl PauseScene {
     }
 }
 
+impl Default for PauseScene {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl Scene for PauseScene {
-    fn update(&mut self, _game: &mut Game, _events: &mut EventQueue, _dt: f32) -> Option<Box<dyn Scene>> {
+    fn update(&mut self, game: &mut Game, _listeners: &mut dyn EventListener, _dt: f32) -> Option<Box<dyn Scene>> {
         // Reprendre la partie
-        if is_key_pressed(KEY_PAUSE) {
+        if game.settings.bindings.pressed(Action::Pause) {
             return Some(Box::new(GameScene::new()));
         }
-        // Retour au menu
+        // Retour au menu (la partie est sauvegardée)
         if is_key_pressed(KEY_MENU) {
+            #[cfg(not(target_arch = "wasm32"))]
+            {
+                save_run_replay(game);
+                save_run(game);
+            }
             return Some(Box::new(MenuScene::new()));
         }
         None
@@ -132,30 +286,95 @@ impl Scene for PauseScene {
         renderer.draw_game(game, Vec2::ZERO);
         
         // Dessiner l'écran de pause par-dessus
-        UI::draw_pause_screen(game.score);
+        UI::draw_pause_screen(game.score, &game.settings.bindings);
     }
 }
 
 // -----------------------------------------------------------------------------
 // SCÈNE 4 : GAME OVER
 // -----------------------------------------------------------------------------
-pub struct GameOverScene;
+pub struct GameOverScene {
+    is_record: bool,            // Meilleur score jamais réalisé
+    name: Option<String>,       // Saisie du nom en cours (score classé)
+    rank: Option<usize>,        // Place obtenue dans le classement
+    status: Option<String>,     // Erreur d'enregistrement éventuelle
+}
 
 impl GameOverScene {
-    pub fn new() -> Self {
-        Self
+    pub fn new(game: &Game) -> Self {
+        let qualifies = game.scores.qualifies(game.score);
+        if qualifies {
+            // macroquad garde les caractères tapés pendant la partie : sans
+            // ce vidage, la touche de tir remplirait le nom
+            clear_input_queue();
+        }
+        Self {
+            is_record: game.score > game.high_score(),
+            name: qualifies.then(String::new),
+            rank: None,
+            status: None,
+        }
+    }
+
+    /// Gère la saisie du nom ; retourne true quand elle est terminée
+    fn update_name_entry(&mut self, game: &mut Game) -> bool {
+        let Some(name) = self.name.as_mut() else {
+            return true;
+        };
+
+        while let Some(c) = get_char_pressed() {
+            if (c.is_alphanumeric() || c == ' ' || c == '-' || c == '_')
+                && name.chars().count() < MAX_NAME_LEN
+            {
+                name.push(c.to_ascii_uppercase());
+            }
+        }
+        if is_key_pressed(KeyCode::Backspace) {
+            name.pop();
+        }
+
+        // Échap : ne pas enregistrer le score
+        if is_key_pressed(KEY_MENU) {
+            self.name = None;
+            return true;
+        }
+
+        if is_key_pressed(KEY_START) {
+            let name = name.trim();
+            let entry = ScoreEntry {
+                name: if name.is_empty() { "ANONYME".to_string() } else { name.to_string() },
+                score: game.score,
+                wave: game.wave,
+                survival_time: game.timer,
+                date: format_date(miniquad::date::now()),
+                seed: game.seed,
+            };
+            match game.scores.submit(entry) {
+                Ok(rank) => self.rank = rank,
+                Err(e) => self.status = Some(e.to_string()),
+            }
+            self.name = None;
+            return true;
+        }
+        false
     }
 }
 
 impl Scene for GameOverScene {
-    fn update(&mut self, game: &mut Game, events: &mut EventQueue, dt: f32) -> Option<Box<dyn Scene>> {
+    fn update(&mut self, game: &mut Game, _listeners: &mut dyn EventListener, dt: f32) -> Option<Box<dyn Scene>> {
         // Mise à jour des effets visuels (particules d'explosion)
         game.effects.update(dt);
-        events.process(game);
+
+        // Saisie du nom : les touches ne servent qu'au champ de texte
+        if self.name.is_some() {
+            self.update_name_entry(game);
+            return None;
+        }
         
         // Recommencer
         if is_key_pressed(KEY_START) {
-            game.reset();
+            game.arena = window_arena(game.settings.arena_mode);
+            game.reset(fresh_seed());
             return Some(Box::new(GameScene::new()));
         }
         // Retour au menu
@@ -171,9 +390,230 @@ impl Scene for GameOverScene {
         renderer.draw_game(game, Vec2::ZERO);
         
         // Dessiner l'écran de game over
-        UI::draw_game_over_screen(game.score, game.high_score, game.wave, 0.0);
+        UI::draw_game_over_screen(
+            game.score,
+            self.is_record,
+            game.wave,
+            &game.stats,
+            self.name.as_deref(),
+            self.rank,
+            0.0,
+        );
+        if let Some(status) = &self.status {
+            UI::draw_menu_status(status);
+        }
         
         // Dessiner les particules par-dessus (explosions)
+        renderer.set_world_camera(game);
         renderer.draw_particles(&game.effects.particles);
+        set_default_camera();
+    }
+}
+
+// -----------------------------------------------------------------------------
+// SCÈNE 5 : RELECTURE D'UN REPLAY
+// -----------------------------------------------------------------------------
+//
+// Rejoue les entrées enregistrées tick par tick dans une partie indépendante
+// (même graine, même arène) : la partie du joueur n'est pas touchée.
+// L'équilibrage n'est pas enregistré : on rejoue avec la configuration actuelle.
+pub struct ReplayScene {
+    game: Game,
+    replay: Replay,
+    tick: usize,        // Prochain tick à rejouer
+    paused: bool,
+    speed: f32,         // Multiplicateur de vitesse (1x, 2x, 4x)
+    renderer: Renderer,
+}
+
+impl ReplayScene {
+    pub fn new(replay: Replay, balance: BalanceConfig) -> Self {
+        let mut game = Game::new(replay.arena, replay.seed);
+        game.balance = balance;
+        game.reset(replay.seed);
+        Self {
+            game,
+            replay,
+            tick: 0,
+            paused: false,
+            speed: 1.0,
+            renderer: Renderer::new(),
+        }
+    }
+
+    /// Rejoue un tick ; retourne false si le replay est terminé
+    fn step(&mut self) -> bool {
+        match self.replay.input_at(self.tick) {
+            Some(input) => {
+                self.game.update(SIM_DT, &input);
+                self.tick += 1;
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+impl Scene for ReplayScene {
+    fn update(&mut self, game: &mut Game, listeners: &mut dyn EventListener, dt: f32) -> Option<Box<dyn Scene>> {
+        if is_key_pressed(KEY_MENU) {
+            return Some(Box::new(MenuScene::new()));
+        }
+        if game.settings.bindings.pressed(Action::Pause) {
+            self.paused = !self.paused;
+        }
+        if is_key_pressed(KEY_REPLAY_1X) {
+            self.speed = 1.0;
+        }
+        if is_key_pressed(KEY_REPLAY_2X) {
+            self.speed = 2.0;
+        }
+        if is_key_pressed(KEY_REPLAY_4X) {
+            self.speed = 4.0;
+        }
+
+        if self.paused {
+            // Avance image par image
+            if is_key_pressed(KEY_REPLAY_STEP) {
+                self.step();
+            }
+        } else {
+            // Même cadence que la partie d'origine (slow-mo compris), accélérée
+            self.game.clock.accumulate(dt * self.speed);
+            while self.game.clock.consume(self.game.tick_cost()) {
+                if !self.step() {
+                    break;
+                }
+            }
+        }
+
+        // La partie rejouée a son propre bus : mêmes effets et mêmes sons
+        self.game.dispatch_events(listeners);
+        None
+    }
+
+    fn draw(&self, game: &mut Game) {
+        self.renderer.draw_game(&self.game, Vec2::ZERO);
+        UI::draw_replay_overlay(
+            self.tick,
+            self.replay.len(),
+            self.speed,
+            self.paused,
+            self.replay.matches_game_version(),
+            &game.settings.bindings,
+        );
+    }
+
+    fn plays_music(&self) -> bool {
+        true
+    }
+}
+
+// -----------------------------------------------------------------------------
+// SCÈNE 6 : CONFIGURATION DES TOUCHES
+// -----------------------------------------------------------------------------
+//
+// Modifie directement game.settings ; les réglages sont enregistrés en
+// quittant l'écran (Échap).
+pub struct BindingsScene {
+    selected: usize,            // Index dans Action::ALL
+    capturing: bool,            // En attente de la nouvelle touche
+    status: Option<String>,     // Conflit ou touche refusée
+}
+
+impl BindingsScene {
+    pub fn new() -> Self {
+        Self {
+            selected: 0,
+            capturing: false,
+            status: None,
+        }
+    }
+
+    /// Attend la touche à ajouter à l'action sélectionnée
+    fn capture_key(&mut self, game: &mut Game) {
+        // Échap annule la saisie
+        if is_key_pressed(KEY_MENU) {
+            self.capturing = false;
+            return;
+        }
+        let Some(key) = get_last_key_pressed() else {
+            return;
+        };
+
+        let action = Action::ALL[self.selected];
+        self.capturing = false;
+        self.status = if !is_bindable(key) {
+            Some(format!("Touche non prise en charge ({key:?})"))
+        } else {
+            match game.settings.bindings.bind(action, key) {
+                Ok(()) => None,
+                Err(conflict) => Some(format!("{} refusée : {conflict}", key_name(key))),
+            }
+        };
+    }
+}
+
+impl Default for BindingsScene {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Scene for BindingsScene {
+    fn update(&mut self, game: &mut Game, _listeners: &mut dyn EventListener, _dt: f32) -> Option<Box<dyn Scene>> {
+        if self.capturing {
+            self.capture_key(game);
+            return None;
+        }
+
+        let count = Action::ALL.len();
+        if is_key_pressed(KEY_MENU_UP) {
+            self.selected = (self.selected + count - 1) % count;
+        }
+        if is_key_pressed(KEY_MENU_DOWN) {
+            self.selected = (self.selected + 1) % count;
+        }
+        if is_key_pressed(KEY_START) {
+            self.capturing = true;
+            self.status = None;
+        }
+        if is_key_pressed(KEY_CLEAR_BINDING) {
+            game.settings.bindings.clear(Action::ALL[self.selected]);
+        }
+        for (key, layout) in [
+            (KEY_PRESET_QWERTY, KeyboardLayout::Qwerty),
+            (KEY_PRESET_AZERTY, KeyboardLayout::Azerty),
+        ] {
+            if is_key_pressed(key) {
+                game.settings.apply_preset(layout);
+                self.status = Some(format!("Préréglage {} appliqué", layout.label()));
+            }
+        }
+        if is_key_pressed(KEY_TOGGLE_SCHEME) {
+            game.settings.control_scheme = game.settings.control_scheme.next();
+            self.status = Some(format!("Contrôle : {}", game.settings.control_scheme.label()));
+        }
+
+        // Enregistrer et revenir au menu
+        if is_key_pressed(KEY_MENU) {
+            #[cfg(not(target_arch = "wasm32"))]
+            if let Err(e) = game.settings.save(SETTINGS_PATH) {
+                return Some(Box::new(MenuScene::with_status(e.to_string())));
+            }
+            return Some(Box::new(MenuScene::new()));
+        }
+        None
+    }
+
+    fn draw(&self, game: &mut Game) {
+        UI::draw_bindings_screen(
+            &game.settings.bindings,
+            game.settings.layout,
+            game.settings.control_scheme,
+            self.selected,
+            self.capturing,
+            self.status.as_deref(),
+        );
     }
 }
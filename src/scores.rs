@@ -0,0 +1,209 @@
+// =============================================================================
+// TABLEAU DES MEILLEURS SCORES
+// =============================================================================
+//
+// Ce fichier gère le classement local des meilleures parties :
+// - ScoreEntry : une ligne du tableau (nom, score, vague, durée, date, graine)
+// - ScoreStore : interface de stockage (le jeu ne connaît que ce trait)
+// - MemoryScoreStore : tableau en mémoire (web, tests, outils)
+// - FileScoreStore : tableau persistant dans un fichier RON (natif)
+//
+// Le tableau est toujours trié par score décroissant et limité à
+// LEADERBOARD_SIZE entrées.
+//
+// =============================================================================
+
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// Nombre d'entrées conservées dans le classement
+pub const LEADERBOARD_SIZE: usize = 10;
+
+/// Longueur maximale d'un nom de joueur
+pub const MAX_NAME_LEN: usize = 12;
+
+/// Emplacement du classement (builds natifs)
+pub const SCORES_PATH: &str = "saves/scores.ron";
+
+// -----------------------------------------------------------------------------
+// ENTRÉE DU CLASSEMENT
+// -----------------------------------------------------------------------------
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ScoreEntry {
+    pub name: String,
+    pub score: i32,
+    pub wave: i32,              // Vague atteinte
+    pub survival_time: f32,     // Durée de la partie (Game::timer, secondes)
+    pub date: String,           // Date de la partie (AAAA-MM-JJ)
+    pub seed: u64,              // Graine (permet de rejouer la même partie)
+}
+
+// -----------------------------------------------------------------------------
+// ERREURS
+// -----------------------------------------------------------------------------
+#[derive(Debug)]
+pub enum ScoreError {
+    Io(io::Error),                      // Lecture / écriture impossible
+    Encode(ron::Error),                 // Sérialisation impossible
+    Parse(ron::error::SpannedError),    // Fichier illisible
+}
+
+impl fmt::Display for ScoreError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ScoreError::Io(e) => write!(f, "erreur d'accès aux scores : {e}"),
+            ScoreError::Encode(e) => write!(f, "écriture des scores impossible : {e}"),
+            ScoreError::Parse(e) => write!(f, "fichier des scores illisible : {e}"),
+        }
+    }
+}
+
+impl std::error::Error for ScoreError {}
+
+impl From<io::Error> for ScoreError {
+    fn from(e: io::Error) -> Self {
+        ScoreError::Io(e)
+    }
+}
+
+impl From<ron::Error> for ScoreError {
+    fn from(e: ron::Error) -> Self {
+        ScoreError::Encode(e)
+    }
+}
+
+impl From<ron::error::SpannedError> for ScoreError {
+    fn from(e: ron::error::SpannedError) -> Self {
+        ScoreError::Parse(e)
+    }
+}
+
+// -----------------------------------------------------------------------------
+// INTERFACE DE STOCKAGE
+// -----------------------------------------------------------------------------
+pub trait ScoreStore {
+    /// Entrées triées par score décroissant
+    fn entries(&self) -> &[ScoreEntry];
+
+    /// Ajoute une entrée ; retourne son rang (0 = premier) si elle est classée
+    fn submit(&mut self, entry: ScoreEntry) -> Result<Option<usize>, ScoreError>;
+
+    /// Vérifie si un score entrerait dans le classement
+    fn qualifies(&self, score: i32) -> bool {
+        score > 0
+            && (self.entries().len() < LEADERBOARD_SIZE
+                || self.entries().iter().any(|e| score > e.score))
+    }
+
+    /// Meilleur score enregistré (0 si le tableau est vide)
+    fn best(&self) -> i32 {
+        self.entries().first().map_or(0, |e| e.score)
+    }
+}
+
+/// Insère une entrée à sa place et tronque le tableau
+/// Retourne le rang de l'entrée, ou None si elle n'est pas classée
+fn insert_ranked(entries: &mut Vec<ScoreEntry>, entry: ScoreEntry) -> Option<usize> {
+    // À score égal, la partie la plus ancienne garde la meilleure place
+    let rank = entries.iter().position(|e| entry.score > e.score).unwrap_or(entries.len());
+    if rank >= LEADERBOARD_SIZE {
+        return None;
+    }
+    entries.insert(rank, entry);
+    entries.truncate(LEADERBOARD_SIZE);
+    Some(rank)
+}
+
+// -----------------------------------------------------------------------------
+// STOCKAGE EN MÉMOIRE
+// -----------------------------------------------------------------------------
+#[derive(Clone, Debug, Default)]
+pub struct MemoryScoreStore {
+    entries: Vec<ScoreEntry>,
+}
+
+impl MemoryScoreStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl ScoreStore for MemoryScoreStore {
+    fn entries(&self) -> &[ScoreEntry] {
+        &self.entries
+    }
+
+    fn submit(&mut self, entry: ScoreEntry) -> Result<Option<usize>, ScoreError> {
+        Ok(insert_ranked(&mut self.entries, entry))
+    }
+}
+
+// -----------------------------------------------------------------------------
+// STOCKAGE FICHIER (NATIF)
+// -----------------------------------------------------------------------------
+pub struct FileScoreStore {
+    path: PathBuf,
+    entries: Vec<ScoreEntry>,
+}
+
+impl FileScoreStore {
+    /// Ouvre le classement stocké dans `path` (vide si le fichier n'existe pas)
+    pub fn open(path: impl AsRef<Path>) -> Result<Self, ScoreError> {
+        let path = path.as_ref().to_path_buf();
+        let mut entries: Vec<ScoreEntry> = match fs::read_to_string(&path) {
+            Ok(text) => ron::from_str(&text)?,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Vec::new(),
+            Err(e) => return Err(e.into()),
+        };
+        entries.sort_by_key(|e| std::cmp::Reverse(e.score));
+        entries.truncate(LEADERBOARD_SIZE);
+        Ok(Self { path, entries })
+    }
+
+    /// Réécrit le fichier complet
+    fn flush(&self) -> Result<(), ScoreError> {
+        if let Some(dir) = self.path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        let text = ron::ser::to_string_pretty(&self.entries, ron::ser::PrettyConfig::default())?;
+        fs::write(&self.path, text)?;
+        Ok(())
+    }
+}
+
+impl ScoreStore for FileScoreStore {
+    fn entries(&self) -> &[ScoreEntry] {
+        &self.entries
+    }
+
+    fn submit(&mut self, entry: ScoreEntry) -> Result<Option<usize>, ScoreError> {
+        let rank = insert_ranked(&mut self.entries, entry);
+        if rank.is_some() {
+            self.flush()?;
+        }
+        Ok(rank)
+    }
+}
+
+// -----------------------------------------------------------------------------
+// DATE
+// -----------------------------------------------------------------------------
+
+/// Formate un horodatage Unix (secondes) en date AAAA-MM-JJ (UTC)
+pub fn format_date(unix_secs: f64) -> String {
+    // Algorithme "civil from days" (H. Hinnant)
+    let days = (unix_secs / 86_400.0).floor() as i64;
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + if month <= 2 { 1 } else { 0 };
+    format!("{year:04}-{month:02}-{day:02}")
+}
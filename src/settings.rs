@@ -0,0 +1,150 @@
+// =============================================================================
+// RÉGLAGES DU JOUEUR
+// =============================================================================
+//
+// Ce fichier gère les préférences conservées d'une session à l'autre :
+// - Settings : disposition du clavier, touches de chaque action, schéma
+//   de contrôle (rotation au clavier ou visée à la souris), volumes et
+//   mode de l'arène (bornée ou torique)
+// - Fichier RON versionné (saves/settings.ron) écrit en quittant l'écran
+//   des touches, ou en changeant le mode de l'arène depuis le menu
+//
+// Un fichier absent donne les réglages par défaut ; un fichier en conflit
+// (même touche pour deux actions) est refusé au chargement.
+//
+// =============================================================================
+
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+use crate::arena::ArenaMode;
+use crate::audio::Mixer;
+use crate::bindings::{Action, BindingConflict, ControlScheme, KeyBindings, KeyboardLayout};
+
+/// Version du fichier de réglages
+pub const SETTINGS_VERSION: u32 = 1;
+
+/// Emplacement des réglages (builds natifs)
+pub const SETTINGS_PATH: &str = "saves/settings.ron";
+
+// -----------------------------------------------------------------------------
+// ERREURS
+// -----------------------------------------------------------------------------
+#[derive(Debug)]
+pub enum SettingsError {
+    Io(io::Error),                      // Lecture / écriture impossible
+    Encode(ron::Error),                 // Sérialisation impossible
+    Parse(ron::error::SpannedError),    // Fichier illisible
+    Conflict(BindingConflict),          // Touches en conflit
+}
+
+impl fmt::Display for SettingsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SettingsError::Io(e) => write!(f, "erreur d'accès aux réglages : {e}"),
+            SettingsError::Encode(e) => write!(f, "écriture des réglages impossible : {e}"),
+            SettingsError::Parse(e) => write!(f, "réglages illisibles : {e}"),
+            SettingsError::Conflict(c) => write!(f, "conflit de touches : {c}"),
+        }
+    }
+}
+
+impl std::error::Error for SettingsError {}
+
+impl From<io::Error> for SettingsError {
+    fn from(e: io::Error) -> Self {
+        SettingsError::Io(e)
+    }
+}
+
+impl From<ron::Error> for SettingsError {
+    fn from(e: ron::Error) -> Self {
+        SettingsError::Encode(e)
+    }
+}
+
+impl From<ron::error::SpannedError> for SettingsError {
+    fn from(e: ron::error::SpannedError) -> Self {
+        SettingsError::Parse(e)
+    }
+}
+
+// -----------------------------------------------------------------------------
+// RÉGLAGES
+// -----------------------------------------------------------------------------
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Settings {
+    pub version: u32,
+    pub layout: KeyboardLayout,     // Dernier préréglage choisi
+    pub bindings: KeyBindings,
+    #[serde(default)]
+    pub control_scheme: ControlScheme,
+    #[serde(default)]
+    pub mixer: Mixer,               // Volumes par catégorie de sons
+    #[serde(default)]
+    pub arena_mode: ArenaMode,      // Mode de l'arène des nouvelles parties
+}
+
+impl Settings {
+    pub fn new() -> Self {
+        Self {
+            version: SETTINGS_VERSION,
+            layout: KeyboardLayout::Qwerty,
+            bindings: KeyBindings::preset(KeyboardLayout::Qwerty),
+            control_scheme: ControlScheme::Tank,
+            mixer: Mixer::new(),
+            arena_mode: ArenaMode::Bounded,
+        }
+    }
+
+    /// Applique un préréglage complet
+    pub fn apply_preset(&mut self, layout: KeyboardLayout) {
+        self.layout = layout;
+        self.bindings = KeyBindings::preset(layout);
+    }
+
+    /// Lit les réglages (par défaut si le fichier n'existe pas)
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, SettingsError> {
+        let text = match fs::read_to_string(path) {
+            Ok(text) => text,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Self::new()),
+            Err(e) => return Err(e.into()),
+        };
+        let mut settings: Settings = ron::from_str(&text)?;
+
+        // Action absente du fichier : touches du préréglage encore libres
+        let preset = KeyBindings::preset(settings.layout);
+        for action in Action::ALL {
+            if !settings.bindings.is_configured(action) {
+                for &key in preset.keys(action) {
+                    let _ = settings.bindings.bind(action, key);
+                }
+            }
+        }
+
+        if let Some(&conflict) = settings.bindings.conflicts().first() {
+            return Err(SettingsError::Conflict(conflict));
+        }
+        settings.version = SETTINGS_VERSION;
+        Ok(settings)
+    }
+
+    /// Écrit les réglages (crée le dossier si nécessaire)
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), SettingsError> {
+        let path = path.as_ref();
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        let text = ron::ser::to_string_pretty(self, ron::ser::PrettyConfig::default())?;
+        fs::write(path, text)?;
+        Ok(())
+    }
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self::new()
+    }
+}
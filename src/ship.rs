@@ -12,18 +12,27 @@
 // =============================================================================
 
 use macroquad::prelude::*;
+use serde::{Deserialize, Serialize};
 use std::collections::VecDeque;
-use crate::consts::*;
+use std::f32::consts::{PI, TAU};
+use crate::arena::Arena;
+use crate::balance::ShipBalance;
+use crate::collider::Collider;
+use crate::consts::{SHIELD_RADIUS, SHIP_RADIUS};
 use crate::game::Cooldown;
+use crate::input::InputState;
 
 // -----------------------------------------------------------------------------
 // STRUCTURE DU VAISSEAU
 // -----------------------------------------------------------------------------
+#[derive(Clone, Serialize, Deserialize)]
 pub struct Ship {
     // Position et mouvement
     pub pos: Vec2,
     pub vel: Vec2,
     pub rot: f32,
+    pub prev_pos: Vec2,     // État au tick précédent (interpolation du rendu)
+    pub prev_rot: f32,
     pub dir: Vec2,
     
     // État du joueur
@@ -43,13 +52,15 @@ pub struct Ship {
     pub rapid_fire_timer: Cooldown,
     pub reverse_boost_timer: Cooldown,
     
-    // Effets visuels
+    // Effets visuels (non sauvegardés)
+    #[serde(skip)]
     pub trail: VecDeque<TrailPoint>,
 }
 
 // -----------------------------------------------------------------------------
 // POINT DE TRAÎNÉE (EFFET VISUEL)
 // -----------------------------------------------------------------------------
+#[derive(Clone)]
 pub struct TrailPoint {
     pub pos: Vec2,
     pub life: f32,
@@ -59,29 +70,42 @@ pub struct TrailPoint {
 // IMPLÉMENTATION DU VAISSEAU
 // -----------------------------------------------------------------------------
 impl Ship {
-    /// Crée un nouveau vaisseau au centre de l'écran
-    pub fn new() -> Self {
+    /// Crée un nouveau vaisseau à la position donnée (centre de l'arène)
+    pub fn new(pos: Vec2, balance: &ShipBalance) -> Self {
         Self {
-            pos: vec2(screen_width() / 2., screen_height() / 2.),
+            pos,
             vel: Vec2::ZERO,
             rot: 0.0,
+            prev_pos: pos,
+            prev_rot: 0.0,
             dir: vec2(1.0, 0.0),
-            lives: PLAYER_LIVES,
-            invincible_timer: Cooldown::new(PLAYER_INVINCIBLE_TIME),
-            shield_energy: Cooldown::full(MAX_SHIELD_TIME),
+            lives: balance.lives,
+            invincible_timer: Cooldown::new(balance.invincible_time),
+            shield_energy: Cooldown::full(balance.shield_time),
             shield_active: false,
-            slowmo_energy: Cooldown::full(MAX_SLOWMO_TIME),
+            slowmo_energy: Cooldown::full(balance.slowmo_time),
             slowmo_active: false,
             is_slowmo_available: true,
-            rapid_fire_timer: Cooldown::new(7.0),
-            reverse_boost_timer: Cooldown::new(0.5),
+            rapid_fire_timer: Cooldown::new(balance.rapid_fire_time),
+            reverse_boost_timer: Cooldown::new(balance.reverse_boost_time),
             trail: VecDeque::new(),
         }
     }
 
+    /// Réapplique les limites actuelles de l'équilibrage
+    /// (chargement d'une sauvegarde, rechargement de balance.ron)
+    pub fn rebalance(&mut self, balance: &ShipBalance) {
+        self.lives = self.lives.min(balance.max_lives);
+        self.invincible_timer = self.invincible_timer.with_max(balance.invincible_time);
+        self.shield_energy = self.shield_energy.with_max(balance.shield_time);
+        self.slowmo_energy = self.slowmo_energy.with_max(balance.slowmo_time);
+        self.rapid_fire_timer = self.rapid_fire_timer.with_max(balance.rapid_fire_time);
+        self.reverse_boost_timer = self.reverse_boost_timer.with_max(balance.reverse_boost_time);
+    }
+
     /// Met à jour le vaisseau pour une frame
     /// Retourne le vecteur directionnel (pour les armes)
-    pub fn update(&mut self, dt: f32) -> Vec2 {
+    pub fn update(&mut self, dt: f32, input: &InputState, arena: &Arena, balance: &ShipBalance) -> Vec2 {
         // Mise à jour des timers
         self.invincible_timer.tick(dt);
         self.rapid_fire_timer.tick(dt);
@@ -90,32 +114,31 @@ impl Ship {
         // -----------------------------------------------------------------
         // GESTION DU BOUCLIER
         // -----------------------------------------------------------------
-        self.shield_active = is_key_down(KEY_SHIELD) && self.shield_energy.time > 0.1;
+        self.shield_active = input.shield && self.shield_energy.time > balance.energy_min;
         if self.shield_active {
             // Consommation d'énergie
-            self.shield_energy.tick(dt * 1.5);
-            //self.shield_energy.time = (self.shield_energy.time - dt * 1.5).max(0.0);
+            self.shield_energy.tick(dt * balance.shield_drain);
         } else {
-            self.shield_energy.increase(dt * 0.6);      // Recharge lente du bouclier
+            self.shield_energy.increase(dt * balance.shield_recharge);  // Recharge lente du bouclier
         }
 
         // -----------------------------------------------------------------
         // GESTION DU SLOW-MOTION
         // -----------------------------------------------------------------
-        self.slowmo_active = is_key_down(KEY_SLOWMO) && 
-                             self.slowmo_energy.time > 0.1 && 
+        self.slowmo_active = input.slowmo && 
+                             self.slowmo_energy.time > balance.energy_min && 
                              self.is_slowmo_available;
                              
         if self.slowmo_active {
             // Consommation d'énergie
-            self.slowmo_energy.tick(dt * 2.5);
+            self.slowmo_energy.tick(dt * balance.slowmo_drain);
             if self.slowmo_energy.time <= 0.0 {
                 self.is_slowmo_available = false;
             }
         } else if self.slowmo_energy.time < self.slowmo_energy.max {
             // Recharge quand inactif
-            self.slowmo_energy.increase(dt*0.4);
-            if self.slowmo_energy.time >= self.slowmo_energy.max * 0.9 {
+            self.slowmo_energy.increase(dt * balance.slowmo_recharge);
+            if self.slowmo_energy.time >= self.slowmo_energy.max * balance.slowmo_ready {
                 self.is_slowmo_available = true;
             }
         }
@@ -123,7 +146,7 @@ impl Ship {
         // -----------------------------------------------------------------
         // GESTION DES MOUVEMENTS
         // -----------------------------------------------------------------
-        self.handle_input(dt);
+        self.handle_input(dt, input, arena, balance);
         
         // -----------------------------------------------------------------
         // GESTION DE LA TRAÎNÉE
@@ -133,14 +156,53 @@ impl Ship {
         self.dir
     }
 
-    /// Traite les entrées clavier pour le mouvement
-    fn handle_input(&mut self, dt: f32) {
+    /// Traite les entrées du joueur pour le mouvement
+    fn handle_input(&mut self, dt: f32, input: &InputState, arena: &Arena, balance: &ShipBalance) {
+        // Boost de recul (action Boost)
+        let reverse_boost = if input.boost && !self.shield_active {
+            self.reverse_boost_timer.reset();
+            balance.reverse_boost
+        } else {
+            1.0
+        };
+
+        match input.aim_angle() {
+            Some(aim) => self.steer_twin_stick(dt, input, aim, reverse_boost, balance),
+            None => self.steer_tank(dt, input, reverse_boost, balance),
+        }
+
+        // Frottement (appliqué à chaque tick fixe de SIM_DT)
+        self.vel *= balance.friction;
+        
+        // Mise à jour de la position
+        self.pos += self.vel * dt;
+        
+        // Arène torique : passage d'un bord à l'autre, la position
+        // précédente suit pour un trajet continu.
+        // Arène bornée : le vaisseau bute contre les bords ; la composante
+        // de la vitesse qui l'en ferait sortir est annulée
+        if arena.wraps() {
+            arena.wrap_motion(&mut self.pos, &mut self.prev_pos);
+        } else {
+            let clamped = self.pos.clamp(Vec2::ZERO, arena.size);
+            if clamped.x != self.pos.x {
+                self.vel.x = 0.0;
+            }
+            if clamped.y != self.pos.y {
+                self.vel.y = 0.0;
+            }
+            self.pos = clamped;
+        }
+    }
+
+    /// Schéma clavier : rotation et poussée dans la direction du vaisseau
+    fn steer_tank(&mut self, dt: f32, input: &InputState, reverse_boost: f32, balance: &ShipBalance) {
         // Rotation
-        if is_key_down(KEY_LEFT) {
-            self.rot -= 2.0 * dt;
+        if input.turn_left {
+            self.rot -= balance.turn_speed * dt;
         }
-        if is_key_down(KEY_RIGHT) {
-            self.rot += 2.0 * dt;
+        if input.turn_right {
+            self.rot += balance.turn_speed * dt;
         }
 
         // Mise à jour du vecteur directionnel
@@ -148,21 +210,13 @@ impl Ship {
 
         // Accélération
         let mut move_speed = 0.0;
-        if is_key_down(KEY_UP) {
-            move_speed = 500.0;
+        if input.thrust {
+            move_speed = balance.thrust;
         }
-        if is_key_down(KEY_DOWN) {
-            move_speed = -300.0;
+        if input.brake {
+            move_speed = -balance.brake;
         }
 
-        // Boost de recul (R)
-        let reverse_boost = if is_key_down(KEY_REVERSE_BOOST) && !self.shield_active {
-            self.reverse_boost_timer.reset();
-            2.5
-        } else {
-            1.0
-        };
-
         if move_speed != 0.0 {
             let effective_speed = if move_speed < 0.0 {
                 move_speed * reverse_boost
@@ -172,16 +226,39 @@ impl Ship {
 
             self.vel += self.dir * effective_speed * dt;
         }
+    }
 
-        // Frottement
-        self.vel *= 0.97;
-        
-        // Mise à jour de la position
-        self.pos += self.vel * dt;
-        
-        // Téléportation aux bords de l'écran (wrap-around)
-        self.pos.x = self.pos.x.rem_euclid(screen_width());
-        self.pos.y = self.pos.y.rem_euclid(screen_height());
+    /// Schéma twin-stick : le vaisseau vise le curseur et se déplace
+    /// dans les directions de l'écran (haut / bas / gauche / droite)
+    fn steer_twin_stick(&mut self, dt: f32, input: &InputState, aim: f32, reverse_boost: f32, balance: &ShipBalance) {
+        // Plus court chemin vers la visée : l'angle reste continu pour
+        // l'interpolation du rendu (pas de tour complet en passant par 0)
+        let delta = (aim - self.rot + PI).rem_euclid(TAU) - PI;
+        self.rot += delta;
+        self.dir = vec2(self.rot.cos(), self.rot.sin());
+
+        let mut move_dir = Vec2::ZERO;
+        if input.thrust {
+            move_dir.y -= 1.0;
+        }
+        if input.brake {
+            move_dir.y += 1.0;
+        }
+        if input.turn_left {
+            move_dir.x -= 1.0;
+        }
+        if input.turn_right {
+            move_dir.x += 1.0;
+        }
+        let move_dir = move_dir.normalize_or_zero();
+
+        // À reculons (dos à la visée) : vitesse de recul, boostable
+        let speed = if move_dir.dot(self.dir) < 0.0 {
+            balance.brake * reverse_boost
+        } else {
+            balance.thrust
+        };
+        self.vel += move_dir * speed * dt;
     }
 
     /// Met à jour la traînée visuelle du vaisseau
@@ -201,26 +278,29 @@ impl Ship {
         self.trail.retain(|tp| tp.life > 0.0);
     }
 
-    /// Inflige des dégâts au vaisseau (retourne true si dégât effectif)
-    pub fn take_damage(&mut self) -> bool {
-        if self.invincible_timer.ready() && !self.shield_active {
-            self.lives -= 1;
-            self.invincible_timer.reset();
-            true
-        } else {
-            false
-        }
-    }
-
     /// Vérifie si le vaisseau est invincible
     pub fn is_invincible(&self) -> bool {
         !self.invincible_timer.ready()
     }
 
-    /// Retourne le facteur de temps (1.0 = normal, 0.3 = slow-mo)
-    pub fn get_slow_time_factor(&self) -> f32 {
+    /// Triangle de la coque à une position et une orientation (rendu)
+    pub fn hull_at(pos: Vec2, rot: f32) -> Collider {
+        Collider::regular_polygon(pos, 3, SHIP_RADIUS, rot)
+    }
+
+    /// Forme de collision : la bulle du bouclier s'il est actif, sinon la coque
+    pub fn collider(&self) -> Collider {
+        if self.shield_active {
+            Collider::circle(self.pos, SHIELD_RADIUS)
+        } else {
+            Self::hull_at(self.pos, self.rot)
+        }
+    }
+
+    /// Retourne le facteur de temps (1.0 = normal, slowmo_factor en slow-mo)
+    pub fn get_slow_time_factor(&self, balance: &ShipBalance) -> f32 {
         if self.slowmo_active {
-            0.3
+            balance.slowmo_factor
         } else {
             1.0
         }
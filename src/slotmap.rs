@@ -0,0 +1,482 @@
+// =============================================================================
+// IDENTIFIANTS D'ENTITÉS (SLOT MAP GÉNÉRATIONNELLE)
+// =============================================================================
+//
+// Les collections du monde (astéroïdes, drones, balles, power-ups, missiles)
+// sont des SlotMap : un Vec dense, parcouru et indexé comme avant, doublé
+// d'un identifiant stable pour chaque entité.
+//
+// - EntityId : (emplacement, génération). Quand une entité disparaît, son
+//   emplacement est recyclé avec une génération de plus : un ancien ID ne
+//   désigne jamais la nouvelle occupante (get() retourne None)
+// - Les index denses (0..len) restent valables pendant un tick comme ceux
+//   d'un Vec : grilles spatiales, tests de collision
+// - Les ID servent à garder une référence d'une frame à l'autre : cible
+//   d'un missile, tireur d'une balle, attribution des coups, debug
+//
+// Même API que Vec pour push / swap_remove / retain / clear, dans le même
+// ordre (retain garde l'ordre) : la simulation est identique à celle des Vec.
+// Lecture comme une slice ; en écriture, seules les valeurs sont modifiables
+// (iter_mut, [i], get2_mut) : un tri ou un échange direct désynchroniserait
+// les ID.
+//
+// Sauvegarde : générations, emplacements libres, ID et valeurs sont écrits
+// tels quels (les ID attribués après reprise sont les mêmes) ; une
+// simple liste (sauvegarde v1) est aussi acceptée et reçoit de nouveaux ID.
+//
+// =============================================================================
+
+use serde::de::{self, MapAccess, SeqAccess, Visitor};
+use serde::ser::SerializeStruct;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt;
+use std::marker::PhantomData;
+use std::ops::{Deref, Index, IndexMut};
+
+// -----------------------------------------------------------------------------
+// IDENTIFIANT
+// -----------------------------------------------------------------------------
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct EntityId {
+    index: u32,         // Emplacement dans la SlotMap
+    generation: u32,    // Nombre de fois que l'emplacement a été libéré
+}
+
+impl EntityId {
+    pub fn index(&self) -> u32 {
+        self.index
+    }
+
+    pub fn generation(&self) -> u32 {
+        self.generation
+    }
+}
+
+impl fmt::Display for EntityId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}v{}", self.index, self.generation)
+    }
+}
+
+// -----------------------------------------------------------------------------
+// SLOT MAP
+// -----------------------------------------------------------------------------
+#[derive(Clone, Copy, Debug)]
+struct Slot {
+    generation: u32,
+    dense: Option<u32>,     // Index dans `values` (None = emplacement libre)
+}
+
+#[derive(Clone, Debug)]
+pub struct SlotMap<T> {
+    values: Vec<T>,
+    ids: Vec<EntityId>,     // ids[i] = identifiant de values[i]
+    slots: Vec<Slot>,
+    free: Vec<u32>,         // Emplacements libres (le dernier libéré d'abord)
+}
+
+impl<T> SlotMap<T> {
+    pub fn new() -> Self {
+        Self {
+            values: Vec::new(),
+            ids: Vec::new(),
+            slots: Vec::new(),
+            free: Vec::new(),
+        }
+    }
+
+    /// Ajoute une entité à la fin et retourne son identifiant
+    pub fn push(&mut self, value: T) -> EntityId {
+        let dense = Some(self.values.len() as u32);
+        let id = match self.free.pop() {
+            Some(index) => {
+                let slot = &mut self.slots[index as usize];
+                slot.dense = dense;
+                EntityId { index, generation: slot.generation }
+            }
+            None => {
+                self.slots.push(Slot { generation: 0, dense });
+                EntityId { index: self.slots.len() as u32 - 1, generation: 0 }
+            }
+        };
+        self.values.push(value);
+        self.ids.push(id);
+        id
+    }
+
+    /// Retire l'entité d'index dense `i` (la dernière prend sa place)
+    pub fn swap_remove(&mut self, i: usize) -> T {
+        self.release(self.ids[i]);
+        self.ids.swap_remove(i);
+        let value = self.values.swap_remove(i);
+        if let Some(moved) = self.ids.get(i) {
+            self.slots[moved.index as usize].dense = Some(i as u32);
+        }
+        value
+    }
+
+    /// Retire l'entité désignée par `id` (None si elle n'existe plus)
+    pub fn remove(&mut self, id: EntityId) -> Option<T> {
+        let i = self.index_of(id)?;
+        Some(self.swap_remove(i))
+    }
+
+    /// Garde les entités acceptées par `keep`, dans le même ordre
+    pub fn retain(&mut self, mut keep: impl FnMut(&T) -> bool) {
+        let mut kept = 0;
+        for i in 0..self.values.len() {
+            if keep(&self.values[i]) {
+                self.values.swap(kept, i);
+                self.ids.swap(kept, i);
+                kept += 1;
+            } else {
+                self.release(self.ids[i]);
+            }
+        }
+        self.values.truncate(kept);
+        self.ids.truncate(kept);
+        for (i, id) in self.ids.iter().enumerate() {
+            self.slots[id.index as usize].dense = Some(i as u32);
+        }
+    }
+
+    /// Retire toutes les entités (les anciens ID deviennent invalides)
+    pub fn clear(&mut self) {
+        for i in 0..self.ids.len() {
+            self.release(self.ids[i]);
+        }
+        self.values.clear();
+        self.ids.clear();
+    }
+
+    /// Index dense de l'entité `id`, si elle existe encore
+    pub fn index_of(&self, id: EntityId) -> Option<usize> {
+        let slot = self.slots.get(id.index as usize)?;
+        if slot.generation != id.generation {
+            return None;
+        }
+        slot.dense.map(|i| i as usize)
+    }
+
+    /// Identifiant de l'entité d'index dense `i`
+    pub fn id_at(&self, i: usize) -> EntityId {
+        self.ids[i]
+    }
+
+    /// Identifiants, dans l'ordre des index denses
+    pub fn ids(&self) -> &[EntityId] {
+        &self.ids
+    }
+
+    pub fn contains(&self, id: EntityId) -> bool {
+        self.index_of(id).is_some()
+    }
+
+    pub fn get(&self, id: EntityId) -> Option<&T> {
+        self.index_of(id).map(|i| &self.values[i])
+    }
+
+    pub fn get_mut(&mut self, id: EntityId) -> Option<&mut T> {
+        self.index_of(id).map(|i| &mut self.values[i])
+    }
+
+    /// Parcourt les entités avec leur identifiant
+    pub fn iter_with_ids(&self) -> impl Iterator<Item = (EntityId, &T)> {
+        self.ids.iter().copied().zip(self.values.iter())
+    }
+
+    /// Parcourt les entités (modifiables), dans l'ordre des index denses
+    pub fn iter_mut(&mut self) -> std::slice::IterMut<'_, T> {
+        self.values.iter_mut()
+    }
+
+    /// Deux entités distinctes modifiables en même temps (paires d'entités
+    /// de la même collection) ; None si l'une manque ou si `a == b`
+    pub fn get2_mut(&mut self, a: EntityId, b: EntityId) -> Option<(&mut T, &mut T)> {
+        let (i, j) = (self.index_of(a)?, self.index_of(b)?);
+        if i < j {
+            let (head, tail) = self.values.split_at_mut(j);
+            Some((&mut head[i], &mut tail[0]))
+        } else if j < i {
+            let (head, tail) = self.values.split_at_mut(i);
+            Some((&mut tail[0], &mut head[j]))
+        } else {
+            None
+        }
+    }
+
+    /// Parcourt les entités (modifiables) avec leur identifiant
+    pub fn iter_mut_with_ids(&mut self) -> impl Iterator<Item = (EntityId, &mut T)> {
+        self.ids.iter().copied().zip(self.values.iter_mut())
+    }
+
+    /// Libère l'emplacement d'un ID (nouvelle génération)
+    fn release(&mut self, id: EntityId) {
+        let slot = &mut self.slots[id.index as usize];
+        slot.generation = slot.generation.wrapping_add(1);
+        slot.dense = None;
+        self.free.push(id.index);
+    }
+
+    /// Reconstruit une SlotMap sauvegardée en vérifiant sa cohérence
+    fn from_parts(
+        generations: Vec<u32>,
+        free: Vec<u32>,
+        ids: Vec<EntityId>,
+        values: Vec<T>,
+    ) -> Result<Self, String> {
+        if ids.len() != values.len() {
+            return Err(format!("{} identifiants pour {} entités", ids.len(), values.len()));
+        }
+        let mut slots: Vec<Slot> = generations
+            .into_iter()
+            .map(|generation| Slot { generation, dense: None })
+            .collect();
+        for (i, id) in ids.iter().enumerate() {
+            match slots.get_mut(id.index as usize) {
+                Some(slot) if slot.generation == id.generation && slot.dense.is_none() => {
+                    slot.dense = Some(i as u32);
+                }
+                _ => return Err(format!("identifiant {id} invalide ou en double")),
+            }
+        }
+        // Chaque emplacement vide doit être libre une seule fois
+        let mut listed = vec![false; slots.len()];
+        for &index in &free {
+            match slots.get(index as usize) {
+                Some(slot) if slot.dense.is_none() && !listed[index as usize] => {
+                    listed[index as usize] = true;
+                }
+                _ => return Err(format!("emplacement libre {index} invalide ou en double")),
+            }
+        }
+        if free.len() + ids.len() != slots.len() {
+            return Err("emplacements libres incomplets".to_string());
+        }
+        Ok(Self { values, ids, slots, free })
+    }
+}
+
+impl<T> Default for SlotMap<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Accès en lecture comme une slice (index denses, iter, len...)
+impl<T> Deref for SlotMap<T> {
+    type Target = [T];
+
+    fn deref(&self) -> &[T] {
+        &self.values
+    }
+}
+
+impl<T> Index<usize> for SlotMap<T> {
+    type Output = T;
+
+    fn index(&self, i: usize) -> &T {
+        &self.values[i]
+    }
+}
+
+/// Écriture d'une entité par son index dense (l'ordre ne change pas)
+impl<T> IndexMut<usize> for SlotMap<T> {
+    fn index_mut(&mut self, i: usize) -> &mut T {
+        &mut self.values[i]
+    }
+}
+
+impl<'a, T> IntoIterator for &'a SlotMap<T> {
+    type Item = &'a T;
+    type IntoIter = std::slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.values.iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a mut SlotMap<T> {
+    type Item = &'a mut T;
+    type IntoIter = std::slice::IterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.values.iter_mut()
+    }
+}
+
+// -----------------------------------------------------------------------------
+// SÉRIALISATION
+// -----------------------------------------------------------------------------
+impl<T: Serialize> Serialize for SlotMap<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let generations: Vec<u32> = self.slots.iter().map(|s| s.generation).collect();
+        let mut state = serializer.serialize_struct("SlotMap", 4)?;
+        state.serialize_field("generations", &generations)?;
+        state.serialize_field("free", &self.free)?;
+        state.serialize_field("ids", &self.ids)?;
+        state.serialize_field("values", &self.values)?;
+        state.end()
+    }
+}
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for SlotMap<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_any(SlotMapVisitor(PhantomData))
+    }
+}
+
+struct SlotMapVisitor<T>(PhantomData<T>);
+
+#[derive(Deserialize)]
+#[serde(field_identifier, rename_all = "lowercase")]
+enum Field {
+    Generations,
+    Free,
+    Ids,
+    Values,
+}
+
+impl<'de, T: Deserialize<'de>> Visitor<'de> for SlotMapVisitor<T> {
+    type Value = SlotMap<T>;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("une SlotMap (generations, free, ids, values) ou une liste d'entités")
+    }
+
+    /// Ancien format : simple liste, de nouveaux ID sont attribués
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut map = SlotMap::new();
+        while let Some(value) = seq.next_element()? {
+            map.push(value);
+        }
+        Ok(map)
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut access: A) -> Result<Self::Value, A::Error> {
+        let mut generations = None;
+        let mut free = None;
+        let mut ids = None;
+        let mut values = None;
+        while let Some(key) = access.next_key()? {
+            match key {
+                Field::Generations => generations = Some(access.next_value()?),
+                Field::Free => free = Some(access.next_value()?),
+                Field::Ids => ids = Some(access.next_value()?),
+                Field::Values => values = Some(access.next_value()?),
+            }
+        }
+        SlotMap::from_parts(
+            generations.ok_or_else(|| de::Error::missing_field("generations"))?,
+            free.ok_or_else(|| de::Error::missing_field("free"))?,
+            ids.ok_or_else(|| de::Error::missing_field("ids"))?,
+            values.ok_or_else(|| de::Error::missing_field("values"))?,
+        )
+        .map_err(de::Error::custom)
+    }
+}
+
+// =============================================================================
+// TESTS
+// =============================================================================
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn swap_remove_recycles_slot_with_new_generation() {
+        let mut map = SlotMap::new();
+        let a = map.push("a");
+        let b = map.push("b");
+        let c = map.push("c");
+
+        assert_eq!(map.swap_remove(0), "a");
+        // "c" a pris la place de "a" : son ID le retrouve toujours
+        assert_eq!(map.get(c), Some(&"c"));
+        assert_eq!(map.index_of(c), Some(0));
+        assert_eq!(map.get(a), None);
+
+        let d = map.push("d");
+        assert_eq!(d.index(), a.index());
+        assert_eq!(d.generation(), a.generation() + 1);
+        assert_eq!(map.get(a), None, "l'ancien ID ne désigne pas la nouvelle occupante");
+        assert_eq!(map.get(d), Some(&"d"));
+        assert_eq!(map.get(b), Some(&"b"));
+    }
+
+    #[test]
+    fn retain_keeps_order_and_invalidates_removed_ids() {
+        let mut map = SlotMap::new();
+        let ids: Vec<EntityId> = (0..6).map(|i| map.push(i)).collect();
+        map.retain(|v| v % 2 == 1);
+
+        assert_eq!(&*map, &[1, 3, 5]);
+        for (i, id) in ids.iter().enumerate() {
+            if i % 2 == 1 {
+                assert_eq!(map.get(*id), Some(&(i as i32)));
+                assert_eq!(map.id_at(map.index_of(*id).unwrap()), *id);
+            } else {
+                assert_eq!(map.get(*id), None);
+            }
+        }
+
+        // Emplacements recyclés, le dernier libéré d'abord
+        let reused = map.push(10);
+        assert_eq!(reused.index(), ids[4].index());
+        assert_eq!(reused.generation(), 1);
+        assert_eq!(map.get(ids[4]), None);
+    }
+
+    #[test]
+    fn mutation_through_index_keeps_ids() {
+        let mut map = SlotMap::new();
+        let a = map.push(1);
+        let b = map.push(2);
+        map[1] += 40;
+        for v in map.iter_mut() {
+            *v *= 10;
+        }
+        assert_eq!(map.get(a), Some(&10));
+        assert_eq!(map.get(b), Some(&420));
+
+        // Paire modifiable, dans l'ordre des ID demandés
+        let (x, y) = map.get2_mut(b, a).unwrap();
+        *x += 1;
+        *y += 2;
+        assert_eq!(map.get(a), Some(&12));
+        assert_eq!(map.get(b), Some(&421));
+        assert!(map.get2_mut(a, a).is_none());
+        map.swap_remove(0);
+        assert!(map.get2_mut(a, b).is_none());
+    }
+
+    #[test]
+    fn serialization_round_trip_keeps_ids() {
+        let mut map = SlotMap::new();
+        let a = map.push(1);
+        map.push(2);
+        map.swap_remove(1);
+        let text = ron::to_string(&map).unwrap();
+        let mut back: SlotMap<i32> = ron::from_str(&text).unwrap();
+        assert_eq!(back.get(a), Some(&1));
+        // Même ID attribué après reprise
+        assert_eq!(back.push(3), map.push(3));
+    }
+
+    #[test]
+    fn legacy_sequence_gets_fresh_ids() {
+        let map: SlotMap<i32> = ron::from_str("[4, 5, 6]").unwrap();
+        assert_eq!(&*map, &[4, 5, 6]);
+        for i in 0..3 {
+            let id = map.id_at(i);
+            assert_eq!((id.index(), id.generation()), (i as u32, 0));
+            assert_eq!(map.get(id), Some(&map[i]));
+        }
+    }
+
+    #[test]
+    fn inconsistent_save_is_rejected() {
+        let text = "(generations:[0],free:[],ids:[(index:0,generation:0),(index:0,generation:0)],values:[1,2])";
+        assert!(ron::from_str::<SlotMap<i32>>(text).is_err());
+    }
+}
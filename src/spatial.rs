@@ -0,0 +1,284 @@
+// =============================================================================
+// GRILLE SPATIALE (BROAD-PHASE DES COLLISIONS)
+// =============================================================================
+//
+// Ce fichier évite de tester chaque balle contre chaque ennemi :
+// - SpatialGrid : table de hachage de cellules carrées (GRID_CELL_SIZE)
+//   contenant les index des entités qui les recouvrent
+// - query / query_segment : candidats proches d'un point ou d'un rayon
+// - nearest : entité la plus proche (ciblage des missiles)
+//
+// Les grilles du monde (astéroïdes, drones) sont reconstruites à chaque tick
+// (World::rebuild_grids). Une requête retourne un sur-ensemble trié par
+// index croissant : le test exact reste fait par l'appelant, dans le même
+// ordre que l'ancien parcours complet, ce qui garde la simulation identique
+// (et les replays valides).
+//
+// En arène torique, une entité proche d'un bord est aussi rangée à la place
+// de sa copie de l'autre côté (même index) : une requête près d'un bord
+// trouve les entités de l'autre côté. Le test exact se fait alors contre
+// la copie la plus proche (Arena::image_near).
+//
+// =============================================================================
+
+use macroquad::prelude::*;
+use std::collections::HashMap;
+use crate::arena::Arena;
+use crate::collider::segment_distance;
+use crate::consts::GRID_CELL_SIZE;
+
+type Cell = (i32, i32);
+
+#[derive(Clone, Default)]
+pub struct SpatialGrid {
+    cells: HashMap<Cell, Vec<usize>>,
+    bounds: Option<(Cell, Cell)>,   // Cellules occupées extrêmes (min, max)
+}
+
+impl SpatialGrid {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Vide la grille (les cellules gardent leur mémoire d'un tick à l'autre)
+    pub fn clear(&mut self) {
+        for entries in self.cells.values_mut() {
+            entries.clear();
+        }
+        self.bounds = None;
+    }
+
+    /// Reconstruit la grille à partir de (position, rayon) de chaque entité,
+    /// indexées dans l'ordre de l'itérateur ; en arène torique, les copies
+    /// à moins d'une cellule d'un bord sont rangées aussi
+    pub fn rebuild(&mut self, arena: &Arena, entities: impl Iterator<Item = (Vec2, f32)>) {
+        self.clear();
+        for (index, (pos, radius)) in entities.enumerate() {
+            for offset in arena.images(pos, radius + GRID_CELL_SIZE) {
+                self.insert(index, pos + offset, radius);
+            }
+        }
+    }
+
+    /// Ajoute une entité dans toutes les cellules que son cercle recouvre
+    pub fn insert(&mut self, index: usize, pos: Vec2, radius: f32) {
+        let (min, max) = cell_range(pos, radius);
+        for x in min.0..=max.0 {
+            for y in min.1..=max.1 {
+                self.cells.entry((x, y)).or_default().push(index);
+            }
+        }
+        self.bounds = Some(match self.bounds {
+            Some((lo, hi)) => ((lo.0.min(min.0), lo.1.min(min.1)), (hi.0.max(max.0), hi.1.max(max.1))),
+            None => (min, max),
+        });
+    }
+
+    /// Index des entités pouvant être à moins de `radius` de `pos`
+    /// (en plus de leur propre rayon), triés et sans doublon
+    pub fn query(&self, pos: Vec2, radius: f32, out: &mut Vec<usize>) {
+        out.clear();
+        let (min, max) = cell_range(pos, radius);
+        self.collect(min, max, |_| true, out);
+    }
+
+    /// Index des entités pouvant être à moins de `radius` du segment [a, b],
+    /// triés et sans doublon
+    pub fn query_segment(&self, a: Vec2, b: Vec2, radius: f32, out: &mut Vec<usize>) {
+        out.clear();
+        let (min_a, max_a) = cell_range(a, radius);
+        let (min_b, max_b) = cell_range(b, radius);
+        let min = (min_a.0.min(min_b.0), min_a.1.min(min_b.1));
+        let max = (max_a.0.max(max_b.0), max_a.1.max(max_b.1));
+        // Une cellule est gardée si son centre est assez proche du segment
+        let reach = radius + GRID_CELL_SIZE * std::f32::consts::FRAC_1_SQRT_2;
+        self.collect(min, max, |cell| segment_distance(cell_center(cell), a, b) <= reach, out);
+    }
+
+    /// Entité la plus proche de `pos` parmi celles acceptées par `accept`
+    /// `positions` donne la position d'une entité à partir de son index
+    /// En cas d'égalité, l'index le plus petit l'emporte
+    pub fn nearest(
+        &self,
+        pos: Vec2,
+        positions: impl Fn(usize) -> Vec2,
+        accept: impl Fn(usize) -> bool,
+    ) -> Option<usize> {
+        let (lo, hi) = self.bounds?;
+        let mut candidates = Vec::new();
+        let mut radius = GRID_CELL_SIZE;
+        loop {
+            self.query(pos, radius, &mut candidates);
+            let mut best: Option<(usize, f32)> = None;
+            for &index in &candidates {
+                if !accept(index) {
+                    continue;
+                }
+                let dist = pos.distance_squared(positions(index));
+                if best.is_none_or(|(_, min)| dist < min) {
+                    best = Some((index, dist));
+                }
+            }
+
+            // Trouvé dans le rayon : aucune entité hors requête n'est plus proche
+            if let Some((index, _)) = best.filter(|&(_, dist)| dist <= radius * radius) {
+                return Some(index);
+            }
+            // La requête couvre déjà toute la grille
+            let (min, max) = cell_range(pos, radius);
+            if min.0 <= lo.0 && min.1 <= lo.1 && max.0 >= hi.0 && max.1 >= hi.1 {
+                return best.map(|(index, _)| index);
+            }
+            radius *= 2.0;
+        }
+    }
+
+    /// Comme `nearest`, mais selon la distance dans l'arène (à travers les
+    /// bords en arène torique) : la recherche part de chaque copie de `pos`
+    /// autour de l'arène, `positions` restant les positions dans l'arène
+    pub fn nearest_in(
+        &self,
+        arena: &Arena,
+        pos: Vec2,
+        positions: impl Fn(usize) -> Vec2,
+        accept: impl Fn(usize) -> bool,
+    ) -> Option<usize> {
+        let mut best: Option<(usize, f32)> = None;
+        for offset in arena.tiles() {
+            let from = pos + offset;
+            let found = self.nearest(from, &positions, &accept);
+            if let Some(index) = found {
+                let dist = from.distance_squared(positions(index));
+                if best.is_none_or(|(min_index, min)| dist < min || (dist == min && index < min_index)) {
+                    best = Some((index, dist));
+                }
+            }
+        }
+        best.map(|(index, _)| index)
+    }
+
+    /// Rassemble les index des cellules [min, max] acceptées par `keep`
+    fn collect(&self, min: Cell, max: Cell, keep: impl Fn(Cell) -> bool, out: &mut Vec<usize>) {
+        // Ne parcourir que la partie occupée de la grille
+        let Some((lo, hi)) = self.bounds else { return };
+        for x in min.0.max(lo.0)..=max.0.min(hi.0) {
+            for y in min.1.max(lo.1)..=max.1.min(hi.1) {
+                match self.cells.get(&(x, y)) {
+                    Some(entries) if !entries.is_empty() && keep((x, y)) => {
+                        out.extend_from_slice(entries);
+                    }
+                    _ => {}
+                }
+            }
+        }
+        out.sort_unstable();
+        out.dedup();
+    }
+}
+
+/// Cellule contenant un point
+fn cell_of(pos: Vec2) -> Cell {
+    ((pos.x / GRID_CELL_SIZE).floor() as i32, (pos.y / GRID_CELL_SIZE).floor() as i32)
+}
+
+/// Cellules (min, max) recouvertes par un cercle
+fn cell_range(pos: Vec2, radius: f32) -> (Cell, Cell) {
+    let r = vec2(radius.max(0.0), radius.max(0.0));
+    (cell_of(pos - r), cell_of(pos + r))
+}
+
+fn cell_center(cell: Cell) -> Vec2 {
+    (vec2(cell.0 as f32, cell.1 as f32) + 0.5) * GRID_CELL_SIZE
+}
+
+// =============================================================================
+// TESTS
+// =============================================================================
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::arena::ArenaMode;
+    use crate::rng::Rng;
+
+    /// Entités au hasard dans l'arène : (position, rayon)
+    fn random_entities(arena: &Arena, rng: &mut Rng, count: usize) -> Vec<(Vec2, f32)> {
+        (0..count)
+            .map(|_| {
+                let pos = vec2(rng.gen_range(0.0, arena.size.x), rng.gen_range(0.0, arena.size.y));
+                (pos, rng.gen_range(2.0, 40.0))
+            })
+            .collect()
+    }
+
+    #[test]
+    fn query_finds_every_entity_within_reach() {
+        for mode in [ArenaMode::Bounded, ArenaMode::Toroidal] {
+            let arena = Arena::new(vec2(800., 600.), mode);
+            let mut rng = Rng::new(11);
+            let entities = random_entities(&arena, &mut rng, 200);
+            let mut grid = SpatialGrid::new();
+            grid.rebuild(&arena, entities.iter().copied());
+
+            let mut found = Vec::new();
+            for _ in 0..500 {
+                // Points et rayons de requête des collisions (jusqu'à une cellule)
+                let pos = vec2(rng.gen_range(0.0, arena.size.x), rng.gen_range(0.0, arena.size.y));
+                let radius = rng.gen_range(0.0, GRID_CELL_SIZE);
+                grid.query(pos, radius, &mut found);
+
+                assert!(found.windows(2).all(|w| w[0] < w[1]), "{mode:?} : résultat non trié");
+                for (index, &(other, r)) in entities.iter().enumerate() {
+                    if arena.delta(pos, other).length() < radius + r {
+                        assert!(
+                            found.binary_search(&index).is_ok(),
+                            "{mode:?} : entité {index} en {other} manquée depuis {pos}"
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn query_segment_finds_every_entity_along_it() {
+        let arena = Arena::bounded(vec2(800., 600.));
+        let mut rng = Rng::new(3);
+        let entities = random_entities(&arena, &mut rng, 200);
+        let mut grid = SpatialGrid::new();
+        grid.rebuild(&arena, entities.iter().copied());
+
+        let mut found = Vec::new();
+        for _ in 0..200 {
+            let a = vec2(rng.gen_range(0.0, arena.size.x), rng.gen_range(0.0, arena.size.y));
+            let b = vec2(rng.gen_range(0.0, arena.size.x), rng.gen_range(0.0, arena.size.y));
+            grid.query_segment(a, b, 4.0, &mut found);
+            for (index, &(pos, r)) in entities.iter().enumerate() {
+                if segment_distance(pos, a, b) < 4.0 + r {
+                    assert!(found.binary_search(&index).is_ok(), "entité {index} manquée sur [{a}, {b}]");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn nearest_in_matches_brute_force() {
+        for mode in [ArenaMode::Bounded, ArenaMode::Toroidal] {
+            let arena = Arena::new(vec2(800., 600.), mode);
+            let mut rng = Rng::new(5);
+            let entities = random_entities(&arena, &mut rng, 30);
+            let mut grid = SpatialGrid::new();
+            grid.rebuild(&arena, entities.iter().copied());
+
+            for _ in 0..200 {
+                let pos = vec2(rng.gen_range(0.0, arena.size.x), rng.gen_range(0.0, arena.size.y));
+                let found = grid.nearest_in(&arena, pos, |i| entities[i].0, |i| i % 3 != 0).unwrap();
+                let best = (0..entities.len())
+                    .filter(|i| i % 3 != 0)
+                    .map(|i| arena.delta(pos, entities[i].0).length())
+                    .fold(f32::INFINITY, f32::min);
+                let dist = arena.delta(pos, entities[found].0).length();
+                assert!((dist - best).abs() < 1e-3, "{mode:?} : {dist} au lieu de {best} depuis {pos}");
+            }
+        }
+    }
+}
@@ -0,0 +1,105 @@
+// =============================================================================
+// STATISTIQUES DE LA PARTIE
+// =============================================================================
+//
+// Ce fichier compte ce qui s'est passé pendant la partie en cours :
+// - Tirs par arme
+// - Ennemis détruits par arme
+// - Vies perdues, coups bloqués, power-ups ramassés
+//
+// RunStats est un abonné du bus d'événements (voir events.rs) : il ne lit
+// jamais l'état du jeu, seulement les événements émis par le gameplay.
+// Les compteurs sont sauvegardés avec la partie et affichés au game over.
+//
+// =============================================================================
+
+use serde::{Deserialize, Serialize};
+use crate::events::{Event, EventListener};
+use crate::weapons::WeaponKind;
+
+// -----------------------------------------------------------------------------
+// COMPTEURS PAR ARME
+// -----------------------------------------------------------------------------
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WeaponCounters {
+    pub cannon: u32,
+    pub missile: u32,
+    pub laser: u32,
+    pub shield: u32,
+    pub collision: u32,
+}
+
+impl WeaponCounters {
+    /// Compteur associé à une arme
+    pub fn get(&self, weapon: WeaponKind) -> u32 {
+        match weapon {
+            WeaponKind::Cannon => self.cannon,
+            WeaponKind::Missile => self.missile,
+            WeaponKind::Laser => self.laser,
+            WeaponKind::Shield => self.shield,
+            WeaponKind::Collision => self.collision,
+        }
+    }
+
+    fn increment(&mut self, weapon: WeaponKind) {
+        let counter = match weapon {
+            WeaponKind::Cannon => &mut self.cannon,
+            WeaponKind::Missile => &mut self.missile,
+            WeaponKind::Laser => &mut self.laser,
+            WeaponKind::Shield => &mut self.shield,
+            WeaponKind::Collision => &mut self.collision,
+        };
+        *counter += 1;
+    }
+
+    /// Somme de toutes les armes
+    pub fn total(&self) -> u32 {
+        self.cannon + self.missile + self.laser + self.shield + self.collision
+    }
+}
+
+// -----------------------------------------------------------------------------
+// STATISTIQUES
+// -----------------------------------------------------------------------------
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct RunStats {
+    pub shots: WeaponCounters,      // Tirs du joueur
+    pub hits: WeaponCounters,       // Coups portés (ennemi touché ou détruit)
+    pub kills: WeaponCounters,      // Ennemis détruits
+    pub hits_taken: u32,            // Vies perdues
+    pub shield_blocks: u32,         // Coups absorbés par le bouclier
+    pub powerups_collected: u32,
+    pub waves_completed: u32,
+}
+
+impl RunStats {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Précision du tir normal (0 à 1, None si aucun tir)
+    pub fn cannon_accuracy(&self) -> Option<f32> {
+        (self.shots.cannon > 0).then(|| self.hits.cannon as f32 / self.shots.cannon as f32)
+    }
+}
+
+impl EventListener for RunStats {
+    fn on_event(&mut self, event: &Event) {
+        match *event {
+            Event::WeaponFired { weapon, .. } => self.shots.increment(weapon),
+            Event::EnemyDamaged { by_weapon, .. } => self.hits.increment(by_weapon),
+            Event::EnemyKilled { by_weapon, .. } => self.kills.increment(by_weapon),
+            Event::PlayerHit { .. } => self.hits_taken += 1,
+            Event::ShieldBlocked { .. } => self.shield_blocks += 1,
+            Event::PowerUpCollected { .. } => self.powerups_collected += 1,
+            Event::WaveCompleted { .. } => self.waves_completed += 1,
+            Event::RunStarted { .. }
+            | Event::ViewMoved { .. }
+            | Event::TimeScaleChanged { .. }
+            | Event::ThreatChanged { .. }
+            | Event::BossArrived { .. }
+            | Event::BossPhaseChanged { .. }
+            | Event::GameOver => {}
+        }
+    }
+}
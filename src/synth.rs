@@ -0,0 +1,437 @@
+// =============================================================================
+// SYNTHÉTISEUR D'EFFETS SONORES (STYLE SFXR)
+// =============================================================================
+//
+// Ce fichier fabrique les effets sonores du jeu à partir de quelques
+// paramètres, sans aucun fichier audio :
+// - Waveform : forme d'onde (carré, dent de scie, sinus, triangle, bruit)
+// - SfxParams : enveloppe (attaque, maintien, punch, déclin), glissement de
+//   hauteur, vibrato, arpège, rapport cyclique, filtre passe-bas
+// - SfxConfig : un jeu de paramètres par son, chargé depuis sounds.ron
+// - render : rendu en échantillons mono (PCM flottant), au démarrage
+//
+// Le rendu est déterministe (le bruit a sa propre graine) : le même fichier
+// donne toujours les mêmes sons. Le binaire sfx_export écrit chaque son en
+// WAV pour l'écouter hors du jeu.
+//
+// =============================================================================
+
+use serde::{Deserialize, Serialize};
+use std::f32::consts::TAU;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+use crate::audio::SoundCue;
+
+/// Emplacement du fichier des effets sonores (builds natifs)
+pub const SOUNDS_PATH: &str = "sounds.ron";
+
+/// Fréquence d'échantillonnage des sons générés
+pub const SAMPLE_RATE: u32 = 22050;
+
+/// Durée maximale d'un son (secondes), garde-fou contre les fautes de frappe
+const MAX_DURATION: f32 = 5.0;
+
+/// Fréquence sous laquelle un son qui descend s'arrête (Hz)
+const MIN_FREQUENCY: f32 = 20.0;
+
+// -----------------------------------------------------------------------------
+// PARAMÈTRES D'UN SON
+// -----------------------------------------------------------------------------
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Waveform {
+    Square,     // Carré (rapport cyclique réglable)
+    Sawtooth,   // Dent de scie
+    Sine,       // Sinus
+    Triangle,   // Triangle
+    Noise,      // Bruit blanc, renouvelé à chaque période (hauteur perceptible)
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct SfxParams {
+    pub waveform: Waveform,     // Forme d'onde (Square)
+    pub volume: f32,            // Volume du son, 0 à 1 (0.5)
+
+    pub attack: f32,            // Montée du volume, secondes (0.0)
+    pub sustain: f32,           // Maintien à plein volume, secondes (0.1)
+    pub punch: f32,             // Surplus de volume au début du maintien, 0 à 1 (0.0)
+    pub decay: f32,             // Extinction, secondes (0.2)
+
+    pub frequency: f32,         // Hauteur de départ, Hz (440)
+    pub slide: f32,             // Glissement de hauteur, octaves/s (0.0)
+    pub delta_slide: f32,       // Variation du glissement, octaves/s² (0.0)
+    pub vibrato_depth: f32,     // Amplitude du vibrato, fraction de la hauteur (0.0)
+    pub vibrato_speed: f32,     // Fréquence du vibrato, Hz (0.0)
+    pub arpeggio: f32,          // Multiplicateur de hauteur de l'arpège, 1 = aucun (1.0)
+    pub arpeggio_time: f32,     // Instant du saut d'arpège, secondes (0.0)
+
+    pub duty: f32,              // Rapport cyclique du carré, 0 à 1 (0.5)
+    pub duty_sweep: f32,        // Variation du rapport cyclique, par seconde (0.0)
+    pub lowpass: f32,           // Ouverture du filtre passe-bas, 1 = aucun filtre (1.0)
+    pub seed: u32,              // Graine du bruit (1)
+}
+
+impl SfxParams {
+    pub fn new() -> Self {
+        Self {
+            waveform: Waveform::Square,
+            volume: 0.5,
+            attack: 0.0,
+            sustain: 0.1,
+            punch: 0.0,
+            decay: 0.2,
+            frequency: 440.0,
+            slide: 0.0,
+            delta_slide: 0.0,
+            vibrato_depth: 0.0,
+            vibrato_speed: 0.0,
+            arpeggio: 1.0,
+            arpeggio_time: 0.0,
+            duty: 0.5,
+            duty_sweep: 0.0,
+            lowpass: 1.0,
+            seed: 1,
+        }
+    }
+
+    /// Durée totale de l'enveloppe (secondes)
+    pub fn duration(&self) -> f32 {
+        self.attack + self.sustain + self.decay
+    }
+
+    /// Volume de l'enveloppe à l'instant `t` (secondes)
+    fn envelope(&self, t: f32) -> f32 {
+        if t < self.attack {
+            t / self.attack
+        } else if t < self.attack + self.sustain {
+            let progress = (t - self.attack) / self.sustain;
+            1.0 + self.punch * (1.0 - progress)
+        } else {
+            let progress = (t - self.attack - self.sustain) / self.decay.max(f32::EPSILON);
+            (1.0 - progress).max(0.0)
+        }
+    }
+
+    /// Hauteur à l'instant `t` (secondes), avant vibrato
+    fn frequency_at(&self, t: f32) -> f32 {
+        let octaves = self.slide * t + 0.5 * self.delta_slide * t * t;
+        let arpeggio = if self.arpeggio_time > 0.0 && t >= self.arpeggio_time {
+            self.arpeggio
+        } else {
+            1.0
+        };
+        self.frequency * octaves.exp2() * arpeggio
+    }
+}
+
+impl Default for SfxParams {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// -----------------------------------------------------------------------------
+// RENDU
+// -----------------------------------------------------------------------------
+
+/// Génère les échantillons mono (-1 à 1) d'un son
+pub fn render(params: &SfxParams, sample_rate: u32) -> Vec<f32> {
+    let rate = sample_rate as f32;
+    let count = (params.duration() * rate) as usize;
+    let mut samples = Vec::with_capacity(count);
+
+    let mut phase = 0.0_f32;
+    let mut noise_seed = params.seed.max(1);
+    let mut noise = next_noise(&mut noise_seed);
+    let mut filtered = 0.0_f32;
+    // Coefficient du passe-bas à un pôle (1 = signal inchangé)
+    let cutoff = params.lowpass.clamp(0.0, 1.0).powi(2);
+
+    for i in 0..count {
+        let t = i as f32 / rate;
+        let vibrato = 1.0 + params.vibrato_depth * (TAU * params.vibrato_speed * t).sin();
+        let frequency = params.frequency_at(t) * vibrato;
+        if frequency < MIN_FREQUENCY && (params.slide < 0.0 || params.delta_slide < 0.0) {
+            break;
+        }
+
+        phase += frequency / rate;
+        if phase >= 1.0 {
+            phase = phase.fract();
+            noise = next_noise(&mut noise_seed);
+        }
+
+        let duty = (params.duty + params.duty_sweep * t).clamp(0.05, 0.95);
+        let wave = match params.waveform {
+            Waveform::Square => if phase < duty { 1.0 } else { -1.0 },
+            Waveform::Sawtooth => 1.0 - phase * 2.0,
+            Waveform::Sine => (phase * TAU).sin(),
+            Waveform::Triangle => 1.0 - (phase * 4.0 - 2.0).abs(),
+            Waveform::Noise => noise,
+        };
+
+        filtered += (wave - filtered) * cutoff;
+        let sample = filtered * params.envelope(t) * params.volume;
+        samples.push(sample.clamp(-1.0, 1.0));
+    }
+    samples
+}
+
+/// Bruit blanc (xorshift 32 bits), de -1 à 1
+fn next_noise(seed: &mut u32) -> f32 {
+    *seed ^= *seed << 13;
+    *seed ^= *seed >> 17;
+    *seed ^= *seed << 5;
+    *seed as f32 / u32::MAX as f32 * 2.0 - 1.0
+}
+
+// -----------------------------------------------------------------------------
+// CONFIGURATION (UN JEU DE PARAMÈTRES PAR SON)
+// -----------------------------------------------------------------------------
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct SfxConfig {
+    pub shot: SfxParams,
+    pub missile: SfxParams,
+    pub laser: SfxParams,
+    pub hit: SfxParams,
+    pub explosion: SfxParams,
+    pub shield_block: SfxParams,
+    pub pickup: SfxParams,
+    pub player_hit: SfxParams,
+    pub wave_complete: SfxParams,
+    pub game_over: SfxParams,
+}
+
+impl SfxConfig {
+    /// Sons d'origine du jeu (ceux de sounds.ron)
+    pub fn new() -> Self {
+        let base = SfxParams::new();
+        Self {
+            shot: SfxParams {
+                volume: 0.4,
+                sustain: 0.03,
+                punch: 0.3,
+                decay: 0.07,
+                frequency: 880.0,
+                slide: -3.0,
+                duty: 0.3,
+                ..base.clone()
+            },
+            missile: SfxParams {
+                waveform: Waveform::Sawtooth,
+                volume: 0.4,
+                sustain: 0.1,
+                decay: 0.2,
+                frequency: 180.0,
+                slide: 1.5,
+                vibrato_depth: 0.05,
+                vibrato_speed: 20.0,
+                lowpass: 0.5,
+                ..base.clone()
+            },
+            laser: SfxParams {
+                waveform: Waveform::Sawtooth,
+                volume: 0.4,
+                sustain: 0.15,
+                decay: 0.3,
+                frequency: 1400.0,
+                slide: -2.5,
+                delta_slide: 2.0,
+                vibrato_depth: 0.1,
+                vibrato_speed: 30.0,
+                lowpass: 0.8,
+                ..base.clone()
+            },
+            hit: SfxParams {
+                waveform: Waveform::Noise,
+                sustain: 0.02,
+                decay: 0.06,
+                frequency: 600.0,
+                slide: -2.0,
+                lowpass: 0.6,
+                seed: 7,
+                ..base.clone()
+            },
+            explosion: SfxParams {
+                waveform: Waveform::Noise,
+                volume: 0.6,
+                sustain: 0.1,
+                punch: 0.5,
+                decay: 0.45,
+                frequency: 180.0,
+                slide: -1.0,
+                lowpass: 0.35,
+                seed: 42,
+                ..base.clone()
+            },
+            shield_block: SfxParams {
+                waveform: Waveform::Triangle,
+                sustain: 0.03,
+                decay: 0.1,
+                frequency: 700.0,
+                slide: 2.0,
+                ..base.clone()
+            },
+            pickup: SfxParams {
+                volume: 0.4,
+                sustain: 0.08,
+                decay: 0.15,
+                frequency: 520.0,
+                arpeggio: 1.5,
+                arpeggio_time: 0.06,
+                ..base.clone()
+            },
+            player_hit: SfxParams {
+                waveform: Waveform::Sawtooth,
+                volume: 0.6,
+                sustain: 0.1,
+                punch: 0.4,
+                decay: 0.3,
+                frequency: 260.0,
+                slide: -2.0,
+                lowpass: 0.5,
+                ..base.clone()
+            },
+            wave_complete: SfxParams {
+                volume: 0.4,
+                sustain: 0.3,
+                decay: 0.4,
+                frequency: 440.0,
+                vibrato_depth: 0.02,
+                vibrato_speed: 8.0,
+                arpeggio: 2.0,
+                arpeggio_time: 0.15,
+                ..base.clone()
+            },
+            game_over: SfxParams {
+                waveform: Waveform::Triangle,
+                volume: 0.6,
+                sustain: 0.5,
+                decay: 0.8,
+                frequency: 330.0,
+                slide: -1.0,
+                vibrato_depth: 0.03,
+                vibrato_speed: 6.0,
+                ..base
+            },
+        }
+    }
+
+    /// Paramètres d'un son
+    pub fn get(&self, cue: SoundCue) -> &SfxParams {
+        match cue {
+            SoundCue::Shot => &self.shot,
+            SoundCue::Missile => &self.missile,
+            SoundCue::Laser => &self.laser,
+            SoundCue::Hit => &self.hit,
+            SoundCue::Explosion => &self.explosion,
+            SoundCue::ShieldBlock => &self.shield_block,
+            SoundCue::Pickup => &self.pickup,
+            SoundCue::PlayerHit => &self.player_hit,
+            SoundCue::WaveComplete => &self.wave_complete,
+            SoundCue::GameOver => &self.game_over,
+        }
+    }
+
+    /// Vérifie que chaque son est jouable
+    pub fn validate(&self) -> Result<(), SynthError> {
+        for cue in SoundCue::ALL {
+            let p = self.get(cue);
+            let check = |field: &'static str, ok: bool, reason: &'static str| {
+                if ok { Ok(()) } else { Err(SynthError::Invalid { cue, field, reason }) }
+            };
+            let finite = [
+                p.volume, p.attack, p.sustain, p.punch, p.decay, p.frequency, p.slide,
+                p.delta_slide, p.vibrato_depth, p.vibrato_speed, p.arpeggio,
+                p.arpeggio_time, p.duty, p.duty_sweep, p.lowpass,
+            ];
+            check("les paramètres", finite.iter().all(|v| v.is_finite()), "doivent être des nombres finis")?;
+            check("volume", (0.0..=1.0).contains(&p.volume), "doit être compris entre 0 et 1")?;
+            check("attack", p.attack >= 0.0, "ne peut pas être négatif")?;
+            check("sustain", p.sustain >= 0.0, "ne peut pas être négatif")?;
+            check("decay", p.decay >= 0.0, "ne peut pas être négatif")?;
+            check("punch", (0.0..=1.0).contains(&p.punch), "doit être compris entre 0 et 1")?;
+            check("attack + sustain + decay", p.duration() > 0.0, "ne peut pas être nul")?;
+            check("attack + sustain + decay", p.duration() <= MAX_DURATION, "ne peut pas dépasser 5 secondes")?;
+            check("frequency", p.frequency >= MIN_FREQUENCY, "doit valoir au moins 20 Hz")?;
+            check("vibrato_depth", (0.0..1.0).contains(&p.vibrato_depth), "doit être compris entre 0 et 1")?;
+            check("vibrato_speed", p.vibrato_speed >= 0.0, "ne peut pas être négatif")?;
+            check("arpeggio", p.arpeggio > 0.0, "doit être strictement positif")?;
+            check("arpeggio_time", p.arpeggio_time >= 0.0, "ne peut pas être négatif")?;
+            check("duty", (0.0..=1.0).contains(&p.duty), "doit être compris entre 0 et 1")?;
+            check("lowpass", p.lowpass > 0.0 && p.lowpass <= 1.0, "doit être compris entre 0 (exclu) et 1")?;
+        }
+        Ok(())
+    }
+
+    /// Lit une configuration depuis du texte RON, puis la valide
+    pub fn from_ron(text: &str) -> Result<Self, SynthError> {
+        let config: SfxConfig = ron::from_str(text)?;
+        config.validate()?;
+        Ok(config)
+    }
+
+    /// Lit et valide le fichier des sons
+    /// Un fichier absent donne les sons par défaut
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, SynthError> {
+        match fs::read_to_string(path) {
+            Ok(text) => Self::from_ron(&text),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Self::new()),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// Sérialise la configuration en RON (ex : exporter les valeurs par défaut)
+    pub fn to_ron(&self) -> Result<String, ron::Error> {
+        ron::ser::to_string_pretty(self, ron::ser::PrettyConfig::default())
+    }
+}
+
+impl Default for SfxConfig {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// -----------------------------------------------------------------------------
+// ERREURS
+// -----------------------------------------------------------------------------
+#[derive(Debug)]
+pub enum SynthError {
+    Io(io::Error),                      // Lecture impossible
+    Parse(ron::error::SpannedError),    // Syntaxe ou champ inconnu
+    Invalid {                           // Paramètre hors limites
+        cue: SoundCue,
+        field: &'static str,
+        reason: &'static str,
+    },
+}
+
+impl fmt::Display for SynthError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SynthError::Io(e) => write!(f, "erreur d'accès aux sons : {e}"),
+            SynthError::Parse(e) => write!(f, "fichier des sons illisible : {e}"),
+            SynthError::Invalid { cue, field, reason } => {
+                write!(f, "son {cue:?} invalide : {field} {reason}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for SynthError {}
+
+impl From<io::Error> for SynthError {
+    fn from(e: io::Error) -> Self {
+        SynthError::Io(e)
+    }
+}
+
+impl From<ron::error::SpannedError> for SynthError {
+    fn from(e: ron::error::SpannedError) -> Self {
+        SynthError::Parse(e)
+    }
+}
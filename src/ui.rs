@@ -4,16 +4,24 @@
 //
 // Ce fichier gère tous les éléments d'interface :
 // - HUD (score, vague, temps, barres d'état)
+// - Minicarte du monde (coin inférieur droit)
 // - Écran titre (menu principal)
+// - Écran de configuration des touches
 // - Écran de pause
 // - Écran de game over
+// - Bandeau de relecture (replay)
 // - Indicateurs d'armes et de bonus
 //
 // =============================================================================
 
 use macroquad::prelude::*;
 use crate::consts::*;
+use crate::arena::ArenaMode;
+use crate::boss::{self, BossPartKind};
+use crate::bindings::{Action, ControlScheme, KeyBindings, KeyboardLayout};
 use crate::game::Game;
+use crate::scores::ScoreEntry;
+use crate::stats::RunStats;
 
 pub struct UI;
 
@@ -25,9 +33,9 @@ impl UI {
     /// Dessine l'interface complète pendant le jeu
     pub fn draw_hud(game: &Game) {
         // Score et vague
-        draw_text(&format!("SCORE: {}", game.score), 20., 30., 30., WHITE);
-        draw_text(&format!("VAGUE: {}", game.wave), 20., 60., 25., GRAY);
-        draw_text(&format!("TEMPS: {:.1}s", game.timer), 20., 90., 20., LIGHTGRAY);
+        draw_text(format!("SCORE: {}", game.score), 20., 30., 30., WHITE);
+        draw_text(format!("VAGUE: {}", game.wave), 20., 60., 25., GRAY);
+        draw_text(format!("TEMPS: {:.1}s", game.timer), 20., 90., 20., LIGHTGRAY);
 
         // Barre de bouclier
         draw_rectangle(20., 110., UI_BAR_WIDTH, UI_BAR_HEIGHT, DARKGRAY);
@@ -59,14 +67,14 @@ impl UI {
         // Combo
         if game.combo > 1 {
             draw_text(
-                &format!("COMBO x{}!", game.combo),
+                format!("COMBO x{}!", game.combo),
                 screen_width() - 150.,
                 30.,
                 30.,
                 YELLOW,
             );
             draw_text(
-                &format!("+{}", game.combo * 5),
+                format!("+{}", game.combo * 5),
                 screen_width() - 150.,
                 60.,
                 20.,
@@ -74,6 +82,9 @@ impl UI {
             );
         }
 
+        // Vaisseau-mère
+        Self::draw_boss_bar(game);
+
         // Indicateurs d'état (bonus actifs)
         Self::draw_status_indicators(game);
 
@@ -81,7 +92,7 @@ impl UI {
         Self::draw_weapon_indicators(game);
 
         // Raccourcis clavier
-        Self::draw_controls();
+        Self::draw_controls(&game.settings.bindings, game.settings.control_scheme);
     }
 
     /// Dessine les indicateurs de bonus actifs
@@ -90,7 +101,7 @@ impl UI {
 
         if game.ship.slowmo_active {
             draw_text(
-                &format!("SLOW-MO: {:.1}s", game.ship.slowmo_energy.time),
+                format!("SLOW-MO: {:.1}s", game.ship.slowmo_energy.time),
                 screen_width() - 200.,
                 y_offset,
                 20.,
@@ -101,7 +112,7 @@ impl UI {
 
         if !game.ship.rapid_fire_timer.ready() {
             draw_text(
-                &format!("TIR RAPIDE: {:.1}s", game.ship.rapid_fire_timer.time),
+                format!("TIR RAPIDE: {:.1}s", game.ship.rapid_fire_timer.time),
                 screen_width() - 200.,
                 y_offset,
                 20.,
@@ -123,7 +134,7 @@ impl UI {
 
         if game.ship.is_invincible() {
             draw_text(
-                &format!("INVINCIBLE: {:.1}s", game.ship.invincible_timer.time),
+                format!("INVINCIBLE: {:.1}s", game.ship.invincible_timer.time),
                 screen_width() - 200.,
                 y_offset,
                 20.,
@@ -136,16 +147,24 @@ impl UI {
     fn draw_weapon_indicators(game: &Game) {
         // Missiles
         draw_text(
-            &format!("MISSILE: {} / {}", game.weapons.missile_ammo, MISSILE_AMMO_MAX),
+            format!("MISSILE: {} / {}", game.weapons.missile_ammo, game.balance.weapons.missile_ammo_max),
             20.,
             170.,
             20.,
             ORANGE,
         );
 
-        if !game.weapons.missile_cooldown.ready() {
+        if game.weapons.locking {
+            draw_text(
+                format!("VERROU: {}", game.weapons.locks.len()),
+                150.,
+                170.,
+                20.,
+                ORANGE,
+            );
+        } else if !game.weapons.missile_cooldown.ready() {
             draw_text(
-                &format!("({:.1}s)", game.weapons.missile_cooldown.time),
+                format!("({:.1}s)", game.weapons.missile_cooldown.time),
                 150.,
                 170.,
                 16.,
@@ -161,7 +180,7 @@ impl UI {
             draw_text("ACTIF", 90., 200., 20., RED);
         } else if !game.weapons.laser.cooldown.ready() {
             draw_text(
-                &format!("RECHARGE {:.1}s", game.weapons.laser.cooldown.time),
+                format!("RECHARGE {:.1}s", game.weapons.laser.cooldown.time),
                 90.,
                 200.,
                 16.,
@@ -172,19 +191,107 @@ impl UI {
         }
     }
 
-    /// Dessine les raccourcis clavier en bas de l'écran
-    fn draw_controls() {
-        draw_text(
+    /// Barre de vie du vaisseau-mère en haut de l'écran (vague de boss)
+    pub fn draw_boss_bar(game: &Game) {
+        let Some(core) = boss::core(&game.world.boss_parts) else {
+            return;
+        };
+        let turrets = game
+            .world
+            .boss_parts
+            .iter()
+            .filter(|p| p.kind == BossPartKind::Turret && p.is_alive())
+            .count();
+        let x = (screen_width() - UI_BOSS_BAR_WIDTH) / 2.;
+        let y = UI_MARGIN * 2.;
+
+        Self::draw_text_centered(&format!("VAISSEAU-MÈRE — PHASE {}", core.phase), y - 8., 22., MAGENTA);
+        draw_rectangle(x, y, UI_BOSS_BAR_WIDTH, UI_BAR_HEIGHT * 1.5, DARKGRAY);
+        draw_rectangle(x, y, core.health_percent() * UI_BOSS_BAR_WIDTH, UI_BAR_HEIGHT * 1.5, MAGENTA);
+        draw_rectangle_lines(x, y, UI_BOSS_BAR_WIDTH, UI_BAR_HEIGHT * 1.5, 1., WHITE);
+
+        // Seuils de phase
+        for threshold in [game.balance.boss.phase2_health, game.balance.boss.phase3_health] {
+            let tx = x + threshold * UI_BOSS_BAR_WIDTH;
+            draw_line(tx, y, tx, y + UI_BAR_HEIGHT * 1.5, 2., WHITE);
+        }
+
+        if turrets > 0 {
+            Self::draw_text_centered(&format!("TOURELLES : {}", turrets), y + 35., 18., LIGHTGRAY);
+        }
+    }
+
+    /// Dessine la minicarte du monde dans le coin inférieur droit :
+    /// astéroïdes, drones, power-ups, vaisseau et cadre de la vue `view`
+    pub fn draw_minimap(game: &Game, view: Rect) {
+        let arena = &game.arena;
+        let scale = UI_MINIMAP_WIDTH / arena.size.x;
+        let size = arena.size * scale;
+        let origin = vec2(
+            screen_width() - UI_MARGIN - size.x,
+            screen_height() - 40. - size.y,
+        );
+        let map = Rect::new(origin.x, origin.y, size.x, size.y);
+        draw_rectangle(map.x, map.y, map.w, map.h, Color::new(0.0, 0.0, 0.0, 0.5));
+
+        // Entités (ramenées dans le monde en arène torique)
+        let dot = |pos: Vec2, radius: f32, color: Color| {
+            let p = origin + arena.wrap(pos) * scale;
+            if map.contains(p) {
+                draw_circle(p.x, p.y, radius, color);
+            }
+        };
+        for a in game.world.asteroids.iter() {
+            dot(a.pos, (a.radius * scale).max(1.5), GRAY);
+        }
+        for d in game.world.drones.iter() {
+            dot(d.pos, 2., RED);
+        }
+        for p in game.world.powerups.iter() {
+            dot(p.pos, 2., p.color());
+        }
+        for p in game.world.boss_parts.iter() {
+            let radius = match p.kind {
+                BossPartKind::Core => 4.,
+                BossPartKind::Turret => 2.,
+            };
+            dot(p.pos, radius, MAGENTA);
+        }
+        dot(game.ship.pos, 3., SKYBLUE);
+
+        // Cadre de la vue, coupé au bord de la carte (en arène torique, il
+        // peut réapparaître du côté opposé)
+        for offset in arena.tiles() {
+            let frame = Rect::new(
+                origin.x + (view.x + offset.x) * scale,
+                origin.y + (view.y + offset.y) * scale,
+                view.w * scale,
+                view.h * scale,
+            );
+            if let Some(part) = frame.intersect(map) {
+                draw_rectangle_lines(part.x, part.y, part.w, part.h, 1., WHITE);
+            }
+        }
+        draw_rectangle_lines(map.x, map.y, map.w, map.h, 1., GRAY);
+    }
+
+    /// Dessine les raccourcis clavier en bas de l'écran (touches configurées)
+    pub fn draw_controls(bindings: &KeyBindings, scheme: ControlScheme) {
+        let key = |action| bindings.describe(action);
+        let missile = match scheme {
+            ControlScheme::Tank => key(Action::Missile),
+            ControlScheme::TwinStick => format!("{}/CLIC DROIT", key(Action::Missile)),
+        };
+        Self::draw_text_centered(
             &format!(
                 "{}:PAUSE | {}:BOUCLIER | {}:RECUL | {}:SLOW-MO | {}:MISSILE | {}:LASER",
-                format!("{:?}", KEY_PAUSE),
-                format!("{:?}", KEY_SHIELD),
-                format!("{:?}", KEY_REVERSE_BOOST),
-                format!("{:?}", KEY_SLOWMO),
-                format!("{:?}", KEY_MISSILE),
-                format!("{:?}", KEY_LASER)
+                key(Action::Pause),
+                key(Action::Shield),
+                key(Action::Boost),
+                key(Action::Slowmo),
+                missile,
+                key(Action::Laser),
             ),
-            screen_width() / 2. - 280.,
             screen_height() - 20.,
             20.,
             GRAY,
@@ -196,36 +303,56 @@ impl UI {
     // ÉCRANS DE MENU
     // -------------------------------------------------------------------------
 
-    /// Dessine l'écran titre (menu principal)
     // =============================================================================
     // SCREEN: MAIN MENU
     // =============================================================================
 
-    pub fn draw_menu(high_score: i32) {
+    /// Dessine l'écran titre (menu principal)
+    pub fn draw_menu(
+        high_score: i32,
+        can_continue: bool,
+        bindings: &KeyBindings,
+        scheme: ControlScheme,
+        arena_mode: ArenaMode,
+    ) {
+        let key = |action| bindings.describe(action);
         Self::draw_text_centered("Void Survivor", screen_height() * 0.3, 48., YELLOW);
         Self::draw_text_centered("CONTRÔLES:", screen_height() * 0.45, 30., WHITE);
 
-        // Mouvements : les touches exactes depuis consts.rs
-        Self::draw_text_centered(
-            &format!(
-                "{} / {} : Tourner | {} : Avancer | {} : Reculer",
-                format!("{:?}", KEY_LEFT),
-                format!("{:?}", KEY_RIGHT),
-                format!("{:?}", KEY_UP),
-                format!("{:?}", KEY_DOWN)
+        // Mouvements : les touches configurées par le joueur
+        let (movement, shoot, missile) = match scheme {
+            ControlScheme::Tank => (
+                format!(
+                    "{} / {} : Tourner | {} : Avancer | {} : Reculer",
+                    key(Action::TurnLeft),
+                    key(Action::TurnRight),
+                    key(Action::Thrust),
+                    key(Action::Brake),
+                ),
+                key(Action::Shoot),
+                key(Action::Missile),
             ),
-            screen_height() * 0.5,
-            20.,
-            GRAY,
-        );
+            ControlScheme::TwinStick => (
+                format!(
+                    "Souris : Viser | {} / {} / {} / {} : Haut / Gauche / Bas / Droite",
+                    key(Action::Thrust),
+                    key(Action::TurnLeft),
+                    key(Action::Brake),
+                    key(Action::TurnRight),
+                ),
+                format!("Clic gauche/{}", key(Action::Shoot)),
+                format!("Clic droit/{}", key(Action::Missile)),
+            ),
+        };
+        Self::draw_text_centered(&movement, screen_height() * 0.5, 20., GRAY);
 
         // Tir, bouclier, recul boosté
         Self::draw_text_centered(
             &format!(
                 "{} : Tirer | {} : Bouclier | {} : Recul rapide",
-                format!("{:?}", KEY_SHOOT),
-                format!("{:?}", KEY_SHIELD),
-                format!("{:?}", KEY_REVERSE_BOOST)
+                shoot,
+                key(Action::Shield),
+                key(Action::Boost),
             ),
             screen_height() * 0.55,
             20.,
@@ -235,10 +362,9 @@ impl UI {
         // Slow-motion, pause, menu
         Self::draw_text_centered(
             &format!(
-                "{} : Slow‑motion (limité) | {} : Pause | {} : Menu",
-                format!("{:?}", KEY_SLOWMO),
-                format!("{:?}", KEY_PAUSE),
-                format!("{:?}", KEY_MENU)
+                "{} : Slow‑motion (limité) | {} : Pause | {KEY_MENU:?} : Menu",
+                key(Action::Slowmo),
+                key(Action::Pause),
             ),
             screen_height() * 0.6,
             20.,
@@ -249,8 +375,8 @@ impl UI {
         Self::draw_text_centered(
             &format!(
                 "{} : Missile téléguidé | {} : Laser perçant",
-                format!("{:?}", KEY_MISSILE),
-                format!("{:?}", KEY_LASER)
+                missile,
+                key(Action::Laser),
             ),
             screen_height() * 0.65,
             20.,
@@ -258,25 +384,184 @@ impl UI {
         );
 
         Self::draw_text_centered(
-            &format!("MEILLEUR SCORE: {}", high_score),
+            &format!("{KEY_REPLAY:?} : Revoir la dernière partie | {KEY_BINDINGS:?} : Configurer les touches"),
+            screen_height() * 0.70,
+            20.,
+            GRAY,
+        );
+
+        Self::draw_text_centered(
+            &format!("MEILLEUR SCORE: {}   ([{KEY_SCORES:?}] CLASSEMENT)", high_score),
             screen_height() * 0.75,
             28.,
             GOLD,
         );
 
         Self::draw_text_centered(
-            &format!(
-                "APPUYEZ SUR [{}] POUR COMMENCER",
-                format!("{:?}", KEY_START)
-            ),
+            &format!("ARÈNE : {}   ([{KEY_ARENA_MODE:?}] CHANGER)", arena_mode.label().to_uppercase()),
+            screen_height() * 0.80,
+            22.,
+            WHITE,
+        );
+
+        Self::draw_text_centered(
+            &format!("APPUYEZ SUR [{KEY_START:?}] POUR COMMENCER"),
             screen_height() * 0.85,
             28.,
             GREEN,
         );
+
+        if can_continue {
+            Self::draw_text_centered(
+                &format!("[{KEY_CONTINUE:?}] CONTINUER LA PARTIE SAUVEGARDÉE"),
+                screen_height() * 0.89,
+                22.,
+                SKYBLUE,
+            );
+        }
+    }
+
+    /// Dessine l'écran de configuration des touches
+    /// `capturing` : l'action sélectionnée attend une nouvelle touche
+    pub fn draw_bindings_screen(
+        bindings: &KeyBindings,
+        layout: KeyboardLayout,
+        scheme: ControlScheme,
+        selected: usize,
+        capturing: bool,
+        status: Option<&str>,
+    ) {
+        Self::draw_text_centered("TOUCHES", screen_height() * 0.12, 40., YELLOW);
+        Self::draw_text_centered(
+            &format!("Préréglage : {} | Contrôle : {}", layout.label(), scheme.label()),
+            screen_height() * 0.17,
+            20.,
+            GRAY,
+        );
+
+        // Actions en conflit (fichier édité à la main)
+        let conflicts = bindings.conflicts();
+        let x = screen_width() / 2. - 220.;
+        let mut y = screen_height() * 0.24;
+        for (i, action) in Action::ALL.into_iter().enumerate() {
+            let in_conflict = conflicts.iter().any(|c| c.involves(action));
+            let color = if i == selected {
+                if capturing { ORANGE } else { YELLOW }
+            } else if in_conflict {
+                RED
+            } else {
+                WHITE
+            };
+            let keys = if i == selected && capturing {
+                "... appuyez sur une touche".to_string()
+            } else {
+                bindings.describe(action)
+            };
+
+            if i == selected {
+                draw_text(">", x - 25., y, 24., color);
+            }
+            draw_text(action.label(), x, y, 24., color);
+            draw_text(&keys, x + 240., y, 24., color);
+            y += 30.;
+        }
+
+        if let Some(status) = status {
+            Self::draw_text_centered(status, screen_height() * 0.84, 20., ORANGE);
+        }
+
+        Self::draw_text_centered(
+            &format!(
+                "{KEY_MENU_UP:?}/{KEY_MENU_DOWN:?} : CHOISIR | {KEY_START:?} : AJOUTER UNE TOUCHE | {KEY_CLEAR_BINDING:?} : EFFACER"
+            ),
+            screen_height() * 0.9,
+            20.,
+            GREEN,
+        );
+        Self::draw_text_centered(
+            &format!(
+                "{KEY_PRESET_QWERTY:?} : QWERTY | {KEY_PRESET_AZERTY:?} : AZERTY | {KEY_TOGGLE_SCHEME:?} : CLAVIER / SOURIS | {KEY_MENU:?} : ENREGISTRER ET QUITTER"
+            ),
+            screen_height() * 0.94,
+            20.,
+            GREEN,
+        );
+    }
+
+    /// Dessine le viseur au curseur de la souris (schéma twin-stick)
+    pub fn draw_crosshair() {
+        let (x, y) = mouse_position();
+        draw_circle_lines(x, y, 8., 1.5, SKYBLUE);
+        draw_line(x - 12., y, x - 4., y, 1.5, SKYBLUE);
+        draw_line(x + 4., y, x + 12., y, 1.5, SKYBLUE);
+        draw_line(x, y - 12., x, y - 4., 1.5, SKYBLUE);
+        draw_line(x, y + 4., x, y + 12., 1.5, SKYBLUE);
+    }
+
+    /// Dessine le classement des meilleures parties
+    pub fn draw_leaderboard(entries: &[ScoreEntry]) {
+        Self::draw_text_centered("MEILLEURS SCORES", screen_height() * 0.15, 40., YELLOW);
+
+        // Colonnes (la police n'est pas à chasse fixe)
+        let x = screen_width() / 2. - 340.;
+        let columns = [0., 40., 200., 290., 360., 450., 570.];
+        let draw_row = |cells: [String; 7], y: f32, color: Color| {
+            for (cell, dx) in cells.iter().zip(columns) {
+                draw_text(cell, x + dx, y, 20., color);
+            }
+        };
+
+        let mut y = screen_height() * 0.25;
+        draw_row(
+            ["#", "NOM", "SCORE", "VAGUE", "TEMPS", "DATE", "GRAINE"].map(String::from),
+            y,
+            GRAY,
+        );
+
+        if entries.is_empty() {
+            Self::draw_text_centered("Aucune partie enregistrée", screen_height() * 0.4, 25., GRAY);
+        }
+
+        for (i, e) in entries.iter().enumerate() {
+            y += 28.;
+            let color = if i == 0 { GOLD } else { WHITE };
+            draw_row(
+                [
+                    format!("{}", i + 1),
+                    e.name.clone(),
+                    e.score.to_string(),
+                    e.wave.to_string(),
+                    format!("{:.1}s", e.survival_time),
+                    e.date.clone(),
+                    e.seed.to_string(),
+                ],
+                y,
+                color,
+            );
+        }
+
+        Self::draw_text_centered(
+            &format!("[{KEY_SCORES:?}] RETOUR | [{KEY_START:?}] JOUER"),
+            screen_height() * 0.9,
+            22.,
+            GREEN,
+        );
+    }
+
+    /// Dessine un message d'état sous le menu (ex : erreur de chargement)
+    pub fn draw_menu_status(message: &str) {
+        Self::draw_text_centered(message, screen_height() * 0.92, 20., ORANGE);
+    }
+
+    /// Dessine une notification temporaire en haut de l'écran
+    /// (ex : rechargement de balance.ron)
+    pub fn draw_notice(message: &str, is_error: bool) {
+        let color = if is_error { RED } else { GREEN };
+        Self::draw_text_centered(message, UI_MARGIN * 2.5, 20., color);
     }
 
     /// Dessine l'écran de pause
-    pub fn draw_pause_screen(score: i32) {
+    pub fn draw_pause_screen(score: i32, bindings: &KeyBindings) {
         // Fond semi-transparent
         draw_rectangle(
             0.,
@@ -287,8 +572,13 @@ impl UI {
         );
 
         Self::draw_text_centered("PAUSE", screen_height() * 0.4, 50., YELLOW);
-        Self::draw_text_centered("P : REPRENDRE", screen_height() * 0.5, 30., WHITE);
-        Self::draw_text_centered("ECHAP : MENU", screen_height() * 0.55, 30., WHITE);
+        Self::draw_text_centered(
+            &format!("{} : REPRENDRE", bindings.describe(Action::Pause)),
+            screen_height() * 0.5,
+            30.,
+            WHITE,
+        );
+        Self::draw_text_centered("ECHAP : SAUVEGARDER ET QUITTER", screen_height() * 0.55, 30., WHITE);
         Self::draw_text_centered(
             &format!("SCORE: {}", score),
             screen_height() * 0.6,
@@ -298,7 +588,16 @@ impl UI {
     }
 
     /// Dessine l'écran de game over
-    pub fn draw_game_over_screen(score: i32, high_score: i32, wave: i32, flash_timer: f32) {
+    /// `name_entry` contient le nom en cours de saisie si le score est classé
+    pub fn draw_game_over_screen(
+        score: i32,
+        is_record: bool,
+        wave: i32,
+        stats: &RunStats,
+        name_entry: Option<&str>,
+        rank: Option<usize>,
+        flash_timer: f32,
+    ) {
         // Effet de flash rouge si nécessaire
         if flash_timer > 0.0 {
             draw_rectangle(
@@ -319,7 +618,7 @@ impl UI {
         );
 
         // Message spécial pour nouveau record
-        if score > high_score {
+        if is_record {
             Self::draw_text_centered("NOUVEAU RECORD !", screen_height() * 0.45, 35., GOLD);
         }
 
@@ -329,20 +628,121 @@ impl UI {
             30.,
             GRAY,
         );
+
+        // Statistiques de la partie
+        let accuracy = stats
+            .cannon_accuracy()
+            .map_or("-".to_string(), |a| format!("{:.0}%", a * 100.));
+        Self::draw_text_centered(
+            &format!(
+                "ENNEMIS DÉTRUITS : {} (canon {} | missile {} | laser {}) | PRÉCISION : {}",
+                stats.kills.total(),
+                stats.kills.cannon,
+                stats.kills.missile,
+                stats.kills.laser,
+                accuracy,
+            ),
+            screen_height() * 0.54,
+            20.,
+            GRAY,
+        );
+
+        // Saisie du nom pour le classement
+        if let Some(name) = name_entry {
+            let cursor = if (get_time() * 2.0) as i32 % 2 == 0 { "_" } else { " " };
+            Self::draw_text_centered(
+                "SCORE CLASSÉ ! ENTREZ VOTRE NOM :",
+                screen_height() * 0.6,
+                25.,
+                YELLOW,
+            );
+            Self::draw_text_centered(
+                &format!("{name}{cursor}"),
+                screen_height() * 0.67,
+                35.,
+                WHITE,
+            );
+            Self::draw_text_centered(
+                &format!("[{KEY_START:?}] VALIDER | [{KEY_MENU:?}] IGNORER"),
+                screen_height() * 0.74,
+                20.,
+                GRAY,
+            );
+            return;
+        }
+
+        if let Some(rank) = rank {
+            Self::draw_text_centered(
+                &format!("CLASSÉ N°{} !", rank + 1),
+                screen_height() * 0.58,
+                25.,
+                GOLD,
+            );
+        }
+
         Self::draw_text_centered(
             "ENTRÉE POUR RECOMMENCER",
-            screen_height() * 0.6,
+            screen_height() * 0.63,
             25.,
             GREEN,
         );
         Self::draw_text_centered(
             "ECHAP POUR MENU",
-            screen_height() * 0.65,
+            screen_height() * 0.68,
             20.,
             GRAY,
         );
     }
 
+    /// Dessine le bandeau de contrôle pendant la relecture d'un replay
+    pub fn draw_replay_overlay(
+        tick: usize,
+        total: usize,
+        speed: f32,
+        paused: bool,
+        same_version: bool,
+        bindings: &KeyBindings,
+    ) {
+        draw_rectangle(0., 0., screen_width(), 40., Color::new(0., 0., 0., 0.6));
+
+        let state = if tick >= total {
+            "TERMINÉ".to_string()
+        } else if paused {
+            "PAUSE".to_string()
+        } else {
+            format!("x{speed}")
+        };
+        Self::draw_text_centered(
+            &format!("REPLAY {state}  -  TICK {tick} / {total}"),
+            28.,
+            25.,
+            YELLOW,
+        );
+
+        // Barre de progression
+        let progress = if total > 0 { tick as f32 / total as f32 } else { 1.0 };
+        draw_rectangle(0., 38., screen_width() * progress, 2., GOLD);
+
+        Self::draw_text_centered(
+            &format!(
+                "{} : PAUSE | {KEY_REPLAY_STEP:?} : TICK SUIVANT | {KEY_REPLAY_1X:?}/{KEY_REPLAY_2X:?}/{KEY_REPLAY_4X:?} : VITESSE | {KEY_MENU:?} : MENU",
+                bindings.describe(Action::Pause)
+            ),
+            screen_height() - 20.,
+            18.,
+            GRAY,
+        );
+
+        if !same_version {
+            Self::draw_text_centered(
+                "Replay d'une autre version : la relecture peut diverger",
+                screen_height() - 45.,
+                18.,
+                ORANGE,
+            );
+        }
+    }
+
     // -------------------------------------------------------------------------
     // FONCTIONS UTILITAIRES
     // -------------------------------------------------------------------------
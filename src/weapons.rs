@@ -7,19 +7,62 @@
 // - Missiles à tête chercheuse
 // - Laser perçant
 //
+// Missiles : maintenir l'action Missile verrouille des cibles (une toutes
+// les `missile_lock_time`, jusqu'au nombre de munitions), la relâcher tire
+// une salve. Chaque missile garde sa cible (par EntityId) et n'en cherche
+// une nouvelle, la plus proche, que si elle est détruite.
+//
+// En arène torique, portée de verrouillage, choix de la cible la plus
+// proche et poursuite passent par le plus court chemin à travers les bords ;
+// le rayon du laser traverse les bords et touche les copies des ennemis.
+//
 // =============================================================================
 
 use macroquad::prelude::*;
-use crate::consts::*;
+use serde::{Deserialize, Serialize};
+use crate::arena::Arena;
+use crate::balance::WeaponBalance;
+use crate::collider::Collider;
+use crate::consts::{KNOCKBACK_LASER, KNOCKBACK_MISSILE, LASER_HALF_WIDTH, MISSILE_RADIUS};
+use crate::damage::{DamageContext, DamageInfo, Damageable};
 use crate::ship::Ship;
 use crate::game::{World, Cooldown};
+use crate::collisions::{earliest, first_contact, Target};
 use crate::enemies::*;
-use crate::effects::EffectSystem;
-use crate::events::EventQueue;
+use crate::events::{Event, EventBus};
+use crate::input::InputState;
+use crate::slotmap::{EntityId, SlotMap};
+use crate::spatial::SpatialGrid;
+
+// -----------------------------------------------------------------------------
+// ORIGINE DES DÉGÂTS (ÉVÉNEMENTS, STATISTIQUES)
+// -----------------------------------------------------------------------------
+#[derive(PartialEq, Eq, Clone, Copy, Debug, Serialize, Deserialize)]
+pub enum WeaponKind {
+    Cannon,     // Tir normal
+    Missile,    // Missile téléguidé
+    Laser,      // Laser perçant
+    Shield,     // Rebond sur le bouclier
+    Collision,  // Impact (vaisseau, astéroïde)
+}
+
+impl WeaponKind {
+    /// Arme maniée par le joueur (points, combo et power-ups)
+    pub fn is_player_weapon(&self) -> bool {
+        !matches!(self, WeaponKind::Collision)
+    }
+
+    /// Arme qui touche à chaque tick tant qu'elle est active : ses coups
+    /// ne rapportent ni points ni combo (seules ses destructions comptent)
+    pub fn is_continuous(&self) -> bool {
+        matches!(self, WeaponKind::Laser)
+    }
+}
 
 // -----------------------------------------------------------------------------
 // SYSTÈME PRINCIPAL D'ARMES
 // -----------------------------------------------------------------------------
+#[derive(Clone, Serialize, Deserialize)]
 pub struct WeaponSystem {
     // Tir normal
     pub fire_cooldown: Cooldown,
@@ -27,8 +70,14 @@ pub struct WeaponSystem {
     // Missiles
     pub missile_ammo: i32,
     pub missile_cooldown: Cooldown,
-    pub missiles: Vec<HomingMissile>,
+    pub missiles: SlotMap<HomingMissile>,
     pub ammo_regen_timer: f32,
+    #[serde(default)]
+    pub locks: Vec<TargetId>,     // Cibles verrouillées pour la prochaine salve
+    #[serde(default)]
+    pub locking: bool,            // Action Missile maintenue, verrouillage en cours
+    #[serde(default)]
+    pub lock_timer: f32,          // Temps avant le prochain verrouillage
     
     // Laser
     pub laser: Laser,
@@ -37,23 +86,83 @@ pub struct WeaponSystem {
 // -----------------------------------------------------------------------------
 // MISSILE À TÊTE CHERCHEUSE
 // -----------------------------------------------------------------------------
+#[derive(Clone, Serialize, Deserialize)]
 pub struct HomingMissile {
     pub pos: Vec2,
+    pub prev_pos: Vec2,     // Position au tick précédent (rendu, balayage des collisions)
     pub vel: Vec2,
     pub life: f32,
     pub speed: f32,
     pub hit: bool,  // Évite les collisions multiples
+    #[serde(default)]
+    pub target: Option<TargetId>,   // Cible poursuivie (gardée jusqu'à sa mort)
+}
+
+// -----------------------------------------------------------------------------
+// CIBLE (VERROUILLAGE DES MISSILES)
+// -----------------------------------------------------------------------------
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TargetId {
+    Drone(EntityId),
+    Asteroid(EntityId),
+    Boss(EntityId),
+}
+
+impl TargetId {
+    /// Position de la cible si elle est toujours en vie
+    pub fn position(&self, world: &World) -> Option<Vec2> {
+        match *self {
+            TargetId::Drone(id) => world.drones.get(id).filter(|d| !d.is_destroyed()).map(|d| d.pos),
+            TargetId::Asteroid(id) => world.asteroids.get(id).filter(|a| !a.is_destroyed()).map(|a| a.pos),
+            TargetId::Boss(id) => world.boss_parts.get(id).filter(|p| !p.is_destroyed()).map(|p| p.pos),
+        }
+    }
+
+    /// Position interpolée pour le rendu (None si la cible est morte)
+    pub fn render_position(&self, world: &World, alpha: f32) -> Option<Vec2> {
+        match *self {
+            TargetId::Drone(id) => world.drones.get(id).map(|d| d.prev_pos.lerp(d.pos, alpha)),
+            TargetId::Asteroid(id) => world.asteroids.get(id).map(|a| a.prev_pos.lerp(a.pos, alpha)),
+            TargetId::Boss(id) => world.boss_parts.get(id).map(|p| p.prev_pos.lerp(p.pos, alpha)),
+        }
+    }
+
+    /// Cible vivante la plus proche de `pos` selon les grilles spatiales
+    /// (drones d'abord, puis vaisseau-mère, sinon astéroïdes)
+    pub fn nearest(world: &World, arena: &Arena, pos: Vec2) -> Option<TargetId> {
+        let drones = &world.drones;
+        let boss_parts = &world.boss_parts;
+        let asteroids = &world.asteroids;
+        world
+            .drone_grid
+            .nearest_in(arena, pos, |d| drones[d].pos, |d| !drones[d].is_destroyed())
+            .map(|d| TargetId::Drone(drones.id_at(d)))
+            .or_else(|| {
+                world
+                    .boss_grid
+                    .nearest_in(arena, pos, |p| boss_parts[p].pos, |p| !boss_parts[p].is_destroyed())
+                    .map(|p| TargetId::Boss(boss_parts.id_at(p)))
+            })
+            .or_else(|| {
+                world
+                    .asteroid_grid
+                    .nearest_in(arena, pos, |a| asteroids[a].pos, |a| !asteroids[a].is_destroyed())
+                    .map(|a| TargetId::Asteroid(asteroids.id_at(a)))
+            })
+    }
 }
 
 impl HomingMissile {
     /// Crée un nouveau missile
-    pub fn new(pos: Vec2, dir: Vec2) -> Self {
+    pub fn new(pos: Vec2, dir: Vec2, balance: &WeaponBalance) -> Self {
         Self {
             pos,
-            vel: dir * MISSILE_SPEED,
-            life: MISSILE_LIFE,
-            speed: MISSILE_SPEED,
+            prev_pos: pos,
+            vel: dir * balance.missile_speed,
+            life: balance.missile_life,
+            speed: balance.missile_speed,
             hit: false,
+            target: None,
         }
     }
 
@@ -64,22 +173,22 @@ impl HomingMissile {
     }
 
     /// Vérifie si le missile est toujours actif
-    pub fn is_alive(&self) -> bool {
-        self.life > 0.0 
-            && !self.hit
-            && self.pos.x > -100.0 
-            && self.pos.x < screen_width() + 100.0
-            && self.pos.y > -100.0 
-            && self.pos.y < screen_height() + 100.0
+    pub fn is_alive(&self, arena: &Arena) -> bool {
+        self.life > 0.0 && !self.hit && arena.contains(self.pos, 100.0)
+    }
+
+    /// Forme de collision (halo dessiné)
+    pub fn collider(&self) -> Collider {
+        Collider::circle(self.pos, MISSILE_RADIUS)
     }
 
     /// Poursuite de la cible (homing)
-    pub fn homing(&mut self, target: Vec2, dt: f32) {
+    pub fn homing(&mut self, target: Vec2, dt: f32, turn_speed: f32) {
         let desired_dir = (target - self.pos).normalize_or_zero();
         if desired_dir != Vec2::ZERO {
             let current_dir = self.vel.normalize_or_zero();
             let new_dir = current_dir
-                .lerp(desired_dir, MISSILE_TURN_SPEED * dt)
+                .lerp(desired_dir, turn_speed * dt)
                 .normalize_or_zero();
             self.vel = new_dir * self.speed;
         }
@@ -89,6 +198,7 @@ impl HomingMissile {
 // -----------------------------------------------------------------------------
 // LASER PERÇANT
 // -----------------------------------------------------------------------------
+#[derive(Clone, Serialize, Deserialize)]
 pub struct Laser {
     pub active: bool,
     pub start_pos: Vec2,
@@ -99,13 +209,13 @@ pub struct Laser {
 
 impl Laser {
     /// Crée un nouveau laser (désactivé par défaut)
-    pub fn new() -> Self {
+    pub fn new(balance: &WeaponBalance) -> Self {
         Self {
             active: false,
             start_pos: Vec2::ZERO,
             direction: Vec2::ZERO,
-            timer: Cooldown::new(LASER_DURATION),
-            cooldown: Cooldown::new(LASER_COOLDOWN),
+            timer: Cooldown::new(balance.laser_duration),
+            cooldown: Cooldown::new(balance.laser_cooldown),
         }
     }
 
@@ -133,67 +243,106 @@ impl Laser {
             self.cooldown.tick(dt);
         }
     }
+
+    /// Forme de collision du rayon (portée `range`)
+    pub fn collider(&self, range: f32) -> Collider {
+        Collider::segment(self.start_pos, self.start_pos + self.direction * range, LASER_HALF_WIDTH)
+    }
+}
+
+impl Default for Laser {
+    fn default() -> Self {
+        Self::new(&WeaponBalance::default())
+    }
 }
 
 // -----------------------------------------------------------------------------
 // IMPLÉMENTATION DU SYSTÈME D'ARMES
 // -----------------------------------------------------------------------------
+impl Default for WeaponSystem {
+    fn default() -> Self {
+        Self::new(&WeaponBalance::default())
+    }
+}
+
 impl WeaponSystem {
     /// Crée un nouveau système d'armes
-    pub fn new() -> Self {
+    pub fn new(balance: &WeaponBalance) -> Self {
         Self {
-            fire_cooldown: Cooldown::new(0.2),
-            missile_ammo: MISSILE_AMMO_MAX,
-            missile_cooldown: Cooldown::new(MISSILE_COOLDOWN_MAX),
-            missiles: Vec::new(),
-            laser: Laser::new(),
+            fire_cooldown: Cooldown::new(balance.fire_rate),
+            missile_ammo: balance.missile_ammo_max,
+            missile_cooldown: Cooldown::new(balance.missile_cooldown),
+            missiles: SlotMap::new(),
+            laser: Laser::new(balance),
             ammo_regen_timer: 0.0,
+            locks: Vec::new(),
+            locking: false,
+            lock_timer: 0.0,
         }
     }
 
+    /// Réapplique les limites actuelles de l'équilibrage
+    /// (chargement d'une sauvegarde, rechargement de balance.ron)
+    pub fn rebalance(&mut self, balance: &WeaponBalance) {
+        self.missile_ammo = self.missile_ammo.min(balance.missile_ammo_max);
+        self.missile_cooldown = self.missile_cooldown.with_max(balance.missile_cooldown);
+        self.laser.timer = self.laser.timer.with_max(balance.laser_duration);
+        self.laser.cooldown = self.laser.cooldown.with_max(balance.laser_cooldown);
+    }
+
     /// Met à jour toutes les armes
+    /// `input` contient les touches maintenues, `pressed` celles enfoncées à cette frame
+    #[allow(clippy::too_many_arguments)]
     pub fn update(
         &mut self, 
         dt: f32, 
+        input: &InputState,
+        pressed: &InputState,
         ship: &mut Ship, 
         world: &mut World, 
-        effects: &mut EffectSystem,
-        event_queue: &mut EventQueue,
+        arena: &Arena,
+        events: &mut EventBus,
+        balance: &WeaponBalance,
     ) {
         self.fire_cooldown.tick(dt);
         self.missile_cooldown.tick(dt);
         
-        let ship_dir = vec2(ship.rot.cos(), ship.rot.sin());
+        // Direction de visée déjà calculée par le vaisseau (rotation ou souris)
+        let ship_dir = ship.dir;
         
         // Tir normal
-        self.handle_shooting(dt, ship, ship_dir, world, effects, event_queue);
+        self.handle_shooting(input, ship, ship_dir, world, events, balance);
         
-        // Tir missile
-        self.handle_missile_launch(dt, ship, ship_dir, world, effects, event_queue);
+        // Verrouillage et salve de missiles
+        self.handle_missile_lock(dt, input, ship, ship_dir, world, arena, events, balance);
         
         // Recharge automatique des missiles
-        self.regen_missile_ammo(dt);
+        self.regen_missile_ammo(dt, balance);
         
         // Tir laser
-        self.handle_laser(dt, ship, ship_dir, event_queue);
+        self.handle_laser(pressed, ship, ship_dir, events, balance);
         
         // Mise à jour du laser
         self.laser.update(dt, ship.pos, ship_dir);
     }
 
-    /// Gère le tir normal (ESPACE)
+    /// Gère le tir normal (action Shoot maintenue)
     fn handle_shooting(
         &mut self, 
-        _dt: f32,                    // Non utilisé (conservé pour signature)
+        input: &InputState,
         ship: &mut Ship, 
         ship_dir: Vec2, 
         world: &mut World, 
-        effects: &mut EffectSystem,
-        _event_queue: &mut EventQueue, // Non utilisé (conservé pour extension)
+        events: &mut EventBus,
+        balance: &WeaponBalance,
     ) {
-        let fire_rate = if ship.rapid_fire_timer.time > 0.0 { 0.1 } else { 0.2 };
+        let fire_rate = if ship.rapid_fire_timer.time > 0.0 {
+            balance.rapid_fire_rate
+        } else {
+            balance.fire_rate
+        };
         
-        if (is_key_down(KEY_SHOOT) || is_key_pressed(KEY_SHOOT)) 
+        if input.shoot
            && !ship.shield_active 
            && self.fire_cooldown.ready() {
             
@@ -201,76 +350,168 @@ impl WeaponSystem {
             let bullet_size = if ship.rapid_fire_timer.time > 0.0 { 2.0 } else { 3.0 };
             
             // Création de la balle
+            let muzzle = ship.pos + ship_dir * 15.0;
             world.bullets.push(Bullet {
-                pos: ship.pos + ship_dir * 15.0,
-                vel: ship_dir * BULLET_SPEED,
+                pos: muzzle,
+                prev_pos: muzzle,
+                vel: ship_dir * balance.bullet_speed,
                 enemy: false,
+                owner: None,
                 size: bullet_size,
-                life: 2.0,
+                life: balance.bullet_life,
             });
 
             // Recul
-            ship.vel += -ship_dir * 30.0;
+            ship.vel += -ship_dir * balance.bullet_recoil;
             self.fire_cooldown.time = fire_rate;
 
-            // Effet visuel
-            effects.create_muzzle_flash(ship.pos + ship_dir * 10.0, ship_dir);
+            events.emit(Event::WeaponFired {
+                weapon: WeaponKind::Cannon,
+                pos: ship.pos,
+                dir: ship_dir,
+            });
         }
     }
 
-    /// Gère le tir de missile à tête chercheuse (F)
-    fn handle_missile_launch(
-        &mut self, 
-        _dt: f32,                    // Non utilisé
-        ship: &mut Ship, 
-        ship_dir: Vec2, 
-        _world: &mut World,          // Non utilisé
-        effects: &mut EffectSystem,
-        _event_queue: &mut EventQueue, // Non utilisé
+    /// Gère le verrouillage (action Missile maintenue) et la salve tirée
+    /// au relâchement ; le bouclier annule le verrouillage
+    #[allow(clippy::too_many_arguments)]
+    fn handle_missile_lock(
+        &mut self,
+        dt: f32,
+        input: &InputState,
+        ship: &mut Ship,
+        ship_dir: Vec2,
+        world: &World,
+        arena: &Arena,
+        events: &mut EventBus,
+        balance: &WeaponBalance,
     ) {
-        if is_key_pressed(KEY_MISSILE) 
-           && !ship.shield_active 
-           && self.missile_ammo > 0 
-           && self.missile_cooldown.ready() {
-            
-            // Création du missile
-            self.missiles.push(HomingMissile::new(
-                ship.pos + ship_dir * 20.0,
-                ship_dir,
-            ));
-            
+        // Les cibles détruites entre-temps sont oubliées
+        self.locks.retain(|t| t.position(world).is_some());
+
+        if input.missile && !ship.shield_active {
+            if !self.locking {
+                if self.missile_ammo <= 0 || !self.missile_cooldown.ready() {
+                    return;
+                }
+                // Premier verrouillage immédiat : un appui bref vise la plus proche
+                self.locking = true;
+                self.lock_timer = 0.0;
+            }
+
+            self.lock_timer -= dt;
+            let can_lock = self.lock_timer <= 0.0 && (self.locks.len() as i32) < self.missile_ammo;
+            let target = if can_lock { self.find_lock_target(ship.pos, world, arena, balance) } else { None };
+            if let Some(target) = target {
+                self.locks.push(target);
+                self.lock_timer = balance.missile_lock_time;
+            }
+        } else if self.locking {
+            self.locking = false;
+            if !ship.shield_active {
+                self.fire_salvo(ship, ship_dir, events, balance);
+            }
+            self.locks.clear();
+        }
+    }
+
+    /// Ennemi vivant non verrouillé le plus proche du vaisseau, à portée
+    /// (drones d'abord, puis vaisseau-mère, sinon astéroïdes)
+    /// Parcours complet : les grilles spatiales datent du tick précédent
+    fn find_lock_target(
+        &self,
+        ship_pos: Vec2,
+        world: &World,
+        arena: &Arena,
+        balance: &WeaponBalance,
+    ) -> Option<TargetId> {
+        let range_sq = balance.missile_lock_range * balance.missile_lock_range;
+        let dist_sq = |pos: &Vec2| arena.distance_squared(*pos, ship_pos);
+        let closest = |candidates: Vec<(TargetId, Vec2)>| {
+            candidates
+                .into_iter()
+                .filter(|(target, pos)| !self.locks.contains(target) && dist_sq(pos) <= range_sq)
+                .min_by(|(_, a), (_, b)| dist_sq(a).total_cmp(&dist_sq(b)))
+                .map(|(target, _)| target)
+        };
+
+        let drones = world
+            .drones
+            .iter_with_ids()
+            .filter(|(_, d)| !d.is_destroyed())
+            .map(|(id, d)| (TargetId::Drone(id), d.pos))
+            .collect();
+        closest(drones)
+            .or_else(|| {
+                let boss_parts = world
+                    .boss_parts
+                    .iter_with_ids()
+                    .filter(|(_, p)| !p.is_destroyed())
+                    .map(|(id, p)| (TargetId::Boss(id), p.pos))
+                    .collect();
+                closest(boss_parts)
+            })
+            .or_else(|| {
+                let asteroids = world
+                    .asteroids
+                    .iter_with_ids()
+                    .filter(|(_, a)| !a.is_destroyed())
+                    .map(|(id, a)| (TargetId::Asteroid(id), a.pos))
+                    .collect();
+                closest(asteroids)
+            })
+    }
+
+    /// Tire un missile par cible verrouillée (un seul, sans cible, si aucune),
+    /// en éventail autour de la direction du vaisseau
+    fn fire_salvo(&mut self, ship: &mut Ship, ship_dir: Vec2, events: &mut EventBus, balance: &WeaponBalance) {
+        let count = (self.locks.len() as i32).max(1).min(self.missile_ammo);
+        for i in 0..count {
+            let offset = (i as f32 - (count - 1) as f32 * 0.5) * balance.missile_salvo_spread;
+            let dir = Vec2::from_angle(offset).rotate(ship_dir);
+
+            // Création du missile, avec sa cible
+            let mut missile = HomingMissile::new(ship.pos + dir * 20.0, dir, balance);
+            missile.target = self.locks.get(i as usize).copied();
+            self.missiles.push(missile);
+
             // Consommation munition
             self.missile_ammo -= 1;
-            self.missile_cooldown.reset();
-            
+
             // Recul
-            ship.vel += -ship_dir * 50.0;
-            
-            // Effet visuel
-            effects.create_missile_launch(ship.pos + ship_dir * 20.0, ship_dir);
+            ship.vel += -dir * balance.missile_recoil;
+
+            events.emit(Event::WeaponFired {
+                weapon: WeaponKind::Missile,
+                pos: ship.pos,
+                dir,
+            });
         }
+        self.missile_cooldown.reset();
     }
 
     /// Recharge automatique des missiles
-    fn regen_missile_ammo(&mut self, dt: f32) {
-        if self.missile_ammo < MISSILE_AMMO_MAX {
+    fn regen_missile_ammo(&mut self, dt: f32, balance: &WeaponBalance) {
+        if self.missile_ammo < balance.missile_ammo_max {
             self.ammo_regen_timer += dt;
-            if self.ammo_regen_timer >= 1.0 {
-                self.missile_ammo = (self.missile_ammo + 1).min(MISSILE_AMMO_MAX);
+            if self.ammo_regen_timer >= balance.missile_regen_time {
+                self.missile_ammo = (self.missile_ammo + 1).min(balance.missile_ammo_max);
                 self.ammo_regen_timer = 0.0;
             }
         }
     }
 
-    /// Gère le tir laser (L)
+    /// Gère le tir laser (action Laser enfoncée)
     fn handle_laser(
         &mut self, 
-        _dt: f32,                    // Non utilisé
+        pressed: &InputState,
         ship: &mut Ship, 
         ship_dir: Vec2, 
-        event_queue: &mut EventQueue,
+        events: &mut EventBus,
+        balance: &WeaponBalance,
     ) {
-        if is_key_pressed(KEY_LASER) 
+        if pressed.laser 
            && !ship.shield_active 
            && self.laser.cooldown.ready() 
            && !self.laser.active {
@@ -279,10 +520,10 @@ impl WeaponSystem {
             self.laser.fire(ship.pos, ship_dir);
             
             // Recul
-            ship.vel += -ship_dir * 80.0;
+            ship.vel += -ship_dir * balance.laser_recoil;
             
-            // Événement pour les effets visuels
-            event_queue.push(crate::events::Event::LaserFired {
+            events.emit(Event::WeaponFired {
+                weapon: WeaponKind::Laser,
                 pos: ship.pos,
                 dir: ship_dir,
             });
@@ -294,106 +535,68 @@ impl WeaponSystem {
         &mut self, 
         dt: f32, 
         world: &mut World, 
-        effects: &mut EffectSystem, 
-        score: &mut i32, 
-        combo: &mut i32, 
-        combo_timer: &mut Cooldown, 
-        enemies_killed: &mut i32, 
-        _event_queue: &mut EventQueue,
+        arena: &Arena,
+        ctx: &mut DamageContext,
     ) {
+        let balance = ctx.balance;
         // Mise à jour du mouvement et homing
         for missile in self.missiles.iter_mut() {
             missile.update(dt);
+            arena.wrap_motion(&mut missile.pos, &mut missile.prev_pos);
             
-            // Recherche de la cible la plus proche
-            let mut target_pos = None;
-            let mut min_dist = f32::INFINITY;
-            
-            // Priorité aux drones
-            for drone in world.drones.iter() {
-                if drone.hp > 0 {
-                    let dist = missile.pos.distance_squared(drone.pos);
-                    if dist < min_dist {
-                        min_dist = dist;
-                        target_pos = Some(drone.pos);
-                    }
-                }
-            }
-
-
-            if target_pos == None {
-                // Sinon, astéroïdes
-                for asteroid in world.asteroids.iter() {
-                    if asteroid.radius > 10.0 {
-                        let dist = missile.pos.distance_squared(asteroid.pos);
-                        if dist < min_dist {
-                            min_dist = dist;
-                            target_pos = Some(asteroid.pos);
-                        }
-                    }
-                }
+            // Cible gardée tant qu'elle vit, sinon la plus proche
+            let mut target_pos = missile.target.and_then(|t| t.position(world));
+            if target_pos.is_none() {
+                missile.target = TargetId::nearest(world, arena, missile.pos);
+                target_pos = missile.target.and_then(|t| t.position(world));
             }
 
-            // Poursuite de la cible
+            // Poursuite de la copie de la cible la plus proche
             if let Some(target) = target_pos {
-                missile.homing(target, dt);
+                let target = arena.image_near(target, missile.pos);
+                missile.homing(target, dt, balance.weapons.missile_turn_speed);
             }
         }
 
         // Gestion des collisions (avec suppression optimisée)
+        // Le missile est balayé sur son trajet du tick : la première cible
+        // vivante rencontrée l'emporte (drone, puis astéroïde, puis
+        // vaisseau-mère en cas d'égalité)
+        let mut candidates = Vec::new();
         let mut i = 0;
         while i < self.missiles.len() {
             let missile = &self.missiles[i];
-            let mut hit = false;
-
-            // Collision avec les drones
-            for drone in world.drones.iter_mut() {
-                if drone.hp > 0 && missile.pos.distance(drone.pos) < 15.0 {
-                    drone.hp -= 2;  // Missile = dégâts importants
-                    *score += 20;
-                    *combo += 1;
-                    combo_timer.reset();
-
-                    effects.create_explosion(missile.pos, ORANGE, 12);
-
-                    if drone.hp <= 0 {
-                        *enemies_killed += 1;
-                        *score += 50;
-                        if rand::gen_range(0.0, 1.0) < POWERUP_SPAWN_CHANCE {
-                            world.powerups.push(PowerUp::new(drone.pos));
-                        }
-                    }
-
-                    hit = true;
-                    break;
-                }
-            }
-
-            // Collision avec les astéroïdes
-            if !hit {
-                for asteroid in world.asteroids.iter_mut() {
-                    if asteroid.radius > 10.0 && missile.pos.distance(asteroid.pos) < asteroid.radius + 10.0 {
-                        asteroid.radius -= 15.0;
-                        *score += 15;
-                        *combo += 1;
-                        combo_timer.reset();
-
-                        effects.create_explosion(missile.pos, ORANGE, 12);
-
-                        if asteroid.radius <= 10.0 {
-                            if rand::gen_range(0.0, 1.0) < POWERUP_SPAWN_CHANCE {
-                                world.powerups.push(PowerUp::new(asteroid.pos));
-                            }
-                        }
-
-                        hit = true;
-                        break;
-                    }
-                }
+            let (from, to) = (missile.prev_pos, missile.pos);
+            let drones = &world.drones;
+            let asteroids = &world.asteroids;
+            let boss_parts = &world.boss_parts;
+            let drone_hit = first_contact(&world.drone_grid, from, to, MISSILE_RADIUS, &mut candidates, |d| {
+                (!drones[d].is_destroyed()).then(|| drones[d].collider_near(arena, to))
+            });
+            let asteroid_hit = first_contact(&world.asteroid_grid, from, to, MISSILE_RADIUS, &mut candidates, |a| {
+                (!asteroids[a].is_destroyed()).then(|| asteroids[a].collider_near(arena, to))
+            });
+            let boss_hit = first_contact(&world.boss_grid, from, to, MISSILE_RADIUS, &mut candidates, |p| {
+                (!boss_parts[p].is_destroyed()).then(|| boss_parts[p].collider_near(arena, to))
+            });
+            let target = earliest([
+                drone_hit.map(|(d, t)| (Target::Drone(d), t)),
+                asteroid_hit.map(|(a, t)| (Target::Asteroid(a), t)),
+                boss_hit.map(|(p, t)| (Target::Boss(p), t)),
+            ]);
+
+            if let Some((target, t)) = target {
+                let hit = DamageInfo::new(balance.weapons.missile_damage, WeaponKind::Missile, from.lerp(to, t))
+                    .with_knockback(missile.vel, KNOCKBACK_MISSILE);
+                match target {
+                    Target::Drone(d) => ctx.damage_enemy(world.drones.id_at(d), &mut world.drones[d], hit),
+                    Target::Asteroid(a) => ctx.damage_enemy(world.asteroids.id_at(a), &mut world.asteroids[a], hit),
+                    Target::Boss(p) => ctx.damage_enemy(world.boss_parts.id_at(p), &mut world.boss_parts[p], hit),
+                };
             }
 
             // Suppression si touché (swap_remove = O(1))
-            if hit {
+            if target.is_some() {
                 self.missiles.swap_remove(i);
             } else {
                 i += 1;
@@ -407,67 +610,218 @@ impl WeaponSystem {
         _dt: f32,                    // Non utilisé
         _ship: &Ship,               // Non utilisé
         world: &mut World, 
-        effects: &mut EffectSystem, 
-        score: &mut i32, 
-        enemies_killed: &mut i32, 
-        _event_queue: &mut EventQueue,
+        arena: &Arena,
+        ctx: &mut DamageContext,
     ) {
         if !self.laser.active {
             return;
         }
 
+        let weapons = &ctx.balance.weapons;
+        let range = weapons.laser_range;
+        let damage = weapons.laser_damage;
+        let beam = self.laser.collider(range);
         let beam_start = self.laser.start_pos;
-        let beam_dir = self.laser.direction;
+        let beam_end = beam_start + self.laser.direction * range;
+        let max_hits = weapons.laser_max_penetration;
         let mut hit_count = 0;
+        let mut candidates = Vec::new();
+
+        // Drones, vaisseau-mère puis astéroïdes le long du rayon
+        let drones = beam_targets(&beam, beam_start, beam_end, &world.drone_grid, arena, &mut candidates, |d, offset| {
+            let drone = &world.drones[d];
+            (!drone.is_destroyed()).then(|| Drone::hull_at(drone.pos + offset))
+        });
+        for (d, impact) in drones {
+            if hit_count >= max_hits { return; }
+            let id = world.drones.id_at(d);
+            let drone = &mut world.drones[d];
+            let hit = DamageInfo::new(damage, WeaponKind::Laser, impact)
+                .with_knockback(self.laser.direction, KNOCKBACK_LASER);
+            ctx.damage_enemy(id, drone, hit);
+            hit_count += 1;
+        }
 
-        // Drones
-        for drone in world.drones.iter_mut() {
-            if hit_count >= LASER_MAX_PENETRATION { break; }
-            if drone.hp <= 0 { continue; }
-
-            let to_drone = drone.pos - beam_start;
-            let proj = to_drone.dot(beam_dir);
-
-            if proj > 0.0 && proj < LASER_RANGE {
-                let perp = (beam_start + beam_dir * proj).distance(drone.pos);
-                if perp < 15.0 {
-                    drone.hp -= LASER_DAMAGE;
-                    hit_count += 1;
-
-                    effects.create_explosion(drone.pos, RED, 6);
-
-                    if drone.hp <= 0 {
-                        *score += 50;
-                        *enemies_killed += 1;
-                        if rand::gen_range(0.0, 1.0) < POWERUP_SPAWN_CHANCE {
-                            world.powerups.push(PowerUp::new(drone.pos));
-                        }
-                    }
-                }
-            }
+        let parts = beam_targets(&beam, beam_start, beam_end, &world.boss_grid, arena, &mut candidates, |p, offset| {
+            let part = &world.boss_parts[p];
+            (!part.is_destroyed()).then(|| part.hull_at(part.pos + offset))
+        });
+        for (p, impact) in parts {
+            if hit_count >= max_hits { return; }
+            let id = world.boss_parts.id_at(p);
+            let part = &mut world.boss_parts[p];
+            let hit = DamageInfo::new(damage, WeaponKind::Laser, impact)
+                .with_knockback(self.laser.direction, KNOCKBACK_LASER);
+            ctx.damage_enemy(id, part, hit);
+            hit_count += 1;
         }
 
-        // Astéroïdes
-        for asteroid in world.asteroids.iter_mut() {
-            if hit_count >= LASER_MAX_PENETRATION { break; }
+        let asteroids = beam_targets(&beam, beam_start, beam_end, &world.asteroid_grid, arena, &mut candidates, |a, offset| {
+            let asteroid = &world.asteroids[a];
+            (!asteroid.is_destroyed()).then(|| asteroid.hull_at(asteroid.pos + offset))
+        });
+        for (a, impact) in asteroids {
+            if hit_count >= max_hits { return; }
+            let id = world.asteroids.id_at(a);
+            let asteroid = &mut world.asteroids[a];
+            let hit = DamageInfo::new(damage, WeaponKind::Laser, impact)
+                .with_knockback(self.laser.direction, KNOCKBACK_LASER);
+            ctx.damage_enemy(id, asteroid, hit);
+            hit_count += 1;
+        }
+    }
+}
 
-            let to_ast = asteroid.pos - beam_start;
-            let proj = to_ast.dot(beam_dir);
+/// Ennemis d'une grille touchés par le rayon `beam` (de `start` à `end`),
+/// chacun une seule fois, dans l'ordre de la grille, avec leur point
+/// d'impact : l'entrée du rayon dans leur coque, du côté qu'il touche. En
+/// arène torique, le rayon traverse les bords : il touche aussi les copies
+/// des ennemis de l'autre côté (`shape` : forme de l'ennemi d'index donné,
+/// décalée de `offset`, None s'il est ignoré)
+fn beam_targets(
+    beam: &Collider,
+    start: Vec2,
+    end: Vec2,
+    grid: &SpatialGrid,
+    arena: &Arena,
+    candidates: &mut Vec<usize>,
+    shape: impl Fn(usize, Vec2) -> Option<Collider>,
+) -> Vec<(usize, Vec2)> {
+    let mut hits: Vec<(usize, Vec2)> = Vec::new();
+    for offset in arena.tiles() {
+        // Rayon ramené dans l'arène de la grille, en face de cette copie
+        grid.query_segment(start - offset, end - offset, LASER_HALF_WIDTH, candidates);
+        for &index in candidates.iter() {
+            if hits.iter().any(|&(hit, _)| hit == index) {
+                continue;
+            }
+            let Some(hull) = shape(index, offset).filter(|s| beam.intersects(s)) else {
+                continue;
+            };
+            // Sinon (contact tangent), point du rayon le plus proche du centre
+            let t = hull.sweep(start, end, LASER_HALF_WIDTH).unwrap_or_else(|| {
+                let ray = end - start;
+                ((hull.center() - start).dot(ray) / ray.length_squared().max(f32::EPSILON)).clamp(0.0, 1.0)
+            });
+            hits.push((index, start.lerp(end, t)));
+        }
+    }
+    hits
+}
 
-            if proj > 0.0 && proj < LASER_RANGE {
-                let perp = (beam_start + beam_dir * proj).distance(asteroid.pos);
-                if perp < asteroid.radius + 10.0 {
-                    asteroid.radius -= 10.0;
-                    hit_count += 1;
+// =============================================================================
+// TESTS
+// =============================================================================
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::arena::ArenaMode;
+    use crate::balance::BalanceConfig;
+    use crate::consts::{SCORE_DRONE_KILL, SIM_DT};
+    use crate::game::{GameState, ScreenShake};
+    use crate::rng::Rng;
+
+    /// État de partie touché par les dégâts, hors du Game
+    struct Tally {
+        score: i32,
+        combo: i32,
+        killed: i32,
+        combo_timer: Cooldown,
+        shake: ScreenShake,
+        state: GameState,
+        rng: Rng,
+        events: EventBus,
+    }
 
-                    effects.create_explosion(asteroid.pos, RED, 6);
+    impl Tally {
+        fn new() -> Self {
+            Self {
+                score: 0,
+                combo: 0,
+                killed: 0,
+                combo_timer: Cooldown::new(2.0),
+                shake: ScreenShake::new(),
+                state: GameState::Playing,
+                rng: Rng::new(7),
+                events: EventBus::new(),
+            }
+        }
 
-                    if asteroid.radius <= 10.0 
-                        && rand::gen_range(0.0, 1.0) < POWERUP_SPAWN_CHANCE {
-                            world.powerups.push(PowerUp::new(asteroid.pos));
-                    }
-                }
+        fn ctx<'a>(&'a mut self, balance: &'a BalanceConfig, arena: &'a Arena) -> DamageContext<'a> {
+            DamageContext {
+                score: &mut self.score,
+                combo: &mut self.combo,
+                combo_timer: &mut self.combo_timer,
+                enemies_killed: &mut self.killed,
+                shake: &mut self.shake,
+                state: &mut self.state,
+                rng: &mut self.rng,
+                events: &mut self.events,
+                balance,
+                arena,
+                drops: Vec::new(),
+                fragments: Vec::new(),
             }
         }
     }
+
+    #[test]
+    fn laser_burst_scores_only_kills() {
+        let arena = Arena::new(vec2(800., 600.), ArenaMode::Bounded);
+        let balance = BalanceConfig::default();
+        let mut tally = Tally::new();
+        let mut world = World::new();
+        // Un drone fragile et deux indestructibles sur la trajectoire du rayon
+        for (x, hp) in [(200., 1), (300., 1_000_000), (400., 1_000_000)] {
+            let mut drone = Drone::new(vec2(x, 300.), 1.0, 1, &balance.drones, &mut tally.rng);
+            drone.hp = hp;
+            world.drones.push(drone);
+        }
+        let ship = Ship::new(vec2(100., 300.), &balance.ship);
+        let mut weapons = WeaponSystem::new(&balance.weapons);
+        weapons.laser.fire(ship.pos, Vec2::X);
+
+        // Rafale complète : un coup par tick sur chaque drone touché
+        let mut ticks = 0;
+        while weapons.laser.active {
+            world.rebuild_grids(&arena);
+            weapons.update_laser(SIM_DT, &ship, &mut world, &arena, &mut tally.ctx(&balance, &arena));
+            weapons.laser.update(SIM_DT, ship.pos, Vec2::X);
+            ticks += 1;
+        }
+        let hits = tally.events.pending().iter().filter(|e| matches!(e, Event::EnemyDamaged { .. })).count();
+
+        assert!(ticks >= 59, "rafale trop courte : {ticks} ticks");
+        assert_eq!(hits, 1 + 2 * ticks);
+        assert_eq!(tally.score, SCORE_DRONE_KILL);
+        assert_eq!(tally.combo, 0);
+        assert_eq!(tally.killed, 1);
+    }
+
+    #[test]
+    fn laser_chips_the_side_it_enters() {
+        // Rayon vers la droite : il entre par le flanc gauche (sommet 4 sur 8),
+        // y compris à travers le bord d'une arène torique
+        for (mode, ship_x, asteroid_x) in [(ArenaMode::Bounded, 100., 400.), (ArenaMode::Toroidal, 700., 60.)] {
+            let arena = Arena::new(vec2(800., 600.), mode);
+            let balance = BalanceConfig::default();
+            let mut tally = Tally::new();
+            let mut world = World::new();
+            let mut asteroid = Asteroid::new(vec2(asteroid_x, 300.), 1.0, &balance.asteroids, &mut tally.rng);
+            asteroid.size = AsteroidSize::Large;
+            asteroid.radius = 60.0;
+            asteroid.rotation = 0.0;
+            asteroid.outline = vec![1.0; 8];
+            world.asteroids.push(asteroid);
+            let ship = Ship::new(vec2(ship_x, 300.), &balance.ship);
+            let mut weapons = WeaponSystem::new(&balance.weapons);
+            weapons.laser.fire(ship.pos, Vec2::X);
+
+            world.rebuild_grids(&arena);
+            weapons.update_laser(SIM_DT, &ship, &mut world, &arena, &mut tally.ctx(&balance, &arena));
+            let outline = &world.asteroids[0].outline;
+            assert!(outline[4] < 1.0, "{mode:?} : flanc gauche intact : {outline:?}");
+            assert_eq!(outline.iter().filter(|&&o| o < 1.0).count(), 1, "{mode:?} : {outline:?}");
+        }
+    }
 }